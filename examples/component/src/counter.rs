@@ -3,7 +3,6 @@ use maycoon::core::app::update::Update;
 use maycoon::core::component::{Component, Composed};
 use maycoon::core::layout::LayoutStyle;
 use maycoon::core::reference::Ref;
-use maycoon::core::signal::eval::EvalSignal;
 use maycoon::core::signal::state::StateSignal;
 use maycoon::core::signal::{MaybeSignal, Signal};
 use maycoon::core::widget::{Widget, WidgetLayoutExt};
@@ -28,7 +27,7 @@ impl Counter {
 }
 
 impl Component for Counter {
-    fn build(&self, context: AppContext) -> impl Widget + 'static {
+    fn build(&self, _: AppContext) -> impl Widget + 'static {
         let counter = self.counter.clone();
 
         Container::new(vec![
@@ -36,28 +35,20 @@ impl Component for Counter {
                 let counter = counter.clone();
 
                 Box::new(
-                    Button::new(Text::new("Increase".to_string())).with_on_pressed(
-                        EvalSignal::new(move || {
-                            counter.mutate(|i| *i += 1);
-                            Update::DRAW
-                        })
-                        .hook(&context)
-                        .maybe(),
-                    ),
+                    Button::new(Text::new("Increase".to_string())).with_on_pressed(move |_, _| {
+                        counter.mutate(|i| *i += 1);
+                        Update::DRAW
+                    }),
                 )
             },
             {
                 let counter = counter.clone();
 
                 Box::new(
-                    Button::new(Text::new("Decrease".to_string())).with_on_pressed(
-                        EvalSignal::new(move || {
-                            counter.mutate(|i| *i -= 1);
-                            Update::DRAW
-                        })
-                        .hook(&context)
-                        .maybe(),
-                    ),
+                    Button::new(Text::new("Decrease".to_string())).with_on_pressed(move |_, _| {
+                        counter.mutate(|i| *i -= 1);
+                        Update::DRAW
+                    }),
                 )
             },
             Box::new(Text::new(counter.map(|i| Ref::Owned(i.to_string())))),
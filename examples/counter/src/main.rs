@@ -5,7 +5,6 @@ use maycoon::core::config::MayConfig;
 use maycoon::core::layout::{AlignItems, Dimension, FlexDirection, LayoutStyle};
 use maycoon::core::reference::Ref;
 use maycoon::core::signal::Signal;
-use maycoon::core::signal::eval::EvalSignal;
 use maycoon::core::signal::state::StateSignal;
 use maycoon::core::vgi::DefaultGraphics;
 use maycoon::core::widget::{Widget, WidgetLayoutExt};
@@ -30,30 +29,22 @@ impl Application for MyApp {
                 let counter = counter.clone();
 
                 Box::new(
-                    Button::new(Text::new("Increase".to_string())).with_on_pressed(
-                        EvalSignal::new(move || {
-                            counter.mutate(|c| *c += 1);
+                    Button::new(Text::new("Increase".to_string())).with_on_pressed(move |_, _| {
+                        counter.mutate(|c| *c += 1);
 
-                            Update::DRAW
-                        })
-                        .hook(&context)
-                        .maybe(),
-                    ),
+                        Update::DRAW
+                    }),
                 )
             },
             {
                 let counter = counter.clone();
 
                 Box::new(
-                    Button::new(Text::new("Decrease".to_string())).with_on_pressed(
-                        EvalSignal::new(move || {
-                            counter.mutate(|c| *c -= 1);
+                    Button::new(Text::new("Decrease".to_string())).with_on_pressed(move |_, _| {
+                        counter.mutate(|c| *c -= 1);
 
-                            Update::DRAW
-                        })
-                        .hook(&context)
-                        .maybe(),
-                    ),
+                        Update::DRAW
+                    }),
                 )
             },
             Box::new(Text::new(counter.map(|i| Ref::Owned(i.to_string())))),
@@ -0,0 +1,86 @@
+use maycoon::core::app::Application;
+use maycoon::core::app::context::AppContext;
+use maycoon::core::app::drag::Files;
+use maycoon::core::app::update::Update;
+use maycoon::core::config::MayConfig;
+use maycoon::core::layout::{AlignItems, Dimension, FlexDirection, LayoutStyle, LengthPercentage};
+use maycoon::core::reference::Ref;
+use maycoon::core::signal::Signal;
+use maycoon::core::signal::state::StateSignal;
+use maycoon::core::vgi::DefaultGraphics;
+use maycoon::core::widget::{Widget, WidgetLayoutExt};
+use maycoon::math::Vector2;
+use maycoon::theme::theme::celeste::CelesteTheme;
+use maycoon::widgets::container::Container;
+use maycoon::widgets::drag_source::DragSource;
+use maycoon::widgets::drop_target::DropTarget;
+use maycoon::widgets::text::Text;
+
+struct MyApp;
+
+impl Application for MyApp {
+    type Theme = CelesteTheme;
+    type Graphics = DefaultGraphics;
+    type State = ();
+
+    fn build(context: AppContext, _: Self::State) -> impl Widget {
+        let fruit = context.use_signal(StateSignal::new(String::from("Drop a fruit here")));
+        let files = context.use_signal(StateSignal::new(String::from("Drop files here")));
+
+        let mut children: Vec<Box<dyn Widget>> = ["Apple", "Banana", "Cherry"]
+            .into_iter()
+            .map(|name| {
+                Box::new(DragSource::new(Text::new(name.to_string()), move || name))
+                    as Box<dyn Widget>
+            })
+            .collect();
+
+        children.push({
+            let fruit = fruit.clone();
+
+            Box::new(
+                DropTarget::new(
+                    Text::new(fruit.map(|text| Ref::Owned(text.clone()))),
+                    move |name: &&'static str, _| {
+                        fruit.set(format!("Dropped {name}"));
+
+                        Update::DRAW | Update::LAYOUT
+                    },
+                )
+                .with_filter(|name| *name != "Cherry"),
+            )
+        });
+
+        children.push({
+            let files = files.clone();
+
+            Box::new(DropTarget::new(
+                Text::new(files.map(|text| Ref::Owned(text.clone()))),
+                move |dropped: &Files, _| {
+                    files.set(format!("Dropped {} file(s)", dropped.paths.len()));
+
+                    Update::DRAW | Update::LAYOUT
+                },
+            ))
+        });
+
+        Container::new(children).with_layout_style(LayoutStyle {
+            size: Vector2::<Dimension>::new(Dimension::percent(1.0), Dimension::percent(1.0)),
+            flex_direction: FlexDirection::Column,
+            align_items: Some(AlignItems::Center),
+            gap: Vector2::new(
+                LengthPercentage::length(10.0),
+                LengthPercentage::length(10.0),
+            ),
+            ..Default::default()
+        })
+    }
+
+    fn config(&self) -> MayConfig<Self::Theme, Self::Graphics> {
+        MayConfig::default()
+    }
+}
+
+fn main() {
+    MyApp.run(())
+}
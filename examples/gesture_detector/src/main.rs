@@ -2,8 +2,6 @@ use maycoon::core::app::Application;
 use maycoon::core::app::context::AppContext;
 use maycoon::core::app::update::Update;
 use maycoon::core::config::MayConfig;
-use maycoon::core::signal::Signal;
-use maycoon::core::signal::eval::EvalSignal;
 use maycoon::core::vgi::DefaultGraphics;
 use maycoon::core::widget::Widget;
 use maycoon::theme::theme::celeste::CelesteTheme;
@@ -17,32 +15,60 @@ impl Application for MyApp {
     type Graphics = DefaultGraphics;
     type State = ();
 
-    fn build(context: AppContext, _: Self::State) -> impl Widget {
+    fn build(_: AppContext, _: Self::State) -> impl Widget {
         GestureDetector::new(Text::new("Gesture Detector".to_string()))
-            .with_on_hover(
-                EvalSignal::new(move || {
-                    println!("Hovered");
-                    Update::DRAW
-                })
-                .hook(&context)
-                .maybe(),
-            )
-            .with_on_release(
-                EvalSignal::new(move || {
-                    println!("Release");
-                    Update::DRAW
-                })
-                .hook(&context)
-                .maybe(),
-            )
-            .with_on_press(
-                EvalSignal::new(move || {
-                    println!("Press");
-                    Update::DRAW
-                })
-                .hook(&context)
-                .maybe(),
-            )
+            .with_on_hover(move |cursor, _| {
+                println!("Hovered at {cursor:?}");
+                Update::DRAW
+            })
+            .with_on_release(move |_, _| {
+                println!("Release");
+                Update::DRAW
+            })
+            .with_on_press(move |click, _| {
+                println!("Press at {:?}", click.position);
+                Update::DRAW
+            })
+            .with_on_hover_enter(move |_, _| {
+                println!("Enter");
+                Update::DRAW
+            })
+            .with_on_hover_leave(move |_, _| {
+                println!("Leave");
+                Update::DRAW
+            })
+            .with_on_double_click(move |_, _| {
+                println!("Double Click");
+                Update::DRAW
+            })
+            .with_on_triple_click(move |_, _| {
+                println!("Triple Click");
+                Update::DRAW
+            })
+            .with_on_long_press(move |_, _| {
+                println!("Long Press");
+                Update::DRAW
+            })
+            .with_on_secondary_press(move |_, _| {
+                println!("Secondary Press");
+                Update::DRAW
+            })
+            .with_on_drag_start(|drag, _| {
+                println!("Drag Start at {:?}", drag.start);
+                Update::DRAW
+            })
+            .with_on_drag_update(|drag, _| {
+                println!("Drag by {:?}", drag.delta);
+                Update::DRAW
+            })
+            .with_on_drag_end(|drag, _| {
+                println!("Drag End after {:?}", drag.offset());
+                Update::DRAW
+            })
+            .with_on_scroll(|delta, _| {
+                println!("Scroll {delta:?}");
+                Update::DRAW
+            })
     }
 
     fn config(&self) -> MayConfig<Self::Theme, Self::Graphics> {
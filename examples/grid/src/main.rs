@@ -0,0 +1,69 @@
+use maycoon::core::app::Application;
+use maycoon::core::app::context::AppContext;
+use maycoon::core::config::MayConfig;
+use maycoon::core::layout::grid::{area, areas, fr, length, repeat};
+use maycoon::core::layout::{Dimension, Display, LayoutStyle, LengthPercentage};
+use maycoon::core::vgi::DefaultGraphics;
+use maycoon::core::widget::{BoxedWidget, Widget, WidgetLayoutExt};
+use maycoon::math::Vector2;
+use maycoon::theme::theme::celeste::CelesteTheme;
+use maycoon::widgets::button::Button;
+use maycoon::widgets::container::Container;
+use maycoon::widgets::text::Text;
+
+struct MyApp;
+
+impl Application for MyApp {
+    type Theme = CelesteTheme;
+    type Graphics = DefaultGraphics;
+    type State = ();
+
+    fn build(_: AppContext, _: Self::State) -> impl Widget {
+        let placed = |name: &str, widget: Text| -> BoxedWidget {
+            Box::new(widget.with_layout_style(LayoutStyle {
+                grid_row: area(name),
+                grid_column: area(name),
+                ..Default::default()
+            }))
+        };
+
+        // three equally wide columns of buttons
+        let buttons = (1..=9)
+            .map(|i| Box::new(Button::new(Text::new(format!("Button {i}")))) as BoxedWidget)
+            .collect();
+
+        Container::new(vec![
+            placed("header", Text::new("Header".to_string())),
+            placed("sidebar", Text::new("Sidebar".to_string())),
+            Box::new(Container::new(buttons).with_layout_style(LayoutStyle {
+                display: Display::Grid,
+                grid_template_columns: vec![repeat(3, vec![fr(1.0)])],
+                gap: Vector2::new(LengthPercentage::length(8.0), LengthPercentage::length(8.0)),
+                grid_row: area("main"),
+                grid_column: area("main"),
+                ..Default::default()
+            })),
+            placed("footer", Text::new("Footer".to_string())),
+        ])
+        .with_layout_style(LayoutStyle {
+            size: Vector2::<Dimension>::new(Dimension::percent(1.0), Dimension::percent(1.0)),
+            display: Display::Grid,
+            grid_template_columns: vec![length(150.0), fr(1.0)],
+            grid_template_rows: vec![length(50.0), fr(1.0), length(50.0)],
+            grid_template_areas: areas(&["header header", "sidebar main", "footer footer"]),
+            gap: Vector2::new(
+                LengthPercentage::length(10.0),
+                LengthPercentage::length(10.0),
+            ),
+            ..Default::default()
+        })
+    }
+
+    fn config(&self) -> MayConfig<Self::Theme, Self::Graphics> {
+        MayConfig::default()
+    }
+}
+
+fn main() {
+    MyApp.run(())
+}
@@ -1,7 +1,7 @@
 use maycoon::core::app::info::AppInfo;
 use maycoon::core::app::update::UpdateManager;
 use maycoon::core::config::MayConfig;
-use maycoon::core::layout::{NodeId, TaffyTree};
+use maycoon::core::layout::{MeasureFn, NodeId, TaffyTree};
 use maycoon::core::plugin::{Plugin, PluginManager};
 use maycoon::core::vgi::VectorGraphicsInterface;
 use maycoon::core::window::{ActiveEventLoop, Window, WindowEvent};
@@ -29,7 +29,7 @@ impl<T: Theme, V: VectorGraphicsInterface> Plugin<T, V> for MyPlugin {
         _config: &mut MayConfig<T, V>,
         _window: &Arc<Window>,
         _scene: &mut V::Scene,
-        _taffy: &mut TaffyTree,
+        _taffy: &mut TaffyTree<MeasureFn>,
         _window_node: NodeId,
         _info: &mut AppInfo,
         _update: &UpdateManager,
@@ -0,0 +1,78 @@
+use maycoon::core::app::Application;
+use maycoon::core::app::context::AppContext;
+use maycoon::core::app::update::Update;
+use maycoon::core::config::MayConfig;
+use maycoon::core::layout::{AlignItems, Dimension, FlexDirection, LayoutStyle};
+use maycoon::core::reference::Ref;
+use maycoon::core::signal::Signal;
+use maycoon::core::signal::state::StateSignal;
+use maycoon::core::vgi::DefaultGraphics;
+use maycoon::core::widget::{BoxedWidget, Widget, WidgetLayoutExt};
+use maycoon::math::Vector2;
+use maycoon::theme::theme::celeste::CelesteTheme;
+use maycoon::widgets::button::Button;
+use maycoon::widgets::container::Container;
+use maycoon::widgets::scroll_view::ScrollView;
+use maycoon::widgets::text::Text;
+
+struct MyApp;
+
+impl Application for MyApp {
+    type Theme = CelesteTheme;
+    type Graphics = DefaultGraphics;
+    type State = ();
+
+    fn build(context: AppContext, _: Self::State) -> impl Widget {
+        let offset = context.use_signal(StateSignal::new(Vector2::<f32>::zeros()));
+
+        let items = (1..=100)
+            .map(|i| Box::new(Text::new(format!("Item {i}"))) as BoxedWidget)
+            .collect();
+
+        Container::new(vec![
+            {
+                let offset = offset.clone();
+
+                Box::new(
+                    Button::new(Text::new("Scroll to top".to_string())).with_on_pressed(
+                        move |_, _| {
+                            offset.set(Vector2::zeros());
+
+                            Update::DRAW
+                        },
+                    ),
+                )
+            },
+            Box::new(
+                ScrollView::new(Container::new(items).with_layout_style(LayoutStyle {
+                    flex_direction: FlexDirection::Column,
+                    ..Default::default()
+                }))
+                .with_offset(offset.maybe())
+                .with_layout_style(LayoutStyle {
+                    size: Vector2::new(Dimension::length(300.0), Dimension::length(400.0)),
+                    flex_direction: FlexDirection::Column,
+                    scrollbar_width: 10.0,
+                    ..Default::default()
+                }),
+            ),
+            Box::new(Text::new(
+                offset.map(|offset| Ref::Owned(format!("Offset: {:.0}", offset.y))),
+            )),
+        ])
+        .with_layout_style(LayoutStyle {
+            size: Vector2::<Dimension>::new(Dimension::percent(1.0), Dimension::percent(1.0)),
+            flex_direction: FlexDirection::Column,
+            align_items: Some(AlignItems::Center),
+            ..Default::default()
+        })
+    }
+
+    fn config(&self) -> MayConfig<Self::Theme, Self::Graphics> {
+        MayConfig::default()
+    }
+}
+
+fn main() {
+    MyApp.run(())
+}
@@ -0,0 +1,75 @@
+use maycoon::core::app::Application;
+use maycoon::core::app::context::AppContext;
+use maycoon::core::app::shortcut::Shortcut;
+use maycoon::core::app::update::Update;
+use maycoon::core::config::MayConfig;
+use maycoon::core::layout::{AlignItems, Dimension, FlexDirection, LayoutStyle};
+use maycoon::core::reference::Ref;
+use maycoon::core::signal::Signal;
+use maycoon::core::signal::state::StateSignal;
+use maycoon::core::vgi::DefaultGraphics;
+use maycoon::core::widget::{Widget, WidgetLayoutExt};
+use maycoon::core::window::{Key, NamedKey};
+use maycoon::math::Vector2;
+use maycoon::theme::theme::celeste::CelesteTheme;
+use maycoon::widgets::container::Container;
+use maycoon::widgets::shortcuts::Shortcuts;
+use maycoon::widgets::text::Text;
+use maycoon::widgets::text_input::TextInput;
+
+struct MyApp;
+
+impl Application for MyApp {
+    type Theme = CelesteTheme;
+    type Graphics = DefaultGraphics;
+    type State = ();
+
+    fn build(context: AppContext, _: Self::State) -> impl Widget {
+        let note = context.use_signal(StateSignal::new(String::new()));
+        let status = context.use_signal(StateSignal::new(String::from(
+            "Press Ctrl+S (Cmd+S on macOS) to save",
+        )));
+
+        let input = {
+            let note = note.clone();
+
+            // Escape only clears the note while the text input is focused.
+            Shortcuts::new(
+                TextInput::new(note.maybe()).with_placeholder("Write a note".to_string()),
+            )
+            .with_shortcut(Shortcut::new(Key::Named(NamedKey::Escape)), move |_, _| {
+                note.set(String::new());
+
+                Update::DRAW
+            })
+        };
+
+        // Saving works regardless of the focus.
+        Shortcuts::new(
+            Container::new(vec![
+                Box::new(input),
+                Box::new(Text::new(status.map(|s| Ref::Owned(s.clone())))),
+            ])
+            .with_layout_style(LayoutStyle {
+                size: Vector2::<Dimension>::new(Dimension::percent(1.0), Dimension::percent(1.0)),
+                flex_direction: FlexDirection::Column,
+                align_items: Some(AlignItems::Center),
+                ..Default::default()
+            }),
+        )
+        .with_global(true)
+        .with_shortcut(Shortcut::char('s').with_primary(), move |_, _| {
+            status.set(format!("Saved: {}", note.get().as_str()));
+
+            Update::DRAW
+        })
+    }
+
+    fn config(&self) -> MayConfig<Self::Theme, Self::Graphics> {
+        MayConfig::default()
+    }
+}
+
+fn main() {
+    MyApp.run(())
+}
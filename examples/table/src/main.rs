@@ -0,0 +1,124 @@
+use maycoon::core::app::Application;
+use maycoon::core::app::context::AppContext;
+use maycoon::core::config::MayConfig;
+use maycoon::core::layout::{AlignItems, Dimension, FlexDirection, JustifyContent, LayoutStyle};
+use maycoon::core::reference::Ref;
+use maycoon::core::signal::Signal;
+use maycoon::core::signal::state::StateSignal;
+use maycoon::core::vgi::DefaultGraphics;
+use maycoon::core::widget::{Widget, WidgetLayoutExt};
+use maycoon::math::Vector2;
+use maycoon::theme::theme::celeste::CelesteTheme;
+use maycoon::widgets::container::Container;
+use maycoon::widgets::table::{Column, SelectionMode, Table};
+use maycoon::widgets::text::Text;
+use std::rc::Rc;
+
+struct Person {
+    name: String,
+    age: u32,
+    city: &'static str,
+}
+
+struct MyApp;
+
+impl Application for MyApp {
+    type Theme = CelesteTheme;
+    type Graphics = DefaultGraphics;
+    type State = ();
+
+    fn build(context: AppContext, _: Self::State) -> impl Widget {
+        const CITIES: [&str; 5] = ["Berlin", "Paris", "Tokyo", "Lima", "Oslo"];
+
+        let people = Rc::new(
+            (0..100_000u32)
+                .map(|i| Person {
+                    name: format!("Person {i}"),
+                    age: 18 + (i * 7919) % 70,
+                    city: CITIES[(i as usize * 31) % CITIES.len()],
+                })
+                .collect::<Vec<_>>(),
+        );
+
+        let selection = context.use_signal(StateSignal::new(Vec::<usize>::new()));
+
+        let name = {
+            let people = people.clone();
+            let sort = people.clone();
+
+            Column::new(
+                Text::new("Name".to_string()),
+                move |index: StateSignal<usize>| {
+                    let people = people.clone();
+
+                    Text::new(index.map(move |index| Ref::Owned(people[*index].name.clone())))
+                },
+            )
+            .with_width(200.0)
+            .with_sort(move |a, b| sort[a].name.cmp(&sort[b].name))
+        };
+
+        let age = {
+            let people = people.clone();
+            let sort = people.clone();
+
+            Column::new(
+                Text::new("Age".to_string()),
+                move |index: StateSignal<usize>| {
+                    let people = people.clone();
+
+                    Text::new(index.map(move |index| Ref::Owned(people[*index].age.to_string())))
+                },
+            )
+            .with_width(80.0)
+            .with_min_width(50.0)
+            .with_align(JustifyContent::End)
+            .with_sort(move |a, b| sort[a].age.cmp(&sort[b].age))
+        };
+
+        let city = {
+            let people = people.clone();
+            let sort = people.clone();
+
+            Column::new(
+                Text::new("City".to_string()),
+                move |index: StateSignal<usize>| {
+                    let people = people.clone();
+
+                    Text::new(index.map(move |index| Ref::Owned(people[*index].city.to_string())))
+                },
+            )
+            .with_sort(move |a, b| sort[a].city.cmp(sort[b].city))
+        };
+
+        Container::new(vec![
+            Box::new(
+                Table::new(people.len(), vec![name, age, city])
+                    .with_selection(selection.maybe())
+                    .with_selection_mode(SelectionMode::Multiple)
+                    .with_layout_style(LayoutStyle {
+                        size: Vector2::new(Dimension::length(500.0), Dimension::length(400.0)),
+                        scrollbar_width: 10.0,
+                        ..Default::default()
+                    }),
+            ),
+            Box::new(Text::new(selection.map(|selection| {
+                Ref::Owned(format!("Selected: {} rows", selection.len()))
+            }))),
+        ])
+        .with_layout_style(LayoutStyle {
+            size: Vector2::<Dimension>::new(Dimension::percent(1.0), Dimension::percent(1.0)),
+            flex_direction: FlexDirection::Column,
+            align_items: Some(AlignItems::Center),
+            ..Default::default()
+        })
+    }
+
+    fn config(&self) -> MayConfig<Self::Theme, Self::Graphics> {
+        MayConfig::default()
+    }
+}
+
+fn main() {
+    MyApp.run(())
+}
@@ -0,0 +1,65 @@
+use maycoon::core::app::Application;
+use maycoon::core::app::context::AppContext;
+use maycoon::core::config::MayConfig;
+use maycoon::core::layout::{AlignItems, Dimension, FlexDirection, LayoutStyle};
+use maycoon::core::reference::Ref;
+use maycoon::core::signal::Signal;
+use maycoon::core::signal::state::StateSignal;
+use maycoon::core::vgi::DefaultGraphics;
+use maycoon::core::widget::{Widget, WidgetLayoutExt};
+use maycoon::math::Vector2;
+use maycoon::theme::theme::celeste::CelesteTheme;
+use maycoon::widgets::container::Container;
+use maycoon::widgets::text::Text;
+use maycoon::widgets::text_area::{CaretPosition, TextArea};
+
+struct MyApp;
+
+impl Application for MyApp {
+    type Theme = CelesteTheme;
+    type Graphics = DefaultGraphics;
+    type State = ();
+
+    fn build(context: AppContext, _: Self::State) -> impl Widget {
+        let text = context.use_signal(StateSignal::new(String::from(
+            "fn main() {\n    println!(\"Hello, World!\");\n}\n",
+        )));
+        let caret = context.use_signal(StateSignal::new(CaretPosition::default()));
+
+        Container::new(vec![
+            Box::new(
+                TextArea::new(text.maybe())
+                    .with_caret(caret.maybe())
+                    .with_line_numbers(true)
+                    .with_layout_style(LayoutStyle {
+                        size: Vector2::new(Dimension::length(600.0), Dimension::length(400.0)),
+                        ..Default::default()
+                    }),
+            ),
+            Box::new(Text::new(caret.map(|caret| {
+                Ref::Owned(format!(
+                    "Line {}, Column {}",
+                    caret.line + 1,
+                    caret.column + 1
+                ))
+            }))),
+            Box::new(Text::new(text.map(|text| {
+                Ref::Owned(format!("{} words", text.split_whitespace().count()))
+            }))),
+        ])
+        .with_layout_style(LayoutStyle {
+            size: Vector2::<Dimension>::new(Dimension::percent(1.0), Dimension::percent(1.0)),
+            flex_direction: FlexDirection::Column,
+            align_items: Some(AlignItems::Center),
+            ..Default::default()
+        })
+    }
+
+    fn config(&self) -> MayConfig<Self::Theme, Self::Graphics> {
+        MayConfig::default()
+    }
+}
+
+fn main() {
+    MyApp.run(())
+}
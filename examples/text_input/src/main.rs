@@ -0,0 +1,60 @@
+use maycoon::core::app::Application;
+use maycoon::core::app::context::AppContext;
+use maycoon::core::app::update::Update;
+use maycoon::core::config::MayConfig;
+use maycoon::core::layout::{AlignItems, Dimension, FlexDirection, LayoutStyle};
+use maycoon::core::reference::Ref;
+use maycoon::core::signal::Signal;
+use maycoon::core::signal::state::StateSignal;
+use maycoon::core::vgi::DefaultGraphics;
+use maycoon::core::widget::{Widget, WidgetLayoutExt};
+use maycoon::math::Vector2;
+use maycoon::theme::theme::celeste::CelesteTheme;
+use maycoon::widgets::container::Container;
+use maycoon::widgets::text::Text;
+use maycoon::widgets::text_input::TextInput;
+
+struct MyApp;
+
+impl Application for MyApp {
+    type Theme = CelesteTheme;
+    type Graphics = DefaultGraphics;
+    type State = ();
+
+    fn build(context: AppContext, _: Self::State) -> impl Widget {
+        let name = context.use_signal(StateSignal::new(String::new()));
+        let greeting = context.use_signal(StateSignal::new(String::from("Who are you?")));
+
+        Container::new(vec![
+            {
+                let name = name.clone();
+                let greeting = greeting.clone();
+
+                Box::new(
+                    TextInput::new(name.maybe())
+                        .with_placeholder("Enter your name".to_string())
+                        .with_on_submit(move |name, _| {
+                            greeting.set(format!("Hello, {name}!"));
+
+                            Update::DRAW
+                        }),
+                )
+            },
+            Box::new(Text::new(greeting.map(|s| Ref::Owned(s.clone())))),
+        ])
+        .with_layout_style(LayoutStyle {
+            size: Vector2::<Dimension>::new(Dimension::percent(1.0), Dimension::percent(1.0)),
+            flex_direction: FlexDirection::Column,
+            align_items: Some(AlignItems::Center),
+            ..Default::default()
+        })
+    }
+
+    fn config(&self) -> MayConfig<Self::Theme, Self::Graphics> {
+        MayConfig::default()
+    }
+}
+
+fn main() {
+    MyApp.run(())
+}
@@ -0,0 +1,102 @@
+use maycoon::core::app::Application;
+use maycoon::core::app::context::AppContext;
+use maycoon::core::app::update::Update;
+use maycoon::core::config::{MayConfig, WindowConfig};
+use maycoon::core::layout::{AlignItems, Dimension, FlexDirection, LayoutStyle};
+use maycoon::core::reference::Ref;
+use maycoon::core::signal::Signal;
+use maycoon::core::signal::state::StateSignal;
+use maycoon::core::vgi::DefaultGraphics;
+use maycoon::core::widget::{Widget, WidgetLayoutExt};
+use maycoon::math::Vector2;
+use maycoon::theme::theme::celeste::CelesteTheme;
+use maycoon::widgets::button::Button;
+use maycoon::widgets::container::Container;
+use maycoon::widgets::text::Text;
+
+struct MyApp;
+
+impl Application for MyApp {
+    type Theme = CelesteTheme;
+    type Graphics = DefaultGraphics;
+    type State = ();
+
+    fn build(context: AppContext, _: Self::State) -> impl Widget {
+        let counter = context.use_signal(StateSignal::new(0));
+
+        Container::new(vec![
+            {
+                let counter = counter.clone();
+                let ctx = context.clone();
+
+                Box::new(
+                    Button::new(Text::new("Open Window".to_string())).with_on_pressed(
+                        move |_, _| {
+                            let counter = counter.clone();
+
+                            ctx.open_window(
+                                WindowConfig {
+                                    title: "Inspector".to_string(),
+                                    size: Vector2::new(300.0, 200.0),
+                                    ..Default::default()
+                                },
+                                move |ctx| inspector(ctx, counter),
+                            );
+
+                            Update::empty()
+                        },
+                    ),
+                )
+            },
+            {
+                let counter = counter.clone();
+
+                Box::new(
+                    Button::new(Text::new("Increase".to_string())).with_on_pressed(move |_, _| {
+                        counter.mutate(|c| *c += 1);
+
+                        Update::DRAW
+                    }),
+                )
+            },
+        ])
+        .with_layout_style(LayoutStyle {
+            size: Vector2::<Dimension>::new(Dimension::percent(1.0), Dimension::percent(1.0)),
+            flex_direction: FlexDirection::Column,
+            align_items: Some(AlignItems::Center),
+            ..Default::default()
+        })
+    }
+
+    fn config(&self) -> MayConfig<Self::Theme, Self::Graphics> {
+        MayConfig::default()
+    }
+}
+
+/// Builds the root widget of a secondary window, showing the shared counter.
+fn inspector(context: AppContext, counter: StateSignal<i32>) -> impl Widget {
+    let window = context.window();
+
+    Container::new(vec![
+        Box::new(Text::new(
+            counter.map(|i| Ref::Owned(format!("Counter: {}", *i))),
+        )),
+        Box::new(
+            Button::new(Text::new("Close".to_string())).with_on_pressed(move |_, context| {
+                context.close_window(window);
+
+                Update::empty()
+            }),
+        ),
+    ])
+    .with_layout_style(LayoutStyle {
+        size: Vector2::<Dimension>::new(Dimension::percent(1.0), Dimension::percent(1.0)),
+        flex_direction: FlexDirection::Column,
+        align_items: Some(AlignItems::Center),
+        ..Default::default()
+    })
+}
+
+fn main() {
+    MyApp.run(())
+}
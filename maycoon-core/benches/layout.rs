@@ -0,0 +1,223 @@
+//! Compares re-building the whole layout tree with the incremental layout of the [WidgetTree].
+//!
+//! The incremental layout only keeps cached measurements of nodes, whose measure function is the same [Rc] as before,
+//! so a third mode creates new measure functions on every layout to show the cost of not caching them.
+//!
+//! Run with `cargo bench -p maycoon-core --features dummy-runner --bench layout`.
+
+use maycoon_core::app::context::AppContext;
+use maycoon_core::app::info::AppInfo;
+use maycoon_core::app::tree::WidgetTree;
+use maycoon_core::app::update::{Update, UpdateManager};
+use maycoon_core::layout::{
+    Dimension, FlexDirection, FlexWrap, LayoutNode, LayoutStyle, LengthPercentage, MeasureFn,
+    StyleNode,
+};
+use maycoon_core::vgi::Scene;
+use maycoon_core::vgi::dummy::DummyScene;
+use maycoon_core::widget::Widget;
+use maycoon_theme::id::WidgetId;
+use maycoon_theme::theme::Theme;
+use nalgebra::Vector2;
+use std::cell::Cell;
+use std::hint::black_box;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+/// The number of rows in the dashboard.
+const ROWS: usize = 100;
+
+/// The number of cells per row.
+const CELLS: usize = 40;
+
+/// The number of frames to measure.
+const FRAMES: u32 = 50;
+
+/// How the layout is computed every frame.
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum Mode {
+    /// The widget is replaced, so the layout tree is built from scratch.
+    ///
+    /// The complete layout forces another layout in the same frame, which only syncs the styles without measuring again.
+    Rebuild,
+    /// The layout tree is updated incrementally.
+    Incremental,
+    /// The layout tree is updated incrementally, but the widget creates new measure functions on every layout.
+    FreshMeasure,
+}
+
+/// The number of layouts and measure function calls.
+#[derive(Default)]
+struct Counter {
+    layouts: Cell<usize>,
+    measures: Cell<usize>,
+}
+
+/// A dashboard of rows with measured cells, which requests a re-layout on every update.
+///
+/// Every frame, the width of a single cell changes.
+struct Dashboard {
+    frame: usize,
+    mode: Mode,
+    counter: Rc<Counter>,
+    measure: MeasureFn,
+}
+
+impl Dashboard {
+    /// Create a new dashboard at the given frame.
+    fn new(frame: usize, mode: Mode, counter: Rc<Counter>) -> Self {
+        Self {
+            frame,
+            mode,
+            measure: measure(counter.clone()),
+            counter,
+        }
+    }
+}
+
+/// Create a measure function, which measures like a short label and counts its calls.
+fn measure(counter: Rc<Counter>) -> MeasureFn {
+    Rc::new(move |known, _| {
+        counter.measures.set(counter.measures.get() + 1);
+
+        Vector2::new(known.x.unwrap_or(60.0), known.y.unwrap_or(20.0))
+    })
+}
+
+impl Widget for Dashboard {
+    fn render(
+        &mut self,
+        _: &mut dyn Scene,
+        _: &mut dyn Theme,
+        _: &LayoutNode,
+        _: &AppInfo,
+        _: AppContext,
+    ) {
+    }
+
+    fn layout_style(&self) -> StyleNode {
+        self.counter.layouts.set(self.counter.layouts.get() + 1);
+
+        let cells = (0..ROWS)
+            .map(|row| StyleNode {
+                style: LayoutStyle {
+                    flex_wrap: FlexWrap::Wrap,
+                    gap: Vector2::new(LengthPercentage::length(4.0), LengthPercentage::length(4.0)),
+                    ..Default::default()
+                },
+                children: (0..CELLS)
+                    .map(|cell| StyleNode {
+                        style: LayoutStyle {
+                            size: Vector2::new(
+                                if (row * CELLS + cell) == self.frame % (ROWS * CELLS) {
+                                    Dimension::length(80.0)
+                                } else {
+                                    Dimension::auto()
+                                },
+                                Dimension::auto(),
+                            ),
+                            ..Default::default()
+                        },
+                        children: Vec::new(),
+                        measure: Some(if self.mode == Mode::FreshMeasure {
+                            measure(self.counter.clone())
+                        } else {
+                            self.measure.clone()
+                        }),
+                    })
+                    .collect(),
+                measure: None,
+            })
+            .collect();
+
+        StyleNode {
+            style: LayoutStyle {
+                size: Vector2::new(Dimension::percent(1.0), Dimension::auto()),
+                flex_direction: FlexDirection::Column,
+                ..Default::default()
+            },
+            children: cells,
+            measure: None,
+        }
+    }
+
+    fn update(&mut self, _: &LayoutNode, _: AppContext, _: &AppInfo) -> Update {
+        // a new dashboard is completely laid out before its first update, so it doesn't need a re-layout
+        if self.mode == Mode::Rebuild {
+            return Update::empty();
+        }
+
+        self.frame += 1;
+
+        Update::LAYOUT
+    }
+
+    fn widget_id(&self) -> WidgetId {
+        WidgetId::new("maycoon-core", "Dashboard")
+    }
+}
+
+/// Run the given number of frames and return the average time,
+/// the number of layouts and the number of measure function calls per frame.
+fn bench(mode: Mode) -> (Duration, f64, f64) {
+    let info = AppInfo {
+        size: Vector2::new(1920.0, 1080.0),
+        ..Default::default()
+    };
+    let update = UpdateManager::new();
+    let counter = Rc::new(Counter::default());
+    let mut tree = WidgetTree::new(DummyScene);
+
+    tree.resize(info.size);
+    tree.set_widget(Dashboard::new(0, mode, counter.clone()));
+    tree.update(&info, &update);
+    update.clear();
+
+    counter.layouts.set(0);
+    counter.measures.set(0);
+
+    let start = Instant::now();
+
+    for frame in 1..=FRAMES {
+        if mode == Mode::Rebuild {
+            // clears the layout tree, like before incremental layout
+            tree.set_widget(Dashboard::new(frame as usize, mode, counter.clone()));
+        }
+
+        tree.update(&info, &update);
+        black_box(tree.layout());
+        update.clear();
+    }
+
+    let elapsed = start.elapsed() / FRAMES;
+    let per_frame = |count: &Cell<usize>| count.get() as f64 / FRAMES as f64;
+
+    (
+        elapsed,
+        per_frame(&counter.layouts),
+        per_frame(&counter.measures),
+    )
+}
+
+fn main() {
+    let nodes = 1 + ROWS + ROWS * CELLS;
+
+    let modes = [
+        ("rebuild", Mode::Rebuild),
+        ("incremental", Mode::Incremental),
+        ("fresh measure", Mode::FreshMeasure),
+    ]
+    .map(|(name, mode)| (name, bench(mode)));
+
+    let rebuild = modes[0].1.0;
+
+    println!("layout of {nodes} nodes:");
+
+    for (name, (time, layouts, measures)) in modes {
+        println!(
+            "  {:<14} {time:?} per frame, {layouts} layouts and {measures} measure calls per frame, {:.2}x speedup",
+            format!("{name}:"),
+            rebuild.as_secs_f64() / time.as_secs_f64()
+        );
+    }
+}
@@ -0,0 +1,335 @@
+use std::cell::RefCell;
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+
+/// An image inside the clipboard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClipboardImage {
+    /// The width of the image in pixels.
+    pub width: usize,
+    /// The height of the image in pixels.
+    pub height: usize,
+    /// The pixels of the image in RGBA8 format, row by row.
+    pub data: Vec<u8>,
+}
+
+/// A provider for reading and writing the contents of a clipboard.
+///
+/// Implement this trait to plug a custom clipboard into the application using [Clipboard::new].
+/// Image support is optional and not available by default.
+pub trait ClipboardProvider {
+    /// Get the text inside the clipboard.
+    ///
+    /// Returns [None] if the clipboard contains no text or can't be read.
+    fn get_text(&mut self) -> Option<String>;
+
+    /// Replace the contents of the clipboard with the given text.
+    fn set_text(&mut self, text: String);
+
+    /// Get the image inside the clipboard.
+    ///
+    /// Returns [None] if the clipboard contains no image or can't be read.
+    fn get_image(&mut self) -> Option<ClipboardImage> {
+        None
+    }
+
+    /// Replace the contents of the clipboard with the given image.
+    fn set_image(&mut self, image: ClipboardImage) {
+        let _ = image;
+    }
+}
+
+/// The contents of a [MemoryClipboard].
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Content {
+    Text(String),
+    Image(ClipboardImage),
+}
+
+/// A [ClipboardProvider] storing its contents in memory.
+///
+/// The contents are only shared with the clones of the [Clipboard] using it and not with the system.
+/// This is useful for tests or platforms without a system clipboard.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryClipboard {
+    content: Option<Content>,
+}
+
+impl MemoryClipboard {
+    /// Create a new empty in-memory clipboard.
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ClipboardProvider for MemoryClipboard {
+    #[inline(always)]
+    fn get_text(&mut self) -> Option<String> {
+        match &self.content {
+            Some(Content::Text(text)) => Some(text.clone()),
+            _ => None,
+        }
+    }
+
+    #[inline(always)]
+    fn set_text(&mut self, text: String) {
+        self.content = Some(Content::Text(text));
+    }
+
+    #[inline(always)]
+    fn get_image(&mut self) -> Option<ClipboardImage> {
+        match &self.content {
+            Some(Content::Image(image)) => Some(image.clone()),
+            _ => None,
+        }
+    }
+
+    #[inline(always)]
+    fn set_image(&mut self, image: ClipboardImage) {
+        self.content = Some(Content::Image(image));
+    }
+}
+
+/// A [ClipboardProvider] using the clipboard of the operating system.
+///
+/// Errors while accessing the system clipboard are logged and treated like an empty clipboard.
+#[cfg(system_clipboard)]
+pub struct SystemClipboard {
+    clipboard: Option<arboard::Clipboard>,
+}
+
+#[cfg(system_clipboard)]
+impl SystemClipboard {
+    /// Create a new provider for the system clipboard.
+    ///
+    /// The system clipboard is connected on first use.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self { clipboard: None }
+    }
+
+    /// Get the connection to the system clipboard or try to connect to it.
+    #[inline(always)]
+    fn clipboard(&mut self) -> Option<&mut arboard::Clipboard> {
+        if self.clipboard.is_none() {
+            match arboard::Clipboard::new() {
+                Ok(clipboard) => self.clipboard = Some(clipboard),
+                Err(err) => tracing::warn!("failed to connect to system clipboard: {err}"),
+            }
+        }
+
+        self.clipboard.as_mut()
+    }
+
+    /// Log the given error, unless the clipboard just has no content of the requested kind.
+    #[inline(always)]
+    fn warn(action: &str, err: &arboard::Error) {
+        if !matches!(err, arboard::Error::ContentNotAvailable) {
+            tracing::warn!("failed to {action} system clipboard: {err}");
+        }
+    }
+}
+
+#[cfg(system_clipboard)]
+impl Default for SystemClipboard {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(system_clipboard)]
+impl ClipboardProvider for SystemClipboard {
+    #[inline(always)]
+    fn get_text(&mut self) -> Option<String> {
+        self.clipboard()?
+            .get_text()
+            .inspect_err(|err| Self::warn("read text from", err))
+            .ok()
+    }
+
+    #[inline(always)]
+    fn set_text(&mut self, text: String) {
+        if let Some(clipboard) = self.clipboard()
+            && let Err(err) = clipboard.set_text(text)
+        {
+            Self::warn("write text to", &err);
+        }
+    }
+
+    #[inline(always)]
+    fn get_image(&mut self) -> Option<ClipboardImage> {
+        self.clipboard()?
+            .get_image()
+            .map(|image| ClipboardImage {
+                width: image.width,
+                height: image.height,
+                data: image.bytes.into_owned(),
+            })
+            .inspect_err(|err| Self::warn("read image from", err))
+            .ok()
+    }
+
+    #[inline(always)]
+    fn set_image(&mut self, image: ClipboardImage) {
+        if let Some(clipboard) = self.clipboard()
+            && let Err(err) = clipboard.set_image(arboard::ImageData {
+                width: image.width,
+                height: image.height,
+                bytes: image.data.into(),
+            })
+        {
+            Self::warn("write image to", &err);
+        }
+    }
+}
+
+#[cfg(system_clipboard)]
+impl Debug for SystemClipboard {
+    #[inline(always)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SystemClipboard")
+            .field("connected", &self.clipboard.is_some())
+            .finish()
+    }
+}
+
+/// A handle to the clipboard of an application for copying and pasting text and images.
+///
+/// The contents are read and written by a [ClipboardProvider].
+/// By default, a [MemoryClipboard] is used. Applications use the `SystemClipboard`,
+/// if the `system-clipboard` feature is enabled on native platforms.
+///
+/// The clipboard is shared between all windows of an application and can be cheaply cloned.
+#[derive(Clone)]
+pub struct Clipboard {
+    provider: Rc<RefCell<dyn ClipboardProvider>>,
+}
+
+impl Clipboard {
+    /// Create a new clipboard using the given provider.
+    #[inline(always)]
+    pub fn new(provider: impl ClipboardProvider + 'static) -> Self {
+        Self {
+            provider: Rc::new(RefCell::new(provider)),
+        }
+    }
+
+    /// Create a new clipboard storing its contents in memory.
+    #[inline(always)]
+    pub fn memory() -> Self {
+        Self::new(MemoryClipboard::new())
+    }
+
+    /// Create a new clipboard using the clipboard of the operating system.
+    #[cfg(system_clipboard)]
+    #[inline(always)]
+    pub fn system() -> Self {
+        Self::new(SystemClipboard::new())
+    }
+
+    /// Get the text inside the clipboard.
+    ///
+    /// Returns [None] if the clipboard contains no text or can't be read.
+    #[inline(always)]
+    pub fn get_text(&self) -> Option<String> {
+        self.provider.borrow_mut().get_text()
+    }
+
+    /// Replace the contents of the clipboard with the given text.
+    #[inline(always)]
+    pub fn set_text(&self, text: impl Into<String>) {
+        self.provider.borrow_mut().set_text(text.into());
+    }
+
+    /// Get the image inside the clipboard.
+    ///
+    /// Returns [None] if the clipboard contains no image, can't be read or the provider doesn't support images.
+    #[inline(always)]
+    pub fn get_image(&self) -> Option<ClipboardImage> {
+        self.provider.borrow_mut().get_image()
+    }
+
+    /// Replace the contents of the clipboard with the given image.
+    ///
+    /// Does nothing if the provider doesn't support images.
+    #[inline(always)]
+    pub fn set_image(&self, image: ClipboardImage) {
+        self.provider.borrow_mut().set_image(image);
+    }
+}
+
+impl Default for Clipboard {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::memory()
+    }
+}
+
+impl Debug for Clipboard {
+    #[inline(always)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Clipboard").finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use crate::app::clipboard::{Clipboard, ClipboardImage, ClipboardProvider};
+
+    /// A provider supporting only text.
+    #[derive(Default)]
+    struct TextOnly(Option<String>);
+
+    impl ClipboardProvider for TextOnly {
+        fn get_text(&mut self) -> Option<String> {
+            self.0.clone()
+        }
+
+        fn set_text(&mut self, text: String) {
+            self.0 = Some(text);
+        }
+    }
+
+    /// Tests that the contents of an in-memory clipboard are shared between its clones.
+    #[test]
+    fn test_memory_clipboard() {
+        let clipboard = Clipboard::memory();
+        let other = clipboard.clone();
+
+        assert_eq!(clipboard.get_text(), None);
+
+        clipboard.set_text("Hello");
+
+        assert_eq!(other.get_text().as_deref(), Some("Hello"));
+        assert_eq!(other.get_image(), None);
+
+        let image = ClipboardImage {
+            width: 1,
+            height: 1,
+            data: vec![255, 0, 0, 255],
+        };
+
+        other.set_image(image.clone());
+
+        assert_eq!(clipboard.get_image(), Some(image));
+        assert_eq!(clipboard.get_text(), None);
+    }
+
+    /// Tests that providers without image support ignore images.
+    #[test]
+    fn test_custom_provider() {
+        let clipboard = Clipboard::new(TextOnly::default());
+
+        clipboard.set_text("Text");
+        clipboard.set_image(ClipboardImage {
+            width: 0,
+            height: 0,
+            data: Vec::new(),
+        });
+
+        assert_eq!(clipboard.get_text().as_deref(), Some("Text"));
+        assert_eq!(clipboard.get_image(), None);
+    }
+}
@@ -1,55 +1,300 @@
+use nalgebra::{Point2, Vector2};
+
+use crate::app::clipboard::Clipboard;
 use crate::app::diagnostics::Diagnostics;
+use crate::app::drag::DragManager;
+use crate::app::focus::FocusManager;
+use crate::app::pointer::PointerManager;
+use crate::app::shortcut::ShortcutManager;
 use crate::app::update::{Update, UpdateManager};
+use crate::app::window::{WindowCommand, WindowKey, WindowManager};
+use crate::config::{Cursor, ResizeDirection, WindowConfig, WindowLevel, WindowMode};
 use crate::signal::Signal;
 use crate::signal::eval::EvalSignal;
 use crate::signal::fixed::FixedSignal;
 use crate::signal::memoized::MemoizedSignal;
 use crate::signal::state::StateSignal;
+use crate::widget::Widget;
 
 /// The application context for managing the application lifecycle.
+///
+/// The context belongs to a single window and is not [Send], since its managers (like the [WindowManager])
+/// are shared via [Rc](std::rc::Rc) and hold widgets and signals, which are not [Send] either.
+/// To request updates from other threads (e.g. in [tasks::spawn](crate::tasks::spawn)), move the [UpdateManager] into the task instead.
 #[derive(Clone)]
 pub struct AppContext {
     update: UpdateManager,
     diagnostics: Diagnostics,
+    windows: WindowManager,
+    window: WindowKey,
+    focus: FocusManager,
+    pointer: PointerManager,
+    drag: DragManager,
+    shortcuts: ShortcutManager,
+    clipboard: Clipboard,
 }
 
 impl AppContext {
     /// Create a new application context using the given [UpdateManager].
+    ///
+    /// The context belongs to the [WindowKey::MAIN] window and has its own [WindowManager].
+    /// Use [AppContext::with_window] to share the window manager of an application.
     #[inline(always)]
     #[tracing::instrument(level = "trace", skip_all)]
     pub fn new(update: UpdateManager, diagnostics: Diagnostics) -> Self {
         Self {
             update,
             diagnostics,
+            windows: WindowManager::new(),
+            window: WindowKey::MAIN,
+            focus: FocusManager::new(),
+            pointer: PointerManager::new(),
+            drag: DragManager::new(),
+            shortcuts: ShortcutManager::new(),
+            clipboard: Clipboard::default(),
         }
     }
 
+    /// Set the [WindowManager] of the application and the key of the window this context belongs to.
+    #[inline(always)]
+    pub fn with_window(mut self, windows: WindowManager, window: WindowKey) -> Self {
+        self.windows = windows;
+        self.window = window;
+        self
+    }
+
+    /// Set the [FocusManager] of the window this context belongs to.
+    #[inline(always)]
+    pub fn with_focus(mut self, focus: FocusManager) -> Self {
+        self.focus = focus;
+        self
+    }
+
+    /// Set the [PointerManager] of the window this context belongs to.
+    #[inline(always)]
+    pub fn with_pointer(mut self, pointer: PointerManager) -> Self {
+        self.pointer = pointer;
+        self
+    }
+
+    /// Set the [DragManager] of the window this context belongs to.
+    #[inline(always)]
+    pub fn with_drag(mut self, drag: DragManager) -> Self {
+        self.drag = drag;
+        self
+    }
+
     /// Get the [Diagnostics] of the application.
     #[inline(always)]
     pub const fn diagnostics(&self) -> Diagnostics {
         self.diagnostics
     }
 
-    /// Get the [UpdateManager] of the application.
+    /// Get the [UpdateManager] of the window.
+    ///
+    /// Unlike the context, the update manager is [Send].
     #[inline(always)]
     pub fn update(&self) -> UpdateManager {
         self.update.clone()
     }
 
+    /// Get the [WindowManager] of the application.
+    #[inline(always)]
+    pub fn windows(&self) -> WindowManager {
+        self.windows.clone()
+    }
+
+    /// Get the key of the window this context belongs to.
+    #[inline(always)]
+    pub const fn window(&self) -> WindowKey {
+        self.window
+    }
+
+    /// Set the [ShortcutManager] of the window this context belongs to.
+    #[inline(always)]
+    pub fn with_shortcuts(mut self, shortcuts: ShortcutManager) -> Self {
+        self.shortcuts = shortcuts;
+        self
+    }
+
+    /// Set the [Clipboard] of the application.
+    #[inline(always)]
+    pub fn with_clipboard(mut self, clipboard: Clipboard) -> Self {
+        self.clipboard = clipboard;
+        self
+    }
+
+    /// Get the [FocusManager] of the window this context belongs to.
+    #[inline(always)]
+    pub fn focus(&self) -> FocusManager {
+        self.focus.clone()
+    }
+
+    /// Get the [PointerManager] of the window this context belongs to.
+    #[inline(always)]
+    pub fn pointer(&self) -> PointerManager {
+        self.pointer.clone()
+    }
+
+    /// Get the [DragManager] of the window this context belongs to.
+    #[inline(always)]
+    pub fn drag(&self) -> DragManager {
+        self.drag.clone()
+    }
+
+    /// Get the [ShortcutManager] of the window this context belongs to.
+    #[inline(always)]
+    pub fn shortcuts(&self) -> ShortcutManager {
+        self.shortcuts.clone()
+    }
+
+    /// Get the [Clipboard] of the application for copying and pasting text and images.
+    #[inline(always)]
+    pub fn clipboard(&self) -> Clipboard {
+        self.clipboard.clone()
+    }
+
+    /// Open a new window with the given configuration.
+    ///
+    /// The root widget is built with the context of the new window, once the window is created.
+    /// The new window shares the theme, fonts and task runner with the rest of the application.
+    ///
+    /// Returns the key of the new window.
+    #[inline(always)]
+    pub fn open_window<W: Widget + 'static>(
+        &self,
+        config: WindowConfig,
+        builder: impl FnOnce(AppContext) -> W + 'static,
+    ) -> WindowKey {
+        self.update.insert(Update::EVAL);
+        self.windows
+            .open(config, Box::new(move |ctx| Box::new(builder(ctx))))
+    }
+
+    /// Close the window with the given key.
+    ///
+    /// Closing the [WindowKey::MAIN] window exits the application.
+    #[inline(always)]
+    pub fn close_window(&self, window: WindowKey) {
+        self.update.insert(Update::EVAL);
+        self.windows.close(window);
+    }
+
+    /// Allow or disallow IME input for the window this context belongs to.
+    ///
+    /// Text input widgets should allow IME while they are focused,
+    /// so they receive [Ime](winit::event::Ime) events in [AppInfo::ime](crate::app::info::AppInfo::ime).
+    #[inline(always)]
+    pub fn set_ime_allowed(&self, allowed: bool) {
+        self.windows.set_ime_allowed(self.window, allowed);
+    }
+
+    /// Set the area of the text cursor in the window this context belongs to,
+    /// so the IME candidate box can be placed next to it.
+    #[inline(always)]
+    pub fn set_ime_cursor_area(&self, position: Vector2<f64>, size: Vector2<f64>) {
+        self.windows
+            .set_ime_cursor_area(self.window, position, size);
+    }
+
+    /// Set the title of the window this context belongs to.
+    #[inline(always)]
+    pub fn set_window_title(&self, title: impl Into<String>) {
+        self.window_command(WindowCommand::SetTitle(title.into()));
+    }
+
+    /// Request to resize the inner area of the window this context belongs to to the given logical size.
+    ///
+    /// The new size is available in [AppInfo::size](crate::app::info::AppInfo::size), once the window is resized.
+    #[inline(always)]
+    pub fn set_window_size(&self, size: Vector2<f64>) {
+        self.window_command(WindowCommand::SetSize(size));
+    }
+
+    /// Move the window this context belongs to to the given logical position on the screen.
+    #[inline(always)]
+    pub fn set_window_position(&self, position: Point2<f64>) {
+        self.window_command(WindowCommand::SetPosition(position));
+    }
+
+    /// Minimize or restore the window this context belongs to.
+    #[inline(always)]
+    pub fn set_window_minimized(&self, minimized: bool) {
+        self.window_command(WindowCommand::SetMinimized(minimized));
+    }
+
+    /// Maximize or restore the window this context belongs to.
+    #[inline(always)]
+    pub fn set_window_maximized(&self, maximized: bool) {
+        self.window_command(WindowCommand::SetMaximized(maximized));
+    }
+
+    /// Switch the [WindowMode] of the window this context belongs to.
+    #[inline(always)]
+    pub fn set_window_mode(&self, mode: WindowMode) {
+        self.window_command(WindowCommand::SetMode(mode));
+    }
+
+    /// Set the [WindowLevel] of the window this context belongs to.
+    #[inline(always)]
+    pub fn set_window_level(&self, level: WindowLevel) {
+        self.window_command(WindowCommand::SetLevel(level));
+    }
+
+    /// Enable or disable the decorations (borders and title bar) of the window this context belongs to.
+    #[inline(always)]
+    pub fn set_window_decorations(&self, decorations: bool) {
+        self.window_command(WindowCommand::SetDecorations(decorations));
+    }
+
+    /// Set the cursor of the window this context belongs to.
+    #[inline(always)]
+    pub fn set_cursor(&self, cursor: impl Into<Cursor>) {
+        self.window_command(WindowCommand::SetCursor(cursor.into()));
+    }
+
+    /// Start moving the window this context belongs to with the cursor, until the left mouse button is released.
+    ///
+    /// This should be called while the left mouse button is pressed, e.g. on a custom title bar.
+    #[inline(always)]
+    pub fn drag_window(&self) {
+        self.window_command(WindowCommand::Drag);
+    }
+
+    /// Start resizing the window this context belongs to in the given direction with the cursor,
+    /// until the left mouse button is released.
+    ///
+    /// This should be called while the left mouse button is pressed, e.g. on a custom window border.
+    #[inline(always)]
+    pub fn drag_resize_window(&self, direction: ResizeDirection) {
+        self.window_command(WindowCommand::DragResize(direction));
+    }
+
+    /// Apply the given [WindowCommand] to the window this context belongs to.
+    #[inline(always)]
+    fn window_command(&self, command: WindowCommand) {
+        self.windows.command(self.window, command);
+    }
+
     /// Make the application exit by setting [Update::EXIT].
     #[inline(always)]
     pub fn exit(&self) {
         self.update.insert(Update::EXIT);
     }
 
-    /// Hook the given [Signal] to the [UpdateManager] of this application and return it.
+    /// Hook the given [Signal] to the [UpdateManager]s of all windows and return it.
     ///
     /// This makes the signal reactive, so it will notify the renderer when the inner value changes.
+    /// Since signals can be shared between windows, a change updates every window of the application.
     #[inline(always)]
     pub fn use_signal<T: 'static, S: Signal<T>>(&self, signal: S) -> S {
         let update = self.update();
+        let windows = self.windows.clone();
 
-        signal.listen(Box::new(move |_| update.insert(Update::EVAL)))
+        signal.listen(Box::new(move |_| {
+            update.insert(Update::EVAL);
+            windows.update_all(Update::EVAL);
+        }))
     }
 
     /// Shortcut for creating and hooking a [StateSignal] into the application lifecycle.
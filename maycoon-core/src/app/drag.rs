@@ -0,0 +1,450 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::fmt::{Debug, Formatter};
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use nalgebra::Vector2;
+use winit::event::MouseButton;
+use winit::keyboard::{Key, NamedKey};
+
+use crate::app::info::AppInfo;
+use crate::vgi::Scene;
+use crate::vgi::kurbo::Affine;
+
+/// The payload of files dragged onto the window by the operating system.
+///
+/// Widgets can accept dropped files like any other payload, using [DragManager::accept] with this type.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Files {
+    /// The paths of the dragged files.
+    pub paths: Vec<PathBuf>,
+}
+
+/// Manages the drag-and-drop operation of a window.
+///
+/// A drag is started by a widget using [DragManager::start] with a payload of any type.
+/// While dragging, the payload can be inspected by other widgets (e.g. to show hover feedback)
+/// and an optional preview, set via [DragManager::set_preview], is drawn at the cursor above all widgets.
+/// When the left mouse button is released, the payload is dropped
+/// and can be taken by the widget under the cursor using [DragManager::accept].
+/// Pressing escape cancels the drag.
+///
+/// Files dragged onto the window by the operating system are handled the same way,
+/// with a payload of type [Files].
+///
+/// Dropped payloads, which are not accepted during the update pass of the drop, are discarded.
+///
+/// The manager belongs to a single window and can be cheaply cloned.
+#[derive(Clone, Default)]
+pub struct DragManager {
+    inner: Rc<RefCell<DragState>>,
+}
+
+#[derive(Default)]
+struct DragState {
+    payload: Option<Box<dyn Any>>,
+    preview: Option<Preview>,
+    cursor: Option<Vector2<f32>>,
+    moved: bool,
+    files: bool,
+    dropped: bool,
+}
+
+/// The preview of the dragged payload.
+struct Preview {
+    scene: Box<dyn Scene>,
+    anchor: Vector2<f32>,
+}
+
+impl DragManager {
+    /// Create a new drag manager without an active drag.
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start dragging the given payload, replacing the current drag.
+    #[inline(always)]
+    pub fn start<T: 'static>(&self, payload: T) {
+        let mut inner = self.inner.borrow_mut();
+
+        inner.payload = Some(Box::new(payload));
+        inner.preview = None;
+        inner.files = false;
+        inner.dropped = false;
+    }
+
+    /// Cancel the current drag. The payload is discarded.
+    #[inline(always)]
+    pub fn cancel(&self) {
+        let mut inner = self.inner.borrow_mut();
+
+        inner.payload = None;
+        inner.files = false;
+        inner.dropped = false;
+    }
+
+    /// Set the preview of the current drag.
+    ///
+    /// The given scene is drawn above all widgets and moved with the cursor,
+    /// so that the given anchor point of the scene is at the cursor position.
+    /// Widgets usually render the preview on every draw, so it reflects their current state.
+    #[inline(always)]
+    pub fn set_preview(&self, scene: Box<dyn Scene>, anchor: Vector2<f32>) {
+        self.inner.borrow_mut().preview = Some(Preview { scene, anchor });
+    }
+
+    /// Returns if a payload is currently dragged.
+    #[inline(always)]
+    pub fn is_dragging(&self) -> bool {
+        self.inner.borrow().payload.is_some()
+    }
+
+    /// Returns if the currently dragged payload is of the given type.
+    #[inline(always)]
+    pub fn is_dragging_type<T: 'static>(&self) -> bool {
+        self.inner
+            .borrow()
+            .payload
+            .as_ref()
+            .is_some_and(|payload| payload.is::<T>())
+    }
+
+    /// Run the given function with the currently dragged payload.
+    ///
+    /// Returns [None] if nothing is dragged or the payload is not of the given type.
+    #[inline(always)]
+    pub fn with_payload<T: 'static, R>(&self, f: impl FnOnce(&T) -> R) -> Option<R> {
+        self.inner
+            .borrow()
+            .payload
+            .as_ref()
+            .and_then(|payload| payload.downcast_ref::<T>())
+            .map(f)
+    }
+
+    /// Returns if the current payload was dropped in this update pass and has not been accepted yet.
+    #[inline(always)]
+    pub fn is_dropped(&self) -> bool {
+        let inner = self.inner.borrow();
+
+        inner.dropped && inner.payload.is_some()
+    }
+
+    /// Take the payload, if it was dropped in this update pass and is of the given type.
+    ///
+    /// Only one widget can accept a payload.
+    /// Widgets should only accept payloads, which were dropped onto them.
+    #[inline(always)]
+    pub fn accept<T: 'static>(&self) -> Option<T> {
+        let mut inner = self.inner.borrow_mut();
+
+        if !inner.dropped || !inner.payload.as_ref().is_some_and(|p| p.is::<T>()) {
+            return None;
+        }
+
+        inner
+            .payload
+            .take()
+            .and_then(|payload| payload.downcast::<T>().ok())
+            .map(|payload| *payload)
+    }
+
+    /// Prepare a new update pass and handle the drop, cancel and file events of the given [AppInfo].
+    #[tracing::instrument(level = "trace", skip_all)]
+    pub(crate) fn begin(&self, info: &AppInfo) {
+        let mut inner = self.inner.borrow_mut();
+
+        inner.moved = inner.cursor != info.cursor_pos;
+        inner.cursor = info.cursor_pos;
+
+        if !info.dropped_files.is_empty() {
+            inner.payload = Some(Box::new(Files {
+                paths: info.dropped_files.clone(),
+            }));
+            inner.files = true;
+            inner.dropped = true;
+            return;
+        }
+
+        if inner.files && info.hovered_files.is_empty() {
+            // the files were dragged out of the window
+            inner.payload = None;
+            inner.files = false;
+        } else if !info.hovered_files.is_empty() && (inner.files || inner.payload.is_none()) {
+            inner.payload = Some(Box::new(Files {
+                paths: info.hovered_files.clone(),
+            }));
+            inner.files = true;
+        }
+
+        if inner.payload.is_none() || inner.files {
+            return;
+        }
+
+        if info
+            .keys
+            .iter()
+            .any(|(_, key)| key.is_pressed() && key.logical_key == Key::Named(NamedKey::Escape))
+        {
+            inner.payload = None;
+        } else if info
+            .buttons
+            .iter()
+            .any(|(_, btn, el)| *btn == MouseButton::Left && !el.is_pressed())
+        {
+            inner.dropped = true;
+        }
+    }
+
+    /// Finish the update pass and discard the dropped payload.
+    ///
+    /// Returns if the preview needs to be redrawn.
+    #[tracing::instrument(level = "trace", skip_all)]
+    pub(crate) fn end(&self) -> bool {
+        let mut inner = self.inner.borrow_mut();
+
+        if inner.dropped {
+            inner.payload = None;
+            inner.files = false;
+            inner.dropped = false;
+        }
+
+        if inner.payload.is_none() {
+            return inner.preview.take().is_some();
+        }
+
+        inner.preview.is_some() && inner.moved
+    }
+
+    /// Draw the preview of the current drag at the cursor position onto the given scene.
+    #[tracing::instrument(level = "trace", skip_all)]
+    pub(crate) fn draw(&self, scene: &mut dyn Scene, info: &AppInfo) {
+        let inner = self.inner.borrow();
+
+        if let (Some(preview), Some(cursor), true) =
+            (&inner.preview, info.cursor_pos, inner.payload.is_some())
+        {
+            let offset = cursor - preview.anchor;
+
+            scene.append(
+                preview.scene.as_ref(),
+                Some(Affine::translate((offset.x as f64, offset.y as f64))),
+            );
+        }
+    }
+}
+
+impl Debug for DragManager {
+    #[inline(always)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let inner = self.inner.borrow();
+
+        f.debug_struct("DragManager")
+            .field("dragging", &inner.payload.is_some())
+            .field("files", &inner.files)
+            .field("dropped", &inner.dropped)
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use crate::app::context::AppContext;
+    use crate::app::drag::{DragManager, Files};
+    use crate::app::info::AppInfo;
+    use crate::app::update::Update;
+    use crate::layout::{LayoutNode, LayoutStyle, StyleNode};
+    use crate::testing::Harness;
+    use crate::vgi::color::palette;
+    use crate::vgi::kurbo::{Affine, Rect};
+    use crate::vgi::recording::{DrawCommand, RecordingScene};
+    use crate::vgi::{Brush, Scene};
+    use crate::widget::Widget;
+    use maycoon_theme::id::WidgetId;
+    use maycoon_theme::theme::Theme;
+    use maycoon_theme::theme::dummy::DummyTheme;
+    use nalgebra::Vector2;
+    use std::cell::RefCell;
+    use std::path::PathBuf;
+    use std::rc::Rc;
+    use winit::event::{ElementState, MouseButton};
+    use winit::keyboard::{Key, NamedKey};
+
+    /// A widget accepting dropped payloads of the given type.
+    struct Target<T> {
+        accepted: Rc<RefCell<Vec<T>>>,
+    }
+
+    impl<T: 'static> Widget for Target<T> {
+        fn render(
+            &mut self,
+            _: &mut dyn Scene,
+            _: &mut dyn Theme,
+            _: &LayoutNode,
+            _: &AppInfo,
+            _: AppContext,
+        ) {
+        }
+
+        fn layout_style(&self) -> StyleNode {
+            StyleNode {
+                style: LayoutStyle::default(),
+                children: Vec::new(),
+                measure: None,
+            }
+        }
+
+        fn update(&mut self, _: &LayoutNode, context: AppContext, _: &AppInfo) -> Update {
+            if let Some(payload) = context.drag().accept::<T>() {
+                self.accepted.borrow_mut().push(payload);
+            }
+
+            Update::empty()
+        }
+
+        fn widget_id(&self) -> WidgetId {
+            WidgetId::new("maycoon-core", "Target")
+        }
+    }
+
+    /// Create a harness with a [Target] accepting payloads of the given type.
+    fn harness<T: 'static>() -> (
+        Harness<Target<T>, DummyTheme>,
+        DragManager,
+        Rc<RefCell<Vec<T>>>,
+    ) {
+        let accepted = Rc::new(RefCell::new(Vec::new()));
+
+        let mut harness = Harness::new(
+            Target {
+                accepted: accepted.clone(),
+            },
+            DummyTheme::default(),
+            Vector2::new(100.0, 100.0),
+        );
+
+        harness.frame();
+
+        let drag = harness.context().drag();
+
+        (harness, drag, accepted)
+    }
+
+    /// Test dropping a payload by releasing the left mouse button.
+    #[test]
+    fn test_drop() {
+        let (mut harness, drag, accepted) = harness::<i32>();
+
+        drag.start(42);
+        harness.frame();
+
+        assert!(drag.is_dragging_type::<i32>());
+        assert_eq!(drag.with_payload(|payload: &i32| *payload), Some(42));
+        assert!(accepted.borrow().is_empty());
+
+        harness.mouse_input(MouseButton::Left, ElementState::Released);
+        harness.frame();
+
+        assert!(!drag.is_dragging());
+        assert_eq!(*accepted.borrow(), vec![42]);
+    }
+
+    /// Test that dropped payloads of other types are discarded and escape cancels the drag.
+    #[test]
+    fn test_discard() {
+        let (mut harness, drag, accepted) = harness::<i32>();
+
+        drag.start("text");
+        harness.mouse_input(MouseButton::Left, ElementState::Released);
+        harness.frame();
+
+        assert!(!drag.is_dragging());
+
+        drag.start(42);
+        harness.press_key(Key::Named(NamedKey::Escape));
+        harness.frame();
+
+        assert!(!drag.is_dragging());
+
+        harness.mouse_input(MouseButton::Left, ElementState::Released);
+        harness.frame();
+
+        assert!(accepted.borrow().is_empty());
+    }
+
+    /// Test dropping files from the operating system.
+    #[test]
+    fn test_files() {
+        let (mut harness, drag, accepted) = harness::<Files>();
+
+        harness.hover_files([PathBuf::from("a.txt")]);
+        harness.frame();
+
+        assert_eq!(
+            drag.with_payload(|files: &Files| files.paths.len()),
+            Some(1)
+        );
+
+        harness.cancel_files();
+        harness.frame();
+
+        assert!(!drag.is_dragging());
+
+        harness.hover_files([PathBuf::from("a.txt"), PathBuf::from("b.txt")]);
+        harness.frame();
+        harness.drop_files();
+        harness.frame();
+
+        assert!(!drag.is_dragging());
+        assert_eq!(
+            *accepted.borrow(),
+            vec![Files {
+                paths: vec![PathBuf::from("a.txt"), PathBuf::from("b.txt")]
+            }]
+        );
+    }
+
+    /// Test drawing the preview at the cursor position.
+    #[test]
+    fn test_preview() {
+        let mut harness = Harness::with_scene(
+            Target::<i32> {
+                accepted: Rc::new(RefCell::new(Vec::new())),
+            },
+            DummyTheme::default(),
+            Vector2::new(100.0, 100.0),
+            RecordingScene::default(),
+        );
+
+        harness.frame();
+
+        let drag = harness.context().drag();
+        let mut preview = RecordingScene::default();
+
+        preview.draw_rect(
+            &Brush::Solid(palette::css::RED),
+            None,
+            None,
+            &Rect::new(0.0, 0.0, 10.0, 10.0),
+        );
+
+        drag.start(42);
+        drag.set_preview(Box::new(preview), Vector2::new(5.0, 5.0));
+        harness.cursor_move(Vector2::new(50.0, 40.0));
+
+        assert!(harness.frame().contains(Update::DRAW));
+        assert!(matches!(
+            harness.scene().commands().last(),
+            Some(DrawCommand::Append { transform: Some(transform), commands })
+                if *transform == Affine::translate((45.0, 35.0)) && commands.len() == 1
+        ));
+
+        drag.cancel();
+        harness.cursor_move(Vector2::new(60.0, 40.0));
+
+        assert!(harness.frame().contains(Update::DRAW));
+        assert!(harness.scene().commands().is_empty());
+    }
+}
@@ -0,0 +1,528 @@
+use std::cell::RefCell;
+use std::fmt::{Debug, Formatter};
+use std::ops::Range;
+use std::rc::Rc;
+
+use winit::event::{Ime, MouseButton};
+use winit::keyboard::{Key, NamedKey};
+
+use crate::app::info::AppInfo;
+use crate::app::input::KeyInput;
+
+/// A handle to a focusable widget, returned by [FocusManager::register].
+///
+/// The handle is only valid for the current update pass and should be re-acquired on every update.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Focus(usize);
+
+impl Focus {
+    /// Get the index of the widget in the focus order (tree order).
+    #[inline(always)]
+    pub const fn index(&self) -> usize {
+        self.0
+    }
+}
+
+/// Tracks the keyboard focus of a widget tree.
+///
+/// Focusable widgets call [FocusManager::register] on every update, which assigns them an index in tree order.
+/// Pressing `Tab` focuses the next and `Shift+Tab` the previous focusable widget.
+/// Pressing the left mouse button clears the focus, unless a widget requests it using [FocusManager::request].
+///
+/// Key input should only be handled by the focused widget using [FocusManager::keys].
+/// Keys handled by a shortcut of the [ShortcutManager](crate::app::shortcut::ShortcutManager) are not returned.
+/// The focused widget can use [FocusManager::capture_tab] to receive `Tab` itself,
+/// in which case `Ctrl+Tab` and `Ctrl+Shift+Tab` move the focus instead.
+///
+/// The focus is identified by its index, so adding or removing focusable widgets
+/// before the focused widget moves the focus to another widget.
+///
+/// The manager belongs to a single window and can be cheaply cloned.
+#[derive(Clone, Default)]
+pub struct FocusManager {
+    inner: Rc<RefCell<FocusState>>,
+}
+
+#[derive(Debug, Default)]
+struct FocusState {
+    focused: Option<usize>,
+    previous: Option<usize>,
+    next: usize,
+    count: usize,
+    requested: bool,
+    pointer_pressed: bool,
+    tab_captured: bool,
+    capture_tab: bool,
+    handled: Vec<usize>,
+}
+
+impl FocusManager {
+    /// Create a new focus manager with no focused widget.
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a focusable widget for the current update pass.
+    ///
+    /// This must be called on every update, before checking for focus or key input.
+    #[inline(always)]
+    pub fn register(&self) -> Focus {
+        let mut inner = self.inner.borrow_mut();
+
+        let focus = Focus(inner.next);
+
+        inner.next += 1;
+
+        focus
+    }
+
+    /// Run the given function and return its result together with the range of focus indices registered inside it.
+    ///
+    /// Widgets with children can update their children inside this function to get the scope of their subtree,
+    /// e.g. for [ShortcutManager::register_scoped](crate::app::shortcut::ShortcutManager::register_scoped).
+    #[inline(always)]
+    pub fn scope<R>(&self, f: impl FnOnce() -> R) -> (R, Range<usize>) {
+        let start = self.inner.borrow().next;
+
+        let result = f();
+
+        (result, start..self.inner.borrow().next)
+    }
+
+    /// Returns if the given widget is focused.
+    #[inline(always)]
+    pub fn is_focused(&self, focus: Focus) -> bool {
+        self.inner.borrow().focused == Some(focus.0)
+    }
+
+    /// Get the index of the focused widget. Returns [None] if no widget is focused.
+    #[inline(always)]
+    pub fn focused(&self) -> Option<usize> {
+        self.inner.borrow().focused
+    }
+
+    /// Get the number of focusable widgets registered during the last update pass.
+    #[inline(always)]
+    pub fn count(&self) -> usize {
+        self.inner.borrow().count
+    }
+
+    /// Focus the given widget.
+    ///
+    /// Usually called when a focusable widget is clicked.
+    #[inline(always)]
+    pub fn request(&self, focus: Focus) {
+        let mut inner = self.inner.borrow_mut();
+
+        inner.focused = Some(focus.0);
+        inner.requested = true;
+    }
+
+    /// Capture `Tab` key input, if the given widget is focused.
+    ///
+    /// This must be called on every update to keep capturing `Tab`.
+    /// Useful for text editors to insert indentation.
+    #[inline(always)]
+    pub fn capture_tab(&self, focus: Focus) {
+        let mut inner = self.inner.borrow_mut();
+
+        if inner.focused == Some(focus.0) {
+            inner.capture_tab = true;
+        }
+    }
+
+    /// Focus the next focusable widget in tree order, wrapping around at the end.
+    #[inline(always)]
+    pub fn focus_next(&self) {
+        let mut inner = self.inner.borrow_mut();
+
+        if inner.count == 0 {
+            return;
+        }
+
+        inner.focused = Some(match inner.focused {
+            Some(i) => (i + 1) % inner.count,
+            None => 0,
+        });
+    }
+
+    /// Focus the previous focusable widget in tree order, wrapping around at the start.
+    #[inline(always)]
+    pub fn focus_previous(&self) {
+        let mut inner = self.inner.borrow_mut();
+
+        if inner.count == 0 {
+            return;
+        }
+
+        inner.focused = Some(match inner.focused {
+            Some(0) | None => inner.count - 1,
+            Some(i) => i - 1,
+        });
+    }
+
+    /// Clear the focus, so no widget is focused.
+    #[inline(always)]
+    pub fn clear(&self) {
+        self.inner.borrow_mut().focused = None;
+    }
+
+    /// Returns the key events for the given widget.
+    ///
+    /// If the widget is not focused, no events are returned.
+    /// Key events used for focus traversal or handled by a shortcut are never returned.
+    #[inline(always)]
+    pub fn keys<'a>(
+        &self,
+        focus: Focus,
+        info: &'a AppInfo,
+    ) -> impl Iterator<Item = &'a KeyInput> + use<'a> {
+        let focused = self.is_focused(focus);
+        let traversal = self.traversal(info);
+        let handled = self.inner.borrow().handled.clone();
+
+        info.keys
+            .iter()
+            .enumerate()
+            .filter(move |(i, _)| !handled.contains(i))
+            .map(|(_, (_, key))| key)
+            .filter(move |key| focused && !(traversal && is_tab(key)))
+    }
+
+    /// Returns if `Tab` key input is used for focus traversal in the current update pass.
+    #[inline(always)]
+    fn traversal(&self, info: &AppInfo) -> bool {
+        !self.inner.borrow().tab_captured || info.modifiers.control_key()
+    }
+
+    /// Returns the IME events for the given widget.
+    ///
+    /// If the widget is not focused, no events are returned.
+    #[inline(always)]
+    pub fn ime<'a>(
+        &self,
+        focus: Focus,
+        info: &'a AppInfo,
+    ) -> impl Iterator<Item = &'a Ime> + use<'a> {
+        let focused = self.is_focused(focus);
+
+        info.ime.iter().filter(move |_| focused)
+    }
+
+    /// Prepare a new update pass and apply focus traversal using the key input of the given [AppInfo].
+    #[tracing::instrument(level = "trace", skip_all)]
+    pub(crate) fn begin(&self, info: &AppInfo) {
+        {
+            let mut inner = self.inner.borrow_mut();
+
+            inner.previous = inner.focused;
+            inner.next = 0;
+            inner.requested = false;
+            inner.tab_captured = inner.capture_tab;
+            inner.capture_tab = false;
+            inner.pointer_pressed = info
+                .buttons
+                .iter()
+                .any(|(_, btn, el)| *btn == MouseButton::Left && el.is_pressed());
+        }
+
+        if !self.traversal(info) {
+            return;
+        }
+
+        for (_, key) in &info.keys {
+            if key.is_pressed() && is_tab(key) {
+                if info.modifiers.shift_key() {
+                    self.focus_previous();
+                } else {
+                    self.focus_next();
+                }
+            }
+        }
+    }
+
+    /// Set the indices of the key inputs in [AppInfo::keys] handled by shortcuts in the current update pass.
+    #[inline(always)]
+    pub(crate) fn set_handled(&self, handled: Vec<usize>) {
+        self.inner.borrow_mut().handled = handled;
+    }
+
+    /// Finish the update pass.
+    ///
+    /// Returns if the focus changed during the pass, so the widgets need to be redrawn.
+    #[tracing::instrument(level = "trace", skip_all)]
+    pub(crate) fn end(&self) -> bool {
+        let mut inner = self.inner.borrow_mut();
+
+        inner.count = inner.next;
+
+        // a click outside any focusable widget clears the focus
+        if inner.pointer_pressed && !inner.requested {
+            inner.focused = None;
+        }
+
+        // the focused widget might have been removed
+        if inner.focused.is_some_and(|i| i >= inner.count) {
+            inner.focused = None;
+        }
+
+        inner.focused != inner.previous
+    }
+}
+
+impl Debug for FocusManager {
+    #[inline(always)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let inner = self.inner.borrow();
+
+        f.debug_struct("FocusManager")
+            .field("focused", &inner.focused)
+            .field("count", &inner.count)
+            .finish()
+    }
+}
+
+/// Returns if the given key is the `Tab` key used for focus traversal.
+#[inline(always)]
+fn is_tab(key: &KeyInput) -> bool {
+    key.logical_key == Key::Named(NamedKey::Tab)
+}
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use crate::app::context::AppContext;
+    use crate::app::info::AppInfo;
+    use crate::app::update::Update;
+    use crate::layout;
+    use crate::layout::{Dimension, FlexDirection, LayoutNode, LayoutStyle, StyleNode};
+    use crate::testing::Harness;
+    use crate::vgi::Scene;
+    use crate::widget::Widget;
+    use maycoon_theme::id::WidgetId;
+    use maycoon_theme::theme::Theme;
+    use maycoon_theme::theme::dummy::DummyTheme;
+    use nalgebra::Vector2;
+    use winit::keyboard::{Key, ModifiersState, NamedKey};
+
+    /// A focusable widget counting the keys it receives.
+    #[derive(Default)]
+    struct Focusable {
+        keys: usize,
+        capture_tab: bool,
+    }
+
+    impl Widget for Focusable {
+        fn render(
+            &mut self,
+            _: &mut dyn Scene,
+            _: &mut dyn Theme,
+            _: &LayoutNode,
+            _: &AppInfo,
+            _: AppContext,
+        ) {
+        }
+
+        fn layout_style(&self) -> StyleNode {
+            StyleNode {
+                style: LayoutStyle {
+                    size: Vector2::new(Dimension::length(10.0), Dimension::length(10.0)),
+                    ..Default::default()
+                },
+                children: Vec::new(),
+                measure: None,
+            }
+        }
+
+        fn update(&mut self, layout: &LayoutNode, context: AppContext, info: &AppInfo) -> Update {
+            let focus = context.focus().register();
+
+            if self.capture_tab {
+                context.focus().capture_tab(focus);
+            }
+
+            if let Some(cursor) = info.cursor_pos
+                && layout::intersects(cursor, &layout.layout)
+                && !info.buttons.is_empty()
+            {
+                context.focus().request(focus);
+            }
+
+            self.keys += context
+                .focus()
+                .keys(focus, info)
+                .filter(|k| k.is_pressed())
+                .count();
+
+            Update::empty()
+        }
+
+        fn widget_id(&self) -> WidgetId {
+            WidgetId::new("maycoon-core", "Focusable")
+        }
+    }
+
+    /// A row of focusable widgets.
+    #[derive(Default)]
+    struct Row {
+        children: [Focusable; 3],
+    }
+
+    impl Widget for Row {
+        fn render(
+            &mut self,
+            _: &mut dyn Scene,
+            _: &mut dyn Theme,
+            _: &LayoutNode,
+            _: &AppInfo,
+            _: AppContext,
+        ) {
+        }
+
+        fn layout_style(&self) -> StyleNode {
+            StyleNode {
+                style: LayoutStyle {
+                    flex_direction: FlexDirection::Row,
+                    ..Default::default()
+                },
+                children: self.children.iter().map(|c| c.layout_style()).collect(),
+                measure: None,
+            }
+        }
+
+        fn update(&mut self, layout: &LayoutNode, context: AppContext, info: &AppInfo) -> Update {
+            let mut update = Update::empty();
+
+            for (child, layout) in self.children.iter_mut().zip(&layout.children) {
+                update |= child.update(layout, context.clone(), info);
+            }
+
+            update
+        }
+
+        fn widget_id(&self) -> WidgetId {
+            WidgetId::new("maycoon-core", "Row")
+        }
+    }
+
+    /// Tests that `Tab` and `Shift+Tab` cycle the focus in tree order.
+    #[test]
+    fn test_focus_traversal() {
+        let mut harness = Harness::new(
+            Row::default(),
+            DummyTheme::default(),
+            Vector2::new(100.0, 100.0),
+        );
+        let focus = harness.context().focus();
+
+        harness.frame();
+
+        assert_eq!(focus.count(), 3);
+        assert_eq!(focus.focused(), None);
+
+        harness.press_key(Key::Named(NamedKey::Tab));
+        assert!(harness.frame().contains(Update::DRAW));
+        assert_eq!(focus.focused(), Some(0));
+
+        harness.press_key(Key::Named(NamedKey::Tab));
+        harness.frame();
+        assert_eq!(focus.focused(), Some(1));
+
+        harness.set_modifiers(ModifiersState::SHIFT);
+        harness.press_key(Key::Named(NamedKey::Tab));
+        harness.press_key(Key::Named(NamedKey::Tab));
+        harness.frame();
+        assert_eq!(focus.focused(), Some(2));
+
+        harness.set_modifiers(ModifiersState::empty());
+        harness.press_key(Key::Named(NamedKey::Tab));
+        harness.frame();
+        assert_eq!(focus.focused(), Some(0));
+
+        // traversal keys are not delivered to widgets
+        assert_eq!(
+            harness
+                .widget()
+                .children
+                .iter()
+                .map(|c| c.keys)
+                .sum::<usize>(),
+            0
+        );
+    }
+
+    /// Tests that only the focused widget receives key input.
+    #[test]
+    fn test_focus_keys() {
+        let mut harness = Harness::new(
+            Row::default(),
+            DummyTheme::default(),
+            Vector2::new(100.0, 100.0),
+        );
+        let focus = harness.context().focus();
+
+        harness.frame();
+
+        harness.press_key(Key::Character("a".into()));
+        harness.frame();
+
+        assert_eq!(
+            harness
+                .widget()
+                .children
+                .iter()
+                .map(|c| c.keys)
+                .sum::<usize>(),
+            0
+        );
+
+        harness.click(Vector2::new(15.0, 5.0));
+        harness.frame();
+        assert_eq!(focus.focused(), Some(1));
+
+        harness.press_key(Key::Character("a".into()));
+        harness.frame();
+
+        let keys = harness
+            .widget()
+            .children
+            .iter()
+            .map(|c| c.keys)
+            .collect::<Vec<_>>();
+        assert_eq!(keys, vec![0, 1, 0]);
+
+        // clicking outside any focusable widget clears the focus
+        harness.click(Vector2::new(50.0, 50.0));
+        assert!(harness.frame().contains(Update::DRAW));
+        assert_eq!(focus.focused(), None);
+    }
+
+    /// Tests that a focused widget can capture `Tab` and `Ctrl+Tab` still moves the focus.
+    #[test]
+    fn test_focus_capture_tab() {
+        let mut row = Row::default();
+
+        row.children[0].capture_tab = true;
+
+        let mut harness = Harness::new(row, DummyTheme::default(), Vector2::new(100.0, 100.0));
+        let focus = harness.context().focus();
+
+        harness.frame();
+
+        harness.press_key(Key::Named(NamedKey::Tab));
+        harness.frame();
+        assert_eq!(focus.focused(), Some(0));
+
+        harness.press_key(Key::Named(NamedKey::Tab));
+        harness.press_key(Key::Named(NamedKey::Tab));
+        harness.frame();
+        assert_eq!(focus.focused(), Some(0));
+        assert_eq!(harness.widget().children[0].keys, 2);
+
+        harness.set_modifiers(ModifiersState::CONTROL);
+        harness.press_key(Key::Named(NamedKey::Tab));
+        harness.frame();
+        assert_eq!(focus.focused(), Some(1));
+        assert_eq!(harness.widget().children[0].keys, 2);
+    }
+}
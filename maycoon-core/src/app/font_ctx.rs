@@ -1,15 +1,19 @@
 use peniko::FontData;
-use rpds::HashTrieMap;
+use rpds::{HashTrieMap, Vector};
 
 /// A font manager for maycoon applications.
 ///
 /// Can be used to load and access in-memory fonts or by system source.
 ///
 /// If the default `include-noto-sans` feature is enabled, the default font is set to [Noto Sans](https://fonts.google.com/specimen/Noto+Sans).
+///
+/// Characters, which are not supported by a font, are drawn using the first supporting fallback font.
+/// See [FontContext::add_fallback] for more.
 #[derive(Clone, Debug)]
 pub struct FontContext {
     default: String,
     fonts: HashTrieMap<String, FontData>,
+    fallbacks: Vector<String>,
 }
 
 impl FontContext {
@@ -22,6 +26,7 @@ impl FontContext {
         Self {
             default: default.to_string(),
             fonts: HashTrieMap::new(),
+            fallbacks: Vector::new(),
         }
     }
 
@@ -42,6 +47,7 @@ impl FontContext {
         Some(Self {
             fonts: self.fonts.insert(name, font),
             default: self.default,
+            fallbacks: self.fallbacks,
         })
     }
 
@@ -53,6 +59,36 @@ impl FontContext {
         self.default = name.to_string();
     }
 
+    /// Adds a font as fallback font.
+    ///
+    /// Fallback fonts are searched in the order they were added,
+    /// when a character is not supported by the font of the text.
+    ///
+    /// **NOTE:** The font must be loaded before usage with [FontContext::load].
+    #[inline(always)]
+    pub fn add_fallback(&mut self, name: impl ToString) {
+        self.fallbacks = self.fallbacks.push_back(name.to_string());
+    }
+
+    /// Returns the loaded fallback fonts in the order they were added.
+    #[inline(always)]
+    pub fn fallbacks(&self) -> impl Iterator<Item = &FontData> {
+        self.fallbacks
+            .iter()
+            .filter_map(|name| self.fonts.get(name))
+    }
+
+    /// Returns the given font followed by the fallback fonts.
+    ///
+    /// Can be passed to [TextLayout::new](crate::text::layout::TextLayout::new) to lay out text with font fallback.
+    #[inline(always)]
+    pub fn font_stack(&self, font: &FontData) -> Vec<FontData> {
+        std::iter::once(font)
+            .chain(self.fallbacks())
+            .cloned()
+            .collect()
+    }
+
     /// Get a font by a specified name. Returns [None] if the font could not be found.
     #[inline(always)]
     pub fn get(&self, name: impl ToString) -> Option<FontData> {
@@ -69,6 +105,7 @@ impl FontContext {
         Some(Self {
             fonts: self.fonts.remove(name.as_ref()),
             default: self.default,
+            fallbacks: self.fallbacks,
         })
     }
 
@@ -0,0 +1,660 @@
+use nalgebra::Vector2;
+use std::f32::consts::{PI, TAU};
+use winit::event::TouchPhase;
+
+use crate::app::info::AppInfo;
+use crate::app::input::{GestureInput, TouchInput};
+
+/// The distance in pixels the fingers have to move, before a pan starts.
+const PAN_THRESHOLD: f32 = 4.0;
+
+/// Recognizes a gesture from touch events and gestures recognized by the platform.
+///
+/// Recognizers are fed with events using [GestureRecognizer::touch] and [GestureRecognizer::platform],
+/// which return the recognized gestures, if an event changed them.
+/// Widgets usually only feed the touch events of fingers which started on the widget.
+///
+/// Every recognized gesture has a [TouchPhase]:
+/// [TouchPhase::Started] when the gesture starts, [TouchPhase::Moved] when it changes
+/// and [TouchPhase::Ended] or [TouchPhase::Cancelled] when it ends.
+pub trait GestureRecognizer {
+    /// The recognized gesture.
+    type Gesture;
+
+    /// Process a touch event. Returns the gestures changed by the event in the order they occurred.
+    ///
+    /// This is usually at most one gesture, but an event can end a gesture and start another one.
+    fn touch(&mut self, touch: &TouchInput) -> Vec<Self::Gesture>;
+
+    /// Process a gesture recognized by the platform at the given position (usually the cursor position).
+    /// Returns the gesture, if the event changed it.
+    fn platform(&mut self, gesture: &GestureInput, position: Vector2<f32>)
+    -> Option<Self::Gesture>;
+
+    /// Process all touch and platform gesture events of the given [AppInfo].
+    ///
+    /// Returns the recognized gestures in the order they occurred.
+    fn recognize(&mut self, info: &AppInfo) -> Vec<Self::Gesture> {
+        let position = info.cursor_pos.unwrap_or_default();
+
+        let mut gestures = info
+            .touches
+            .iter()
+            .flat_map(|touch| self.touch(touch))
+            .collect::<Vec<_>>();
+
+        gestures.extend(
+            info.gestures
+                .iter()
+                .filter_map(|gesture| self.platform(gesture, position)),
+        );
+
+        gestures
+    }
+}
+
+/// A pan gesture, moving one or more fingers.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Pan {
+    /// The phase of the gesture.
+    pub phase: TouchPhase,
+    /// The movement in pixels since the last event.
+    pub delta: Vector2<f32>,
+    /// The center of the fingers.
+    pub position: Vector2<f32>,
+}
+
+/// A pinch gesture, moving two fingers closer together or further apart.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Pinch {
+    /// The phase of the gesture.
+    pub phase: TouchPhase,
+    /// The change of the distance between the fingers since the last event as a factor.
+    ///
+    /// Values above `1.0` mean zooming in and values below `1.0` zooming out.
+    pub scale: f32,
+    /// The center between the fingers.
+    pub center: Vector2<f32>,
+}
+
+/// A rotation gesture, rotating two fingers around their center.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Rotation {
+    /// The phase of the gesture.
+    pub phase: TouchPhase,
+    /// The change of rotation in radians since the last event.
+    ///
+    /// Positive values rotate clockwise on screen (as the y-axis points down).
+    pub delta: f32,
+    /// The center between the fingers.
+    pub center: Vector2<f32>,
+}
+
+/// Recognizes [Pan] gestures of one or more fingers.
+///
+/// The pan starts, once the center of the fingers moved a few pixels,
+/// and ends, when the last finger is lifted.
+#[derive(Debug, Clone, Default)]
+pub struct PanRecognizer {
+    points: TouchPoints,
+    moved: Vector2<f32>,
+    active: bool,
+}
+
+impl PanRecognizer {
+    /// Create a new pan recognizer.
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns if a pan is in progress.
+    #[inline(always)]
+    pub const fn is_active(&self) -> bool {
+        self.active
+    }
+}
+
+impl PanRecognizer {
+    /// Process a touch event. Returns the pan, if the event changed it.
+    fn pan(&mut self, touch: &TouchInput) -> Option<Pan> {
+        let center = self.points.center();
+        let old = self.points.apply(touch);
+
+        match touch.phase {
+            TouchPhase::Started => {
+                if self.points.len() == 1 {
+                    self.moved = Vector2::zeros();
+                }
+
+                None
+            },
+
+            TouchPhase::Moved => {
+                // the center moves by the average movement of all fingers
+                let delta = (touch.position - old?) / self.points.len() as f32;
+                let position = self.points.center()?;
+
+                self.moved += delta;
+
+                if self.active {
+                    Some(Pan {
+                        phase: TouchPhase::Moved,
+                        delta,
+                        position,
+                    })
+                } else if self.moved.norm() > PAN_THRESHOLD {
+                    self.active = true;
+
+                    Some(Pan {
+                        phase: TouchPhase::Started,
+                        delta: self.moved,
+                        position,
+                    })
+                } else {
+                    None
+                }
+            },
+
+            TouchPhase::Ended | TouchPhase::Cancelled => {
+                if !self.active || old.is_none() || self.points.len() > 0 {
+                    return None;
+                }
+
+                self.active = false;
+
+                Some(Pan {
+                    phase: touch.phase,
+                    delta: Vector2::zeros(),
+                    position: center?,
+                })
+            },
+        }
+    }
+}
+
+impl GestureRecognizer for PanRecognizer {
+    type Gesture = Pan;
+
+    #[inline(always)]
+    fn touch(&mut self, touch: &TouchInput) -> Vec<Pan> {
+        self.pan(touch).into_iter().collect()
+    }
+
+    fn platform(&mut self, gesture: &GestureInput, position: Vector2<f32>) -> Option<Pan> {
+        let GestureInput::Pan { delta, phase } = *gesture else {
+            return None;
+        };
+
+        Some(Pan {
+            phase,
+            delta,
+            position,
+        })
+    }
+}
+
+/// Recognizes [Pinch] gestures of two fingers.
+///
+/// The pinch starts, when the second finger touches the screen, and ends, when one of the two fingers is lifted.
+/// If more fingers are on the screen, a new pinch of the next two fingers starts right away.
+#[derive(Debug, Clone, Default)]
+pub struct PinchRecognizer {
+    points: TouchPoints,
+}
+
+impl PinchRecognizer {
+    /// Create a new pinch recognizer.
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl GestureRecognizer for PinchRecognizer {
+    type Gesture = Pinch;
+
+    fn touch(&mut self, touch: &TouchInput) -> Vec<Pinch> {
+        self.points
+            .apply_pair(touch)
+            .into_iter()
+            .filter_map(|(phase, before, after)| {
+                let scale = match (before, after) {
+                    (Some(before), Some(after)) if before.distance() > 0.0 => {
+                        after.distance() / before.distance()
+                    },
+                    _ => 1.0,
+                };
+
+                Some(Pinch {
+                    phase,
+                    scale,
+                    center: after.or(before)?.center(),
+                })
+            })
+            .collect()
+    }
+
+    fn platform(&mut self, gesture: &GestureInput, position: Vector2<f32>) -> Option<Pinch> {
+        let GestureInput::Pinch { delta, phase } = *gesture else {
+            return None;
+        };
+
+        // the platform may report invalid deltas
+        Some(Pinch {
+            phase,
+            scale: if delta.is_finite() { 1.0 + delta } else { 1.0 },
+            center: position,
+        })
+    }
+}
+
+/// Recognizes [Rotation] gestures of two fingers.
+///
+/// The rotation starts, when the second finger touches the screen, and ends, when one of the two fingers is lifted.
+/// If more fingers are on the screen, a new rotation of the next two fingers starts right away.
+#[derive(Debug, Clone, Default)]
+pub struct RotationRecognizer {
+    points: TouchPoints,
+}
+
+impl RotationRecognizer {
+    /// Create a new rotation recognizer.
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl GestureRecognizer for RotationRecognizer {
+    type Gesture = Rotation;
+
+    fn touch(&mut self, touch: &TouchInput) -> Vec<Rotation> {
+        self.points
+            .apply_pair(touch)
+            .into_iter()
+            .filter_map(|(phase, before, after)| {
+                let delta = match (before, after) {
+                    (Some(before), Some(after)) => {
+                        // wrap into (-PI, PI], so crossing the negative x-axis is not a full turn
+                        let delta = (after.angle() - before.angle()).rem_euclid(TAU);
+
+                        if delta > PI { delta - TAU } else { delta }
+                    },
+                    _ => 0.0,
+                };
+
+                Some(Rotation {
+                    phase,
+                    delta,
+                    center: after.or(before)?.center(),
+                })
+            })
+            .collect()
+    }
+
+    fn platform(&mut self, gesture: &GestureInput, position: Vector2<f32>) -> Option<Rotation> {
+        let GestureInput::Rotation { delta, phase } = *gesture else {
+            return None;
+        };
+
+        // the platform rotates counterclockwise in degrees
+        Some(Rotation {
+            phase,
+            delta: -delta.to_radians(),
+            center: position,
+        })
+    }
+}
+
+/// The touch points of the fingers currently on the screen, in the order they touched it.
+#[derive(Debug, Clone, Default)]
+struct TouchPoints {
+    points: Vec<(u64, Vector2<f32>)>,
+}
+
+impl TouchPoints {
+    /// Get the number of fingers on the screen.
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Get the center of all fingers. Returns [None] if no finger is on the screen.
+    #[inline(always)]
+    fn center(&self) -> Option<Vector2<f32>> {
+        if self.points.is_empty() {
+            return None;
+        }
+
+        Some(self.points.iter().map(|(_, pos)| pos).sum::<Vector2<f32>>() / self.len() as f32)
+    }
+
+    /// Get the first two fingers on the screen, which take part in two-finger gestures.
+    #[inline(always)]
+    fn pair(&self) -> Option<Pair> {
+        match self.points.as_slice() {
+            [(first_id, first), (second_id, second), ..] => Some(Pair {
+                ids: (*first_id, *second_id),
+                first: *first,
+                second: *second,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Apply the given touch event.
+    ///
+    /// Returns the previous position of the finger, if it was already on the screen.
+    fn apply(&mut self, touch: &TouchInput) -> Option<Vector2<f32>> {
+        let index = self.points.iter().position(|(id, _)| *id == touch.id);
+
+        match (touch.phase, index) {
+            (TouchPhase::Started, None) => {
+                self.points.push((touch.id, touch.position));
+
+                None
+            },
+
+            (TouchPhase::Started | TouchPhase::Moved, Some(index)) => {
+                Some(std::mem::replace(&mut self.points[index].1, touch.position))
+            },
+
+            (TouchPhase::Ended | TouchPhase::Cancelled, Some(index)) => {
+                Some(self.points.remove(index).1)
+            },
+
+            _ => None,
+        }
+    }
+
+    /// Apply the given touch event and compute the phases of a two-finger gesture.
+    ///
+    /// Returns the phases with the first two fingers before and after the event, if the gesture changed.
+    /// If one of the two fingers is lifted while more fingers are on the screen,
+    /// the gesture ends and a new gesture of the next two fingers starts.
+    fn apply_pair(&mut self, touch: &TouchInput) -> Vec<(TouchPhase, Option<Pair>, Option<Pair>)> {
+        let before = self.pair();
+
+        self.apply(touch);
+
+        let after = self.pair();
+
+        let end = if touch.phase == TouchPhase::Cancelled {
+            TouchPhase::Cancelled
+        } else {
+            TouchPhase::Ended
+        };
+
+        match (before, after) {
+            (None, Some(_)) => vec![(TouchPhase::Started, None, after)],
+
+            (Some(_), None) => vec![(end, before, None)],
+
+            // start a new gesture, so the other finger does not cause a jump
+            (Some(old), Some(new)) if old.ids != new.ids => {
+                vec![(end, before, None), (TouchPhase::Started, None, after)]
+            },
+
+            (Some(old), Some(new)) if old != new => vec![(TouchPhase::Moved, before, after)],
+
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// The first two fingers on the screen.
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Pair {
+    ids: (u64, u64),
+    first: Vector2<f32>,
+    second: Vector2<f32>,
+}
+
+impl Pair {
+    /// Get the center between the fingers.
+    #[inline(always)]
+    fn center(&self) -> Vector2<f32> {
+        (self.first + self.second) / 2.0
+    }
+
+    /// Get the distance between the fingers.
+    #[inline(always)]
+    fn distance(&self) -> f32 {
+        (self.second - self.first).norm()
+    }
+
+    /// Get the angle of the line from the first to the second finger.
+    #[inline(always)]
+    fn angle(&self) -> f32 {
+        let line = self.second - self.first;
+
+        line.y.atan2(line.x)
+    }
+}
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use crate::app::gesture::{
+        GestureRecognizer, PanRecognizer, PinchRecognizer, RotationRecognizer,
+    };
+    use crate::app::info::AppInfo;
+    use crate::app::input::{GestureInput, TouchInput};
+    use nalgebra::Vector2;
+    use std::f32::consts::FRAC_PI_2;
+    use winit::event::TouchPhase;
+
+    /// Create a touch event.
+    fn touch(id: u64, phase: TouchPhase, x: f32, y: f32) -> TouchInput {
+        TouchInput::new(id, phase, Vector2::new(x, y))
+    }
+
+    /// Tests recognizing a pan of a single finger.
+    #[test]
+    fn test_pan() {
+        let mut pan = PanRecognizer::new();
+
+        assert!(
+            pan.touch(&touch(0, TouchPhase::Started, 10.0, 10.0))
+                .is_empty()
+        );
+
+        // below the threshold
+        assert!(
+            pan.touch(&touch(0, TouchPhase::Moved, 12.0, 10.0))
+                .is_empty()
+        );
+        assert!(!pan.is_active());
+
+        let started = pan.touch(&touch(0, TouchPhase::Moved, 20.0, 10.0))[0];
+
+        assert_eq!(started.phase, TouchPhase::Started);
+        assert_eq!(started.delta, Vector2::new(10.0, 0.0));
+        assert_eq!(started.position, Vector2::new(20.0, 10.0));
+
+        let moved = pan.touch(&touch(0, TouchPhase::Moved, 20.0, 15.0))[0];
+
+        assert_eq!(moved.phase, TouchPhase::Moved);
+        assert_eq!(moved.delta, Vector2::new(0.0, 5.0));
+
+        let ended = pan.touch(&touch(0, TouchPhase::Ended, 20.0, 15.0))[0];
+
+        assert_eq!(ended.phase, TouchPhase::Ended);
+        assert!(!pan.is_active());
+    }
+
+    /// Tests that fingers touching or leaving the screen during a pan don't move it.
+    #[test]
+    fn test_pan_multiple_fingers() {
+        let mut pan = PanRecognizer::new();
+
+        pan.touch(&touch(0, TouchPhase::Started, 0.0, 0.0));
+        pan.touch(&touch(0, TouchPhase::Moved, 10.0, 0.0));
+
+        assert!(
+            pan.touch(&touch(1, TouchPhase::Started, 50.0, 50.0))
+                .is_empty()
+        );
+
+        // the center moves by half of the movement of one finger
+        let moved = pan.touch(&touch(1, TouchPhase::Moved, 50.0, 60.0))[0];
+
+        assert_eq!(moved.delta, Vector2::new(0.0, 5.0));
+        assert_eq!(moved.position, Vector2::new(30.0, 30.0));
+
+        assert!(
+            pan.touch(&touch(1, TouchPhase::Ended, 50.0, 60.0))
+                .is_empty()
+        );
+        assert!(pan.is_active());
+
+        let ended = pan.touch(&touch(0, TouchPhase::Cancelled, 10.0, 0.0))[0];
+
+        assert_eq!(ended.phase, TouchPhase::Cancelled);
+    }
+
+    /// Tests recognizing a pinch of two fingers.
+    #[test]
+    fn test_pinch() {
+        let mut pinch = PinchRecognizer::new();
+
+        assert!(
+            pinch
+                .touch(&touch(0, TouchPhase::Started, 0.0, 0.0))
+                .is_empty()
+        );
+
+        let started = pinch.touch(&touch(1, TouchPhase::Started, 100.0, 0.0))[0];
+
+        assert_eq!(started.phase, TouchPhase::Started);
+        assert_eq!(started.scale, 1.0);
+        assert_eq!(started.center, Vector2::new(50.0, 0.0));
+
+        let moved = pinch.touch(&touch(1, TouchPhase::Moved, 200.0, 0.0))[0];
+
+        assert_eq!(moved.phase, TouchPhase::Moved);
+        assert_eq!(moved.scale, 2.0);
+        assert_eq!(moved.center, Vector2::new(100.0, 0.0));
+
+        // a third finger does not take part
+        assert!(
+            pinch
+                .touch(&touch(2, TouchPhase::Started, 0.0, 50.0))
+                .is_empty()
+        );
+        assert!(
+            pinch
+                .touch(&touch(2, TouchPhase::Moved, 0.0, 80.0))
+                .is_empty()
+        );
+
+        // lifting a finger of the pinch ends it and starts a new pinch with the third finger
+        let changed = pinch.touch(&touch(0, TouchPhase::Ended, 0.0, 0.0));
+
+        assert_eq!(changed.len(), 2);
+        assert_eq!(changed[0].phase, TouchPhase::Ended);
+        assert_eq!(changed[0].center, Vector2::new(100.0, 0.0));
+        assert_eq!(changed[1].phase, TouchPhase::Started);
+        assert_eq!(changed[1].scale, 1.0);
+        assert_eq!(changed[1].center, Vector2::new(100.0, 40.0));
+
+        let ended = pinch.touch(&touch(1, TouchPhase::Ended, 200.0, 0.0))[0];
+
+        assert_eq!(ended.phase, TouchPhase::Ended);
+        assert_eq!(ended.scale, 1.0);
+    }
+
+    /// Tests recognizing a rotation of two fingers.
+    #[test]
+    fn test_rotation() {
+        let mut rotation = RotationRecognizer::new();
+
+        rotation.touch(&touch(0, TouchPhase::Started, 0.0, 0.0));
+        rotation.touch(&touch(1, TouchPhase::Started, 100.0, 0.0));
+
+        // rotate clockwise on screen by a quarter turn
+        let moved = rotation.touch(&touch(1, TouchPhase::Moved, 0.0, 100.0))[0];
+
+        assert_eq!(moved.phase, TouchPhase::Moved);
+        assert!((moved.delta - FRAC_PI_2).abs() < 1e-5);
+
+        // crossing the negative x-axis
+        rotation.touch(&touch(1, TouchPhase::Moved, -100.0, 1.0));
+
+        let moved = rotation.touch(&touch(1, TouchPhase::Moved, -100.0, -1.0))[0];
+
+        assert!(moved.delta > 0.0 && moved.delta < 0.1);
+    }
+
+    /// Tests that lifting a finger of a rotation with three fingers ends it and starts a new rotation without a jump.
+    #[test]
+    fn test_rotation_three_fingers() {
+        let mut rotation = RotationRecognizer::new();
+
+        rotation.touch(&touch(0, TouchPhase::Started, 0.0, 0.0));
+        rotation.touch(&touch(1, TouchPhase::Started, 100.0, 0.0));
+
+        assert!(
+            rotation
+                .touch(&touch(2, TouchPhase::Started, 0.0, 100.0))
+                .is_empty()
+        );
+
+        let changed = rotation.touch(&touch(1, TouchPhase::Cancelled, 100.0, 0.0));
+
+        assert_eq!(changed.len(), 2);
+        assert_eq!(changed[0].phase, TouchPhase::Cancelled);
+        assert_eq!(changed[0].delta, 0.0);
+        assert_eq!(changed[1].phase, TouchPhase::Started);
+        assert_eq!(changed[1].delta, 0.0);
+        assert_eq!(changed[1].center, Vector2::new(0.0, 50.0));
+
+        // the new rotation is measured from the new pair of fingers
+        let moved = rotation.touch(&touch(2, TouchPhase::Moved, -100.0, 0.0))[0];
+
+        assert_eq!(moved.phase, TouchPhase::Moved);
+        assert!((moved.delta - FRAC_PI_2).abs() < 1e-5);
+
+        let ended = rotation.touch(&touch(0, TouchPhase::Ended, 0.0, 0.0));
+
+        assert_eq!(ended.len(), 1);
+        assert_eq!(ended[0].phase, TouchPhase::Ended);
+        assert!(
+            rotation
+                .touch(&touch(2, TouchPhase::Ended, -100.0, 0.0))
+                .is_empty()
+        );
+    }
+
+    /// Tests recognizing gestures from an [AppInfo] including platform gestures.
+    #[test]
+    fn test_recognize() {
+        let mut info = AppInfo {
+            cursor_pos: Some(Vector2::new(5.0, 5.0)),
+            ..Default::default()
+        };
+
+        info.touches.push(touch(0, TouchPhase::Started, 0.0, 0.0));
+        info.touches.push(touch(1, TouchPhase::Started, 10.0, 0.0));
+        info.gestures.push(GestureInput::Pinch {
+            delta: 0.5,
+            phase: TouchPhase::Moved,
+        });
+        info.gestures.push(GestureInput::Rotation {
+            delta: 90.0,
+            phase: TouchPhase::Moved,
+        });
+
+        let pinches = PinchRecognizer::new().recognize(&info);
+
+        assert_eq!(pinches.len(), 2);
+        assert_eq!(pinches[0].phase, TouchPhase::Started);
+        assert_eq!(pinches[1].scale, 1.5);
+        assert_eq!(pinches[1].center, Vector2::new(5.0, 5.0));
+
+        let rotations = RotationRecognizer::new().recognize(&info);
+
+        assert!((rotations[1].delta + FRAC_PI_2).abs() < 1e-5);
+        assert!(PanRecognizer::new().recognize(&info).is_empty());
+    }
+}
@@ -1,23 +1,25 @@
 use nalgebra::Vector2;
+use std::marker::PhantomData;
 use std::sync::Arc;
-use taffy::{
-    AvailableSpace, Dimension, NodeId, PrintTree, Size, Style, TaffyResult, TaffyTree,
-    TraversePartialTree,
-};
 use winit::application::ApplicationHandler;
+use winit::dpi::{LogicalPosition, LogicalSize, PhysicalPosition, PhysicalSize};
 use winit::event::WindowEvent;
 use winit::event_loop::ActiveEventLoop;
-use winit::window::{Window, WindowAttributes, WindowId};
+use winit::keyboard::ModifiersState;
+use winit::window::{Cursor, CursorIcon, Window, WindowAttributes, WindowId};
 
+use crate::app::clipboard::Clipboard;
 use crate::app::context::AppContext;
 use crate::app::font_ctx::FontContext;
 use crate::app::info::AppInfo;
+use crate::app::input::GestureInput;
+use crate::app::tree::WidgetTree;
 use crate::app::update::{Update, UpdateManager};
-use crate::config::MayConfig;
-use crate::layout::{LayoutNode, StyleNode};
+use crate::app::window::{WindowBuilder, WindowCommand, WindowKey, WindowManager, WindowRequest};
+use crate::config::{MayConfig, WindowConfig, WindowMode};
 use crate::plugin::PluginManager;
 use crate::vgi::{Scene, VectorGraphicsInterface};
-use crate::widget::Widget;
+use crate::widget::{BoxedWidget, Widget};
 use maycoon_theme::theme::Theme;
 
 /// The core application handler. You should use [MayApp](crate::app::MayApp) instead for running applications.
@@ -30,305 +32,419 @@ where
 {
     config: MayConfig<T, V>,
     attrs: WindowAttributes,
-    window: Option<Arc<Window>>,
-    scene: V::Scene,
-    taffy: TaffyTree,
-    window_node: NodeId,
+    windows: Vec<WindowState<V>>,
+    manager: WindowManager,
     builder: F,
     state: Option<S>,
-    widget: Option<W>,
+    graphics: V,
+    plugins: PluginManager<T, V>,
+    _widget: PhantomData<W>,
+}
+
+/// The state of a single application window.
+///
+/// Every window has its own [UpdateManager], so input only updates the window receiving it.
+struct WindowState<V: VectorGraphicsInterface> {
+    window: Option<Arc<Window>>,
+    close_on_request: bool,
+    cursor: Cursor,
+    cursor_icon: Option<CursorIcon>,
+    tree: WidgetTree<BoxedWidget, V::Scene>,
     info: AppInfo,
     update: UpdateManager,
-    plugins: PluginManager<T, V>,
-    graphics: V,
 }
 
-impl<T, W, S, F, V> AppHandler<T, W, S, F, V>
-where
-    T: Theme,
-    W: Widget,
-    F: Fn(AppContext, S) -> W,
-    V: VectorGraphicsInterface,
-{
-    /// Create a new handler with given window attributes, config, widget and state.
+impl<V: VectorGraphicsInterface> WindowState<V> {
+    /// Create a new window state without an actual window.
     #[inline(always)]
-    #[tracing::instrument(level = "trace", skip_all)]
-    pub fn new(
-        attrs: WindowAttributes,
-        config: MayConfig<T, V>,
-        builder: F,
-        state: S,
-        font_context: FontContext,
+    fn new(
+        key: WindowKey,
+        manager: WindowManager,
+        config: &WindowConfig,
         update: UpdateManager,
-        plugins: PluginManager<T, V>,
+        font_context: FontContext,
+        clipboard: Clipboard,
     ) -> Self {
-        tracing::trace!("creating taffy tree");
-        let mut taffy = TaffyTree::with_capacity(16);
-
-        // gets configured on resume
-        let window_node = taffy
-            .new_leaf(Style::default())
-            .expect("Failed to create window node");
-
-        let size = config.window.size;
-
-        let graphics = config.graphics.clone();
+        // hooked signals update all windows of the application
+        manager.attach(key, update.clone());
 
         Self {
-            attrs,
             window: None,
-            config,
-            scene: Scene::new(),
-            taffy,
-            widget: None,
+            close_on_request: config.close_on_request,
+            cursor: config.cursor.clone(),
+            cursor_icon: None,
+            tree: WidgetTree::with_window(Scene::new(), manager, key).with_clipboard(clipboard),
             info: AppInfo {
                 font_context,
-                size,
+                size: config.size,
                 ..Default::default()
             },
-            window_node,
-            builder,
-            state: Some(state),
             update,
-            plugins,
-            graphics: V::new(graphics).expect("Failed to create vector graphics interface"),
         }
     }
 
-    /// Get the application context.
+    /// Initialize the layout and vector graphics interface with the given window.
     #[inline(always)]
     #[tracing::instrument(level = "trace", skip_all)]
-    pub fn context(&self) -> AppContext {
-        AppContext::new(self.update.clone(), self.info.diagnostics)
-    }
+    fn init(&mut self, window: Arc<Window>, event_loop: &ActiveEventLoop, graphics: &mut V) {
+        tracing::info!("initializing layout");
+        let size = window.inner_size();
 
-    /// Add the parent node and its children to the layout tree.
-    #[tracing::instrument(level = "trace", skip(self, style))]
-    fn layout_widget(&mut self, parent: NodeId, style: &StyleNode) -> TaffyResult<()> {
-        let node = self.taffy.new_leaf(style.style.clone().into())?;
+        self.info.size = Vector2::new(size.width as f64, size.height as f64);
+        self.tree.resize(self.info.size);
 
-        self.taffy.add_child(parent, node)?;
+        tracing::info!("initializing vector graphics interface");
+        graphics
+            .init(window.clone(), event_loop)
+            .expect("Failed to initialize vector graphics interface");
+
+        self.window = Some(window);
+    }
 
-        for child in &style.children {
-            self.layout_widget(node, child)?;
+    /// Apply the cursor icon requested by the hovered widgets or the default cursor of the window, if it changed.
+    #[inline(always)]
+    fn update_cursor(&mut self) {
+        let icon = self.tree.pointer().cursor_icon();
+
+        if icon != self.cursor_icon {
+            self.cursor_icon = icon;
+            self.apply_cursor();
         }
+    }
 
-        Ok(())
+    /// Apply the current cursor of the window.
+    #[inline(always)]
+    fn apply_cursor(&self) {
+        if let Some(window) = &self.window {
+            window.set_cursor(
+                self.cursor_icon
+                    .map_or_else(|| self.cursor.clone(), Cursor::Icon),
+            );
+        }
     }
 
-    /// Compute the layout of the root node and its children.
+    /// Apply the given [WindowCommand] to the window.
     #[inline(always)]
-    #[tracing::instrument(level = "trace", skip(self))]
-    fn compute_layout(&mut self) -> TaffyResult<()> {
-        self.taffy.compute_layout(
-            self.window_node,
-            Size::<AvailableSpace> {
-                width: AvailableSpace::Definite(
-                    self.window.as_ref().unwrap().inner_size().width as f32,
-                ),
-                height: AvailableSpace::Definite(
-                    self.window.as_ref().unwrap().inner_size().height as f32,
-                ),
+    fn command(&mut self, command: WindowCommand) {
+        match command {
+            // the default cursor is shown while no hovered widget requests a cursor icon
+            WindowCommand::SetCursor(cursor) => {
+                self.cursor = cursor;
+                self.apply_cursor();
             },
-        )?;
-        Ok(())
+
+            command => {
+                if let Some(window) = &self.window {
+                    apply_command(window, command);
+                }
+            },
+        }
     }
 
-    /// Collect the computed layout of the given node and its children. Make sure to call [AppHandler::compute_layout] before, to not get dirty results.
+    /// Render the window via the vector graphics interface.
     #[inline(always)]
-    #[tracing::instrument(level = "trace", skip(self, style))]
-    fn collect_layout(&mut self, node: NodeId, style: &StyleNode) -> TaffyResult<LayoutNode> {
-        tracing::trace!("collecting node layout {node:?}");
+    #[tracing::instrument(level = "trace", skip_all)]
+    fn render(
+        &self,
+        event_loop: &ActiveEventLoop,
+        graphics: &mut V,
+        theme: &dyn Theme,
+    ) -> Result<(), V::Error> {
+        let window = self.window.clone().expect("Window not initialized");
 
-        let mut children = Vec::with_capacity(style.children.capacity());
+        // check surface validity
+        if window.inner_size().width != 0 && window.inner_size().height != 0 {
+            tracing::trace!("rendering via vector graphics interface");
+            graphics.render(
+                window,
+                event_loop,
+                &self.tree.scene,
+                theme.window_background(),
+            )?;
+        } else {
+            tracing::debug!("skipping render due to invalid surface");
+        }
+
+        Ok(())
+    }
+}
+
+impl<T, W, S, F, V> AppHandler<T, W, S, F, V>
+where
+    T: Theme,
+    W: Widget + 'static,
+    F: Fn(AppContext, S) -> W,
+    V: VectorGraphicsInterface,
+{
+    /// Create a new handler with given window attributes, config, widget and state.
+    #[inline(always)]
+    #[tracing::instrument(level = "trace", skip_all)]
+    pub fn new(
+        attrs: WindowAttributes,
+        config: MayConfig<T, V>,
+        builder: F,
+        state: S,
+        font_context: FontContext,
+        update: UpdateManager,
+        plugins: PluginManager<T, V>,
+    ) -> Self {
+        let manager = WindowManager::new();
 
-        for (i, style) in style.children.iter().enumerate() {
-            let child = self.taffy.child_at_index(node, i)?;
+        let main = WindowState::new(
+            WindowKey::MAIN,
+            manager.clone(),
+            &config.window,
+            update,
+            font_context,
+            config.clipboard.clone(),
+        );
 
-            tracing::trace!("collecting layout of child {child:?}");
+        // all windows share the same graphics interface and therefore the same device
+        let graphics =
+            V::new(config.graphics.clone()).expect("Failed to create vector graphics interface");
 
-            children.push(self.collect_layout(child, style)?);
+        Self {
+            attrs,
+            windows: vec![main],
+            manager,
+            config,
+            builder,
+            state: Some(state),
+            graphics,
+            plugins,
+            _widget: PhantomData,
         }
-
-        Ok(LayoutNode {
-            layout: self.taffy.get_final_layout(node),
-            children,
-        })
     }
 
-    /// Request a window redraw.
+    /// Get the application context of the main window.
     #[inline(always)]
-    #[tracing::instrument(level = "trace", skip(self))]
-    fn request_redraw(&self) {
-        tracing::trace!("requesting redraw");
-        self.window.as_ref().unwrap().request_redraw();
+    #[tracing::instrument(level = "trace", skip_all)]
+    pub fn context(&self) -> AppContext {
+        let main = &self.windows[0];
+
+        main.tree.context(&main.info, &main.update)
     }
 
-    /// Render the application via the vector graphics interface.
+    /// Returns the index of the window state with the given window id.
     #[inline(always)]
-    #[tracing::instrument(level = "trace", skip(self))]
-    fn render(
-        &mut self,
-        window: Arc<Window>,
-        event_loop: &ActiveEventLoop,
-    ) -> Result<(), V::Error> {
-        tracing::trace!("rendering via vector graphics interface");
-        self.graphics.render(
-            window,
-            event_loop,
-            &self.scene,
-            self.config.theme.window_background(),
-        )?;
-
-        Ok(())
+    fn window_index(&self, window_id: WindowId) -> Option<usize> {
+        self.windows.iter().position(|state| {
+            state
+                .window
+                .as_ref()
+                .is_some_and(|window| window.id() == window_id)
+        })
     }
 
-    /// Update the app and process events.
+    /// Update the window at the given index and process events.
     #[inline(always)]
     #[tracing::instrument(level = "trace", skip_all)]
-    fn update(&mut self, event_loop: &ActiveEventLoop) {
+    fn update(&mut self, idx: usize, event_loop: &ActiveEventLoop) {
         // tick the task runner
         crate::tasks::runner().tick();
 
+        let state = &mut self.windows[idx];
+        let window = state.window.clone().expect("Window not initialized");
+
         // update plugins
         tracing::trace!("updating plugins");
         self.plugins.run(|pl| {
             pl.on_update(
                 &mut self.config,
-                self.window.as_ref().expect("Window not initialized"),
-                &mut self.scene,
-                &mut self.taffy,
-                self.window_node,
-                &mut self.info,
-                &self.update,
+                &window,
+                &mut state.tree.scene,
+                &mut state.tree.taffy,
+                state.tree.root,
+                &mut state.info,
+                &state.update,
                 event_loop,
             )
         });
 
-        // completely layout widgets if taffy is not set up yet (e.g. during first update)
-        if self.taffy.child_count(self.window_node) == 0 {
-            tracing::trace_span!("complete layout").in_scope(|| {
-                let style = self.widget.as_ref().unwrap().layout_style();
-
-                self.layout_widget(self.window_node, &style)
-                    .expect("Failed to layout window");
-
-                self.compute_layout().expect("Failed to compute layout");
-
-                self.update.insert(Update::FORCE);
-            });
-        }
-
-        let style = self.widget.as_ref().unwrap().layout_style();
-
-        let mut layout_node = self
-            .collect_layout(
-                self.taffy.child_at_index(self.window_node, 0).unwrap(),
-                &style,
-            )
-            .expect("Failed to collect layout");
-
-        // update call to check if app should re-evaluate
-        let context = self.context();
-
-        tracing::trace!("updating widget");
-        self.update.insert(
-            self.widget
-                .as_mut()
-                .unwrap()
-                .update(&layout_node, context, &self.info),
-        );
-
-        // check if app should re-evaluate layout
-        if self.update.is_set(Update::LAYOUT | Update::FORCE) {
-            tracing::trace_span!("layout").in_scope(|| {
-                // clear all nodes (except root window node)
-                self.taffy
-                    .set_children(self.window_node, &[])
-                    .expect("Failed to set children");
-
-                let style = self.widget.as_ref().unwrap().layout_style();
-
-                self.layout_widget(self.window_node, &style)
-                    .expect("Failed to layout window");
-
-                self.compute_layout().expect("Failed to compute layout");
-
-                layout_node = self
-                    .collect_layout(
-                        self.taffy.child_at_index(self.window_node, 0).unwrap(),
-                        &style,
-                    )
-                    .expect("Failed to collect layout");
-            });
-        }
+        // update the widget and re-evaluate the layout if requested
+        state.tree.update(&state.info, &state.update);
+        state.update_cursor();
 
         // check if app should redraw
-        if self
+        if state
             .update
             .is_set(Update::DRAW | Update::LAYOUT | Update::FORCE)
         {
             tracing::trace_span!("draw").in_scope(|| {
-                // clear scene
-                tracing::trace!("resetting vector graphics interface scene");
-                self.scene.reset();
-
-                let context = self.context();
-
-                tracing::trace!("drawing root widget");
-                self.widget.as_mut().unwrap().render(
-                    &mut self.scene,
-                    &mut self.config.theme,
-                    &layout_node,
-                    &self.info,
-                    context,
-                );
-
-                let window = self.window.clone().expect("Window not initialized");
-
-                // check surface validity
-                if window.inner_size().width != 0 && window.inner_size().height != 0 {
-                    self.render(window, event_loop)
-                        .expect("Failed rendering process");
-                } else {
-                    tracing::debug!("skipping render due to invalid surface");
-                }
+                state
+                    .tree
+                    .draw(&mut self.config.theme, &state.info, &state.update);
+
+                state
+                    .render(event_loop, &mut self.graphics, &self.config.theme)
+                    .expect("Failed rendering process");
             });
         }
 
-        // check if app should re-evaluate
-        if self.update.is_set(Update::EVAL) {
+        // check if app should re-evaluate in the next frame
+        if state.update.is_set(Update::EVAL) {
             tracing::trace!("re-evaluating application state");
         }
 
         // update the app if requested
-        if self.update.is_set(Update::EXIT) {
+        if state.update.is_set(Update::EXIT) {
             tracing::trace!("exiting event loop");
             event_loop.exit();
             return;
         }
 
-        // reset AppInfo and update states
+        // reset AppInfo and update states, except for re-evaluation requested during the update
         tracing::trace!("resetting app info and update states");
-        self.info.reset();
-        self.update.clear();
+        state.update.remove(!Update::EVAL);
+        state.info.reset();
 
         // update diagnostics
-        self.info.diagnostics.first_run = false;
-        self.info.diagnostics.do_update();
+        state.info.diagnostics.first_run = false;
+        state.info.diagnostics.do_update();
+
+        self.handle_window_requests(event_loop);
     }
 
+    /// Returns the index of the window state with the given window key.
+    #[inline(always)]
+    fn key_index(&self, key: WindowKey) -> Option<usize> {
+        self.windows
+            .iter()
+            .position(|state| state.tree.window() == key)
+    }
+
+    /// Returns the window with the given window key, if it is created.
+    #[inline(always)]
+    fn key_window(&self, key: WindowKey) -> Option<Arc<Window>> {
+        self.key_index(key)
+            .and_then(|idx| self.windows[idx].window.clone())
+    }
+
+    /// Apply the pending requests of the [WindowManager].
+    #[inline(always)]
+    #[tracing::instrument(level = "trace", skip_all)]
+    fn handle_window_requests(&mut self, event_loop: &ActiveEventLoop) {
+        for request in self.manager.take_requests() {
+            tracing::debug!("handling window request {request:?}");
+
+            match request {
+                WindowRequest::Open {
+                    key,
+                    config,
+                    builder,
+                } => self.open_window(event_loop, key, *config, builder),
+
+                WindowRequest::Close(key) => {
+                    if let Some(idx) = self.key_index(key) {
+                        self.close(idx, event_loop, true);
+                    }
+                },
+
+                WindowRequest::SetImeAllowed(key, allowed) => {
+                    if let Some(window) = self.key_window(key) {
+                        window.set_ime_allowed(allowed);
+                    }
+                },
+
+                WindowRequest::SetImeCursorArea {
+                    key,
+                    position,
+                    size,
+                } => {
+                    if let Some(window) = self.key_window(key) {
+                        window.set_ime_cursor_area(
+                            PhysicalPosition::new(position.x, position.y),
+                            PhysicalSize::new(size.x, size.y),
+                        );
+                    }
+                },
+
+                WindowRequest::Command(key, command) => {
+                    if let Some(idx) = self.key_index(key) {
+                        self.windows[idx].command(command);
+                    }
+                },
+            }
+        }
+    }
+
+    /// Create a new window and build its root widget.
+    #[tracing::instrument(level = "trace", skip(self, event_loop, builder))]
+    fn open_window(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        key: WindowKey,
+        config: WindowConfig,
+        builder: WindowBuilder,
+    ) {
+        tracing::info!("creating window");
+        let window = Arc::new(
+            event_loop
+                .create_window(config.attributes())
+                .expect("Failed to create window"),
+        );
+
+        apply_mode(&window, config.mode);
+
+        let mut state = WindowState::new(
+            key,
+            self.manager.clone(),
+            &config,
+            UpdateManager::new(),
+            self.windows[0].info.font_context.clone(),
+            self.config.clipboard.clone(),
+        );
+
+        state.init(window, event_loop, &mut self.graphics);
+
+        tracing::info!("building root widget");
+        let widget = builder(state.tree.context(&state.info, &state.update));
+
+        state.tree.set_widget(widget);
+
+        // make sure the new window gets laid out and drawn
+        state.update.insert(Update::EVAL);
+
+        self.windows.push(state);
+    }
+
+    /// Close the window at the given index.
+    ///
+    /// Closing the main window destroys the graphics of all windows and exits the application,
+    /// if `exit` is set or the window is configured to close on request.
     #[cold]
-    fn close(&mut self, window: Arc<Window>, event_loop: &ActiveEventLoop) {
+    fn close(&mut self, idx: usize, event_loop: &ActiveEventLoop, exit: bool) {
         tracing::trace!("close requested");
 
-        self.graphics
-            .destroy(window, event_loop)
-            .expect("Failed to destroy vector graphics interface");
+        if !exit && !self.windows[idx].close_on_request {
+            return;
+        }
 
-        if self.config.window.close_on_request {
+        // the main window takes all other windows with it
+        let closed = if idx == 0 {
             tracing::info!("exiting event loop");
             event_loop.exit();
+
+            0..self.windows.len()
+        } else {
+            tracing::info!("closing window");
+
+            idx..idx + 1
+        };
+
+        for window in self.windows[closed]
+            .iter()
+            .filter_map(|state| state.window.clone())
+        {
+            self.graphics
+                .destroy(window, event_loop)
+                .expect("Failed to destroy vector graphics interface");
+        }
+
+        if idx != 0 {
+            let state = self.windows.remove(idx);
+
+            self.manager.detach(state.tree.window());
         }
     }
 }
@@ -336,22 +452,24 @@ where
 impl<T, W, S, F, V> ApplicationHandler for AppHandler<T, W, S, F, V>
 where
     T: Theme,
-    W: Widget,
+    W: Widget + 'static,
     F: Fn(AppContext, S) -> W,
     V: VectorGraphicsInterface,
 {
     #[inline(always)]
     #[tracing::instrument(level = "trace", skip_all)]
     fn resumed(&mut self, event_loop: &ActiveEventLoop) {
+        let main = &mut self.windows[0];
+
         tracing::debug!("resuming plugins");
         self.plugins.run(|pl| {
             pl.on_resume(
                 &mut self.config,
-                &mut self.scene,
-                &mut self.taffy,
-                self.window_node,
-                &mut self.info,
-                &self.update,
+                &mut main.tree.scene,
+                &mut main.tree.taffy,
+                main.tree.root,
+                &mut main.info,
+                &main.update,
                 event_loop,
             )
         });
@@ -363,34 +481,22 @@ where
                 .expect("Failed to create window"),
         );
 
-        tracing::info!("initializing layout");
-        let size = window.inner_size();
-
-        self.taffy
-            .set_style(
-                self.window_node,
-                Style {
-                    size: Size::<Dimension> {
-                        width: Dimension::length(size.width as f32),
-                        height: Dimension::length(size.height as f32),
-                    },
-                    ..Default::default()
-                },
-            )
-            .expect("Failed to set window node style");
+        apply_mode(&window, self.config.window.mode);
 
-        tracing::info!("initializing vector graphics interface");
-        self.graphics
-            .init(window.clone(), event_loop)
-            .expect("Failed to initialize vector graphics interface");
+        main.init(window, event_loop, &mut self.graphics);
 
         tracing::info!("building root widget");
-        self.widget = Some((self.builder)(
-            AppContext::new(self.update.clone(), self.info.diagnostics),
+        let widget = (self.builder)(
+            main.tree.context(&main.info, &main.update),
             self.state.take().unwrap(),
-        ));
+        );
 
-        self.window = Some(window);
+        main.tree.set_widget(Box::new(widget));
+
+        // make sure the window gets laid out and drawn, even if no resize event is sent
+        main.update.insert(Update::EVAL);
+
+        self.handle_window_requests(event_loop);
     }
 
     #[inline(always)]
@@ -402,157 +508,283 @@ where
         window_id: WindowId,
         mut event: WindowEvent,
     ) {
+        let Some(idx) = self.window_index(window_id) else {
+            return;
+        };
+
+        let state = &mut self.windows[idx];
+        let window = state.window.clone().unwrap();
+
         tracing::trace!("running plugin window event");
         self.plugins.run(|pl| {
             pl.on_window_event(
                 &mut event,
                 &mut self.config,
-                self.window.as_ref().unwrap(),
-                &mut self.scene,
-                &mut self.taffy,
-                self.window_node,
-                &mut self.info,
-                &self.update,
+                &window,
+                &mut state.tree.scene,
+                &mut state.tree.taffy,
+                state.tree.root,
+                &mut state.info,
+                &state.update,
                 event_loop,
             )
         });
 
-        if let Some(window) = &self.window
-            && window.id() == window_id
-        {
-            match event {
-                WindowEvent::Resized(new_size) => {
-                    tracing::debug!("resizing window to {new_size:?}");
-
-                    if new_size.width != 0 && new_size.height != 0 {
-                        tracing::trace!("resizing vector graphics interface");
-                        self.graphics
-                            .resize(
-                                window.clone(),
-                                event_loop,
-                                Vector2::new(new_size.width, new_size.height),
-                            )
-                            .expect("Failed to resize vector graphics interface");
-
-                        tracing::trace!("resizing root layout node");
-                        self.taffy
-                            .set_style(
-                                self.window_node,
-                                Style {
-                                    size: Size::<Dimension> {
-                                        width: Dimension::length(new_size.width as f32),
-                                        height: Dimension::length(new_size.height as f32),
-                                    },
-                                    ..Default::default()
-                                },
-                            )
-                            .expect("Failed to set window node style");
-
-                        self.info.size =
-                            Vector2::new(new_size.width as f64, new_size.height as f64);
-
-                        self.update
-                            .insert(Update::EVAL | Update::DRAW | Update::LAYOUT);
-                    } else {
-                        tracing::trace!("window size is 0x0, ignoring resize event");
-                    }
-                },
+        match event {
+            WindowEvent::Resized(new_size) => {
+                tracing::debug!("resizing window to {new_size:?}");
 
-                WindowEvent::CloseRequested => {
-                    self.close(window.clone(), event_loop);
-                },
+                if new_size.width != 0 && new_size.height != 0 {
+                    tracing::trace!("resizing vector graphics interface");
+                    self.graphics
+                        .resize(
+                            window,
+                            event_loop,
+                            Vector2::new(new_size.width, new_size.height),
+                        )
+                        .expect("Failed to resize vector graphics interface");
 
-                WindowEvent::RedrawRequested => {
-                    window.request_redraw();
+                    state.info.size = Vector2::new(new_size.width as f64, new_size.height as f64);
 
-                    // update diagnostics frames
-                    self.info.diagnostics.do_frame();
+                    state.tree.resize(state.info.size);
 
-                    if self.update.is_set(Update::EVAL) {
-                        self.update.remove(Update::EVAL);
-                        self.update(event_loop);
-                    }
+                    state
+                        .update
+                        .insert(Update::EVAL | Update::DRAW | Update::LAYOUT);
+                } else {
+                    tracing::trace!("window size is 0x0, ignoring resize event");
+                }
+            },
+
+            WindowEvent::CloseRequested => {
+                self.close(idx, event_loop, false);
+            },
+
+            WindowEvent::RedrawRequested => {
+                window.request_redraw();
 
+                // update diagnostics frames
+                state.info.diagnostics.do_frame();
+
+                if state.update.is_set(Update::EVAL) {
+                    state.update.remove(Update::EVAL);
+                    self.update(idx, event_loop);
+                }
+
+                // the window might have been closed during the update
+                if let Some(state) = self.windows.get_mut(idx)
+                    && state.window.as_ref().is_some_and(|w| w.id() == window_id)
+                {
                     // update diagnostics computations
                     tracing::trace!("updating diagnostics");
-                    self.info.diagnostics.tick();
+                    state.info.diagnostics.tick();
 
-                    tracing::debug!("updates per sec {}", self.info.diagnostics.updates_per_sec);
-                    tracing::debug!("frames per sec {}", self.info.diagnostics.frames_per_sec);
-                },
+                    tracing::debug!("updates per sec {}", state.info.diagnostics.updates_per_sec);
+                    tracing::debug!("frames per sec {}", state.info.diagnostics.frames_per_sec);
+                }
+            },
 
-                WindowEvent::CursorLeft { .. } => {
-                    self.info.cursor_pos = None;
-                    self.update.insert(Update::EVAL);
-                },
+            WindowEvent::CursorLeft { .. } => {
+                state.info.cursor_pos = None;
+                state.update.insert(Update::EVAL);
+            },
 
-                WindowEvent::CursorMoved { position, .. } => {
-                    self.info.cursor_pos = Some(Vector2::new(position.x as f32, position.y as f32));
-                    self.update.insert(Update::EVAL);
-                },
+            WindowEvent::CursorMoved { position, .. } => {
+                state.info.cursor_pos = Some(Vector2::new(position.x as f32, position.y as f32));
+                state.update.insert(Update::EVAL);
+            },
 
-                WindowEvent::KeyboardInput {
-                    event,
-                    device_id,
-                    is_synthetic,
-                } => {
-                    if !is_synthetic {
-                        tracing::trace!("keyboard input {event:?}");
+            WindowEvent::KeyboardInput {
+                event,
+                device_id,
+                is_synthetic: false,
+            } => {
+                tracing::trace!("keyboard input {event:?}");
 
-                        self.info.keys.push((device_id, event));
-                        self.update.insert(Update::EVAL);
-                    }
-                },
+                state.info.keys.push((device_id, event.into()));
+                state.update.insert(Update::EVAL);
+            },
 
-                WindowEvent::MouseInput {
-                    device_id,
-                    button,
-                    state,
-                } => {
-                    tracing::trace!("mouse input {button:?} {state:?}");
+            WindowEvent::Ime(ime) => {
+                tracing::trace!("ime {ime:?}");
+                state.info.ime.push(ime);
+                state.update.insert(Update::EVAL);
+            },
 
-                    self.info.buttons.push((device_id, button, state));
-                    self.update.insert(Update::EVAL);
-                },
+            WindowEvent::ModifiersChanged(modifiers) => {
+                tracing::trace!("modifiers changed {modifiers:?}");
+                state.info.modifiers = modifiers.state();
+            },
 
-                WindowEvent::MouseWheel { delta, .. } => {
-                    tracing::trace!("mouse wheel {delta:?}");
-                    self.info.mouse_scroll_delta = Some(delta);
-                    self.update.insert(Update::EVAL);
-                },
+            WindowEvent::Focused(false) => {
+                // modifier releases are not reported while the window is unfocused
+                state.info.modifiers = ModifiersState::empty();
+            },
 
-                WindowEvent::Destroyed => tracing::info!("window destroyed"),
+            WindowEvent::MouseInput {
+                device_id,
+                button,
+                state: button_state,
+            } => {
+                tracing::trace!("mouse input {button:?} {button_state:?}");
 
-                _ => (),
-            }
+                state.info.buttons.push((device_id, button, button_state));
+                state.update.insert(Update::EVAL);
+            },
+
+            WindowEvent::MouseWheel { delta, .. } => {
+                tracing::trace!("mouse wheel {delta:?}");
+                state.info.mouse_scroll_delta = Some(delta);
+                state.update.insert(Update::EVAL);
+            },
+
+            WindowEvent::Touch(touch) => {
+                tracing::trace!("touch {touch:?}");
+                state.info.touches.push(touch.into());
+                state.update.insert(Update::EVAL);
+            },
+
+            WindowEvent::PinchGesture { delta, phase, .. } => {
+                tracing::trace!("pinch gesture {delta} {phase:?}");
+                state.info.gestures.push(GestureInput::Pinch {
+                    delta: delta as f32,
+                    phase,
+                });
+                state.update.insert(Update::EVAL);
+            },
+
+            WindowEvent::RotationGesture { delta, phase, .. } => {
+                tracing::trace!("rotation gesture {delta} {phase:?}");
+                state
+                    .info
+                    .gestures
+                    .push(GestureInput::Rotation { delta, phase });
+                state.update.insert(Update::EVAL);
+            },
+
+            WindowEvent::PanGesture { delta, phase, .. } => {
+                tracing::trace!("pan gesture {delta:?} {phase:?}");
+                state.info.gestures.push(GestureInput::Pan {
+                    delta: Vector2::new(delta.x, delta.y),
+                    phase,
+                });
+                state.update.insert(Update::EVAL);
+            },
+
+            WindowEvent::HoveredFile(path) => {
+                tracing::trace!("hovered file {path:?}");
+                state.info.hovered_files.push(path);
+                state.update.insert(Update::EVAL);
+            },
+
+            WindowEvent::HoveredFileCancelled => {
+                tracing::trace!("hovered files cancelled");
+                state.info.hovered_files.clear();
+                state.update.insert(Update::EVAL);
+            },
+
+            WindowEvent::DroppedFile(path) => {
+                tracing::trace!("dropped file {path:?}");
+                state.info.hovered_files.clear();
+                state.info.dropped_files.push(path);
+                state.update.insert(Update::EVAL);
+            },
+
+            WindowEvent::Destroyed => tracing::info!("window destroyed"),
+
+            _ => (),
         }
     }
 
     #[cold]
     #[tracing::instrument(level = "trace", skip_all)]
     fn suspended(&mut self, event_loop: &ActiveEventLoop) {
+        tracing::trace!("closing secondary windows");
+        for mut state in self.windows.drain(1..) {
+            self.manager.detach(state.tree.window());
+
+            if let Some(window) = state.window.take() {
+                self.graphics
+                    .destroy(window, event_loop)
+                    .expect("Failed to destroy vector graphics interface");
+            }
+        }
+
+        let main = &mut self.windows[0];
+
         tracing::trace!("destroying vector graphics interface");
-        let window = self.window.clone().unwrap();
+        let window = main.window.clone().unwrap();
         self.graphics
             .uninit(window, event_loop)
             .expect("Failed to destroy vector graphics interface");
 
         tracing::trace!("destroying window");
-        self.window = None;
+        main.window = None;
 
         tracing::trace!("running plugin suspensions");
         self.plugins.run(|pl| {
             pl.on_suspended(
                 &mut self.config,
-                &mut self.scene,
-                &mut self.taffy,
-                self.window_node,
-                &mut self.info,
-                &self.update,
+                &mut main.tree.scene,
+                &mut main.tree.taffy,
+                main.tree.root,
+                &mut main.info,
+                &main.update,
                 event_loop,
             )
         });
 
-        self.info.reset();
+        main.info.reset();
+    }
+}
+
+/// Apply the given [WindowCommand] to the window.
+#[inline(always)]
+#[tracing::instrument(level = "trace", skip(window))]
+fn apply_command(window: &Window, command: WindowCommand) {
+    match command {
+        WindowCommand::SetTitle(title) => window.set_title(&title),
+
+        WindowCommand::SetSize(size) => {
+            // the window is resized asynchronously on some platforms and sends a resize event after
+            let _ = window.request_inner_size(LogicalSize::new(size.x, size.y));
+        },
+
+        WindowCommand::SetPosition(position) => {
+            window.set_outer_position(LogicalPosition::new(position.x, position.y))
+        },
+
+        WindowCommand::SetMinimized(minimized) => window.set_minimized(minimized),
+
+        WindowCommand::SetMaximized(maximized) => window.set_maximized(maximized),
+
+        WindowCommand::SetMode(mode) => apply_mode(window, mode),
+
+        WindowCommand::SetLevel(level) => window.set_window_level(level),
+
+        WindowCommand::SetDecorations(decorations) => window.set_decorations(decorations),
+
+        WindowCommand::SetCursor(cursor) => window.set_cursor(cursor),
+
+        WindowCommand::Drag => {
+            if let Err(err) = window.drag_window() {
+                tracing::warn!("failed to drag window: {err}");
+            }
+        },
+
+        WindowCommand::DragResize(direction) => {
+            if let Err(err) = window.drag_resize_window(direction) {
+                tracing::warn!("failed to drag resize window: {err}");
+            }
+        },
+    }
+}
+
+/// Switch the [WindowMode] of the window on its current monitor.
+#[inline(always)]
+fn apply_mode(window: &Window, mode: WindowMode) {
+    // avoid leaving fullscreen, if the window was never in it
+    if mode != WindowMode::Windowed || window.fullscreen().is_some() {
+        window.set_fullscreen(mode.fullscreen(window.current_monitor()));
     }
 }
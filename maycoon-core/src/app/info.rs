@@ -1,19 +1,52 @@
 use nalgebra::Vector2;
-use winit::event::{DeviceId, ElementState, KeyEvent, MouseButton, MouseScrollDelta};
+use std::path::PathBuf;
+use winit::event::{DeviceId, ElementState, Ime, MouseButton, MouseScrollDelta};
+use winit::keyboard::ModifiersState;
 
 use crate::app::diagnostics::Diagnostics;
 use crate::app::font_ctx::FontContext;
+use crate::app::input::{GestureInput, KeyInput, TouchInput};
 
 /// The application information container.
+#[derive(Clone)]
 pub struct AppInfo {
     /// The position of the cursor. If [None], the cursor left the window.
     pub cursor_pos: Option<Vector2<f32>>,
     /// The fired key events.
-    pub keys: Vec<(DeviceId, KeyEvent)>,
+    ///
+    /// These are the raw events of the window.
+    /// Focusable widgets should use [FocusManager::keys](crate::app::focus::FocusManager::keys),
+    /// so only the focused widget handles key input.
+    pub keys: Vec<(DeviceId, KeyInput)>,
+    /// The currently held modifier keys.
+    pub modifiers: ModifiersState,
+    /// The fired IME events.
+    ///
+    /// IME must be allowed using [AppContext::set_ime_allowed](crate::app::context::AppContext::set_ime_allowed)
+    /// to receive these events.
+    pub ime: Vec<Ime>,
     /// The fired mouse button events.
+    ///
+    /// These are the raw events of the window.
+    /// Widgets should use [PointerManager::events](crate::app::pointer::PointerManager::events),
+    /// so only the widgets under the cursor handle them.
     pub buttons: Vec<(DeviceId, MouseButton, ElementState)>,
     /// The mouse scroll delta, if a [winit::event::WindowEvent::MouseWheel] event was fired.
     pub mouse_scroll_delta: Option<MouseScrollDelta>,
+    /// The fired touch events, in the order they occurred.
+    ///
+    /// Each finger has its own identifier. Use the [gesture recognizers](crate::app::gesture)
+    /// to recognize gestures like pinching from them.
+    pub touches: Vec<TouchInput>,
+    /// The fired gesture events recognized by the platform, in the order they occurred.
+    pub gestures: Vec<GestureInput>,
+    /// The files, which are currently dragged over the window by the operating system.
+    ///
+    /// Unlike the other events, these are kept until the files are dropped or the drag is cancelled.
+    /// Widgets should use the [DragManager](crate::app::drag::DragManager) to accept dropped files.
+    pub hovered_files: Vec<PathBuf>,
+    /// The files, which were dropped onto the window.
+    pub dropped_files: Vec<PathBuf>,
     /// App Diagnostics.
     pub diagnostics: Diagnostics,
     /// The current font context.
@@ -29,7 +62,11 @@ impl AppInfo {
     pub fn reset(&mut self) {
         self.buttons.clear();
         self.keys.clear();
+        self.ime.clear();
         self.mouse_scroll_delta = None;
+        self.touches.clear();
+        self.gestures.clear();
+        self.dropped_files.clear();
     }
 }
 
@@ -39,8 +76,14 @@ impl Default for AppInfo {
         Self {
             cursor_pos: None,
             keys: Vec::with_capacity(4),
+            modifiers: ModifiersState::empty(),
+            ime: Vec::new(),
             buttons: Vec::with_capacity(2),
             mouse_scroll_delta: None,
+            touches: Vec::new(),
+            gestures: Vec::new(),
+            hovered_files: Vec::new(),
+            dropped_files: Vec::new(),
             diagnostics: Diagnostics::default(),
             font_context: FontContext::default(),
             size: Vector2::new(0.0, 0.0),
@@ -0,0 +1,136 @@
+use nalgebra::Vector2;
+use winit::event::{ElementState, Force, KeyEvent, Touch, TouchPhase};
+use winit::keyboard::{Key, KeyLocation, NativeKeyCode, PhysicalKey, SmolStr};
+
+/// A keyboard input event.
+///
+/// This mirrors [KeyEvent], but can also be constructed manually (e.g. for testing).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KeyInput {
+    /// The physical key (position on the keyboard) that was pressed or released.
+    pub physical_key: PhysicalKey,
+    /// The logical key, respecting the keyboard layout and modifiers.
+    pub logical_key: Key,
+    /// The text produced by this key event, if any.
+    pub text: Option<SmolStr>,
+    /// The location of the key on the keyboard.
+    pub location: KeyLocation,
+    /// Whether the key was pressed or released.
+    pub state: ElementState,
+    /// Whether this event was produced by holding the key down.
+    pub repeat: bool,
+}
+
+impl KeyInput {
+    /// Create a new key input from a logical key and an element state.
+    ///
+    /// The physical key is unidentified and the text is derived from the logical key.
+    #[inline(always)]
+    pub fn new(logical_key: Key, state: ElementState) -> Self {
+        let text = match &logical_key {
+            Key::Character(c) if state.is_pressed() => Some(c.clone()),
+            _ => None,
+        };
+
+        Self {
+            physical_key: PhysicalKey::Unidentified(NativeKeyCode::Unidentified),
+            logical_key,
+            text,
+            location: KeyLocation::Standard,
+            state,
+            repeat: false,
+        }
+    }
+
+    /// Returns if the key was pressed.
+    #[inline(always)]
+    pub fn is_pressed(&self) -> bool {
+        self.state.is_pressed()
+    }
+}
+
+impl From<KeyEvent> for KeyInput {
+    #[inline(always)]
+    fn from(event: KeyEvent) -> Self {
+        Self {
+            physical_key: event.physical_key,
+            logical_key: event.logical_key,
+            text: event.text,
+            location: event.location,
+            state: event.state,
+            repeat: event.repeat,
+        }
+    }
+}
+
+/// A touch input event of a single finger.
+///
+/// This mirrors [Touch], but can also be constructed manually (e.g. for testing).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TouchInput {
+    /// The unique identifier of the finger, which stays the same until the finger is lifted.
+    pub id: u64,
+    /// The phase of the touch (started, moved, ended or cancelled).
+    pub phase: TouchPhase,
+    /// The position of the finger in the window.
+    pub position: Vector2<f32>,
+    /// How hard the screen was pressed, if supported by the platform.
+    pub force: Option<Force>,
+}
+
+impl TouchInput {
+    /// Create a new touch input for the finger with the given identifier, without a force.
+    #[inline(always)]
+    pub fn new(id: u64, phase: TouchPhase, position: Vector2<f32>) -> Self {
+        Self {
+            id,
+            phase,
+            position,
+            force: None,
+        }
+    }
+}
+
+impl From<Touch> for TouchInput {
+    #[inline(always)]
+    fn from(touch: Touch) -> Self {
+        Self {
+            id: touch.id,
+            phase: touch.phase,
+            position: Vector2::new(touch.location.x as f32, touch.location.y as f32),
+            force: touch.force,
+        }
+    }
+}
+
+/// A gesture recognized by the platform, usually from a touchpad.
+///
+/// These are only reported on some platforms (like **macOS** and **iOS**).
+/// Touch gestures on touchscreens are recognized from [TouchInput] events by the
+/// [gesture recognizers](crate::app::gesture).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum GestureInput {
+    /// A two-finger pinch gesture.
+    Pinch {
+        /// The change of magnification since the last event.
+        /// Positive values indicate zooming in and negative values zooming out.
+        delta: f32,
+        /// The phase of the gesture.
+        phase: TouchPhase,
+    },
+    /// A two-finger rotation gesture.
+    Rotation {
+        /// The change of rotation in degrees since the last event.
+        /// Positive values indicate a counterclockwise and negative values a clockwise rotation.
+        delta: f32,
+        /// The phase of the gesture.
+        phase: TouchPhase,
+    },
+    /// A multi-finger pan gesture.
+    Pan {
+        /// The movement in pixels since the last event.
+        delta: Vector2<f32>,
+        /// The phase of the gesture.
+        phase: TouchPhase,
+    },
+}
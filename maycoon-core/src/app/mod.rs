@@ -18,6 +18,9 @@ pub mod handler;
 /// Contains the application information structure.
 pub mod info;
 
+/// Contains input event structures.
+pub mod input;
+
 /// Contains the update mode bitflag.
 pub mod update;
 
@@ -27,6 +30,30 @@ pub mod context;
 /// Contains the [MayRunner] structure to create and run an application using `winit`.
 pub mod runner;
 
+/// Contains the [tree::WidgetTree] structure for running the widget pipeline.
+pub mod tree;
+
+/// Contains the [window::WindowManager] for opening and closing windows at runtime.
+pub mod window;
+
+/// Contains the [focus::FocusManager] for keyboard focus management.
+pub mod focus;
+
+/// Contains the [pointer::PointerManager] for hit-testing and routing pointer events.
+pub mod pointer;
+
+/// Contains recognizers for touch gestures like pinching, panning and rotating.
+pub mod gesture;
+
+/// Contains the [drag::DragManager] for drag-and-drop operations.
+pub mod drag;
+
+/// Contains the [clipboard::Clipboard] for copying and pasting text and images.
+pub mod clipboard;
+
+/// Contains the [shortcut::ShortcutManager] for keyboard shortcuts.
+pub mod shortcut;
+
 /// The main application interface.
 ///
 /// Contains basic functions for the [MayRunner] to create and run an application.
@@ -48,7 +75,7 @@ pub trait Application: Sized {
     /// Renders/builds the application's widgets.
     ///
     /// This function will be passed to the [MayRunner] to create and run the application.
-    fn build(context: AppContext, state: Self::State) -> impl Widget;
+    fn build(context: AppContext, state: Self::State) -> impl Widget + 'static;
 
     /// Returns the [MayConfig] for the application.
     fn config(&self) -> MayConfig<Self::Theme, Self::Graphics>;
@@ -0,0 +1,963 @@
+use std::cell::RefCell;
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use nalgebra::Vector2;
+use winit::event::{ElementState, MouseButton, MouseScrollDelta};
+use winit::window::CursorIcon;
+
+use crate::app::info::AppInfo;
+use crate::layout::Layout;
+use crate::vgi::kurbo::{Point, Rect};
+
+/// A stable identifier of a widget taking part in pointer routing.
+///
+/// Widgets create their ids once (e.g. when they are constructed) and register with the same id on every update.
+/// Pointer events are routed to the id of the widget they hit,
+/// so they reach the right widget even if other widgets were added or removed in the meantime.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PointerId(u64);
+
+impl PointerId {
+    /// Create a new unique pointer id.
+    #[inline(always)]
+    pub fn new() -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+
+        Self(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+impl Default for PointerId {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A handle to a widget registered for pointer events, returned by [PointerManager::register].
+///
+/// The handle is only valid for the current update pass and should be re-acquired on every update.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Pointer {
+    index: usize,
+    id: PointerId,
+}
+
+impl Pointer {
+    /// Get the index of the widget in paint order (tree order) in the current update pass.
+    #[inline(always)]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Get the [PointerId] the widget registered with.
+    #[inline(always)]
+    pub const fn id(&self) -> PointerId {
+        self.id
+    }
+}
+
+/// The phase in which a [PointerEvent] is delivered to a widget.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Phase {
+    /// The event travels from the outermost widget towards the target.
+    ///
+    /// Only delivered to widgets registered using [PointerManager::register_capture].
+    Capture,
+    /// The event is delivered to the target, which is the topmost widget under the cursor.
+    ///
+    /// Events are also delivered in this phase to the widget capturing the pointer using [PointerManager::capture].
+    Target,
+    /// The event travels from the target back to the outermost widget.
+    Bubble,
+}
+
+/// Whether a pointer event propagates to the next widget after being delivered to a widget.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum Propagation {
+    /// The event propagates to the next widget.
+    #[default]
+    Continue,
+    /// The event stops propagating, so the following widgets don't receive it.
+    Stop,
+    /// Button events stop propagating, while scroll events propagate to the next widget.
+    ///
+    /// Useful for clickable widgets, which should not keep the widgets containing them from scrolling.
+    StopButtons,
+    /// Scroll events stop propagating, while button events propagate to the next widget.
+    ///
+    /// Useful for scrollable widgets, so the scrollable widgets containing them don't scroll as well.
+    StopScroll,
+}
+
+impl Propagation {
+    /// Returns if an event of the given kind stops propagating after being delivered.
+    #[inline(always)]
+    pub const fn stops(&self, kind: &PointerEventKind) -> bool {
+        match self {
+            Propagation::Continue => false,
+            Propagation::Stop => true,
+            Propagation::StopButtons => matches!(kind, PointerEventKind::Button(..)),
+            Propagation::StopScroll => matches!(kind, PointerEventKind::Scroll(..)),
+        }
+    }
+}
+
+/// The kind of [PointerEvent].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PointerEventKind {
+    /// A mouse button was pressed or released.
+    Button(MouseButton, ElementState),
+    /// The mouse wheel or touchpad was scrolled.
+    Scroll(MouseScrollDelta),
+}
+
+/// A pointer event, which was routed to a widget by the [PointerManager].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PointerEvent {
+    /// The kind of event.
+    pub kind: PointerEventKind,
+    /// The cursor position at the time of the event.
+    pub position: Vector2<f32>,
+    /// The phase in which the event is delivered.
+    pub phase: Phase,
+}
+
+/// Hit-tests the widgets of a widget tree and routes pointer events to them.
+///
+/// Widgets taking part in pointer routing call [PointerManager::register] on every update with their [PointerId] and layout,
+/// which places them in paint order (tree order).
+/// The topmost widget under the cursor is the target of the pointer events.
+/// Widgets, which don't register themselves, are transparent to the pointer.
+///
+/// Events are routed along the target and the widgets containing it, which are established
+/// by updating children inside [PointerManager::scope]:
+/// 1. In the [Phase::Capture], from the outermost widget towards the target, if registered using [PointerManager::register_capture].
+/// 2. In the [Phase::Target], to the target itself.
+/// 3. In the [Phase::Bubble], from the target back to the outermost widget.
+///
+/// Widgets registered with [Propagation::Stop] stop the event after receiving it.
+/// [Propagation::StopButtons] and [Propagation::StopScroll] only stop button or scroll events,
+/// so clickable widgets inside scrollable widgets don't keep them from scrolling and vice versa.
+/// The routed events for a widget are returned by [PointerManager::events].
+/// Clipping widgets should update their children inside [PointerManager::clip],
+/// so clipped parts of the children can't be hit.
+///
+/// While dragging, widgets can capture the pointer using [PointerManager::capture],
+/// so they receive all pointer events, even if the cursor left them or the window.
+///
+/// Widgets can request a [CursorIcon] while they are hovered using [PointerManager::set_cursor_icon].
+/// The icon of the topmost hovered widget requesting one is applied to the window after each update pass.
+///
+/// Hit-testing uses the widgets registered during the previous update pass and events are routed to their ids.
+/// If the registered widgets moved afterward (e.g. because of a re-layout), the regions are outdated
+/// and [PointerManager::invalidate] holds the events back until the widgets registered at their new positions.
+///
+/// The manager belongs to a single window and can be cheaply cloned.
+#[derive(Clone, Default)]
+pub struct PointerManager {
+    inner: Rc<RefCell<PointerState>>,
+}
+
+#[derive(Debug, Default)]
+struct PointerState {
+    regions: Vec<Region>,
+    previous: Vec<Region>,
+    parents: Vec<usize>,
+    clips: Vec<Rect>,
+    path: Vec<PointerId>,
+    events: Vec<(PointerId, PointerEvent)>,
+    pending: Vec<(PointerEventKind, Vector2<f32>)>,
+    capture: Option<PointerId>,
+    position: Option<Vector2<f32>>,
+    invalid: bool,
+}
+
+/// The hit region of a registered widget.
+#[derive(Debug)]
+struct Region {
+    id: PointerId,
+    rect: Rect,
+    parent: Option<usize>,
+    capture: bool,
+    propagation: Propagation,
+    cursor: Option<CursorIcon>,
+}
+
+impl PointerManager {
+    /// Create a new pointer manager with no registered widgets.
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a widget with the given id and layout as hit region for the current update pass.
+    ///
+    /// The widget receives events in the [Phase::Target] and [Phase::Bubble].
+    /// This must be called on every update, before checking for pointer events.
+    #[inline(always)]
+    pub fn register(&self, id: PointerId, layout: &Layout, propagation: Propagation) -> Pointer {
+        self.insert(id, layout, false, propagation)
+    }
+
+    /// Register a widget with the given id and layout as hit region for the current update pass.
+    ///
+    /// Unlike [PointerManager::register], the widget receives events in the [Phase::Capture] instead of the [Phase::Bubble],
+    /// so it can stop events before they reach the widgets inside it.
+    #[inline(always)]
+    pub fn register_capture(
+        &self,
+        id: PointerId,
+        layout: &Layout,
+        propagation: Propagation,
+    ) -> Pointer {
+        self.insert(id, layout, true, propagation)
+    }
+
+    /// Insert a new hit region for the current update pass.
+    fn insert(
+        &self,
+        id: PointerId,
+        layout: &Layout,
+        capture: bool,
+        propagation: Propagation,
+    ) -> Pointer {
+        let mut inner = self.inner.borrow_mut();
+
+        let rect = Rect::new(
+            layout.location.x as f64,
+            layout.location.y as f64,
+            (layout.location.x + layout.size.width) as f64,
+            (layout.location.y + layout.size.height) as f64,
+        );
+
+        let region = Region {
+            id,
+            rect: inner.clips.last().map_or(rect, |clip| clip.intersect(rect)),
+            parent: inner.parents.last().copied(),
+            capture,
+            propagation,
+            cursor: None,
+        };
+
+        inner.regions.push(region);
+
+        Pointer {
+            index: inner.regions.len() - 1,
+            id,
+        }
+    }
+
+    /// Run the given function with the given widget as the parent of all widgets registered inside it.
+    ///
+    /// Widgets with children should update their children inside this function,
+    /// so events targeting the children are routed through the widget.
+    #[inline(always)]
+    pub fn scope<R>(&self, pointer: Pointer, f: impl FnOnce() -> R) -> R {
+        self.inner.borrow_mut().parents.push(pointer.index);
+
+        let result = f();
+
+        self.inner.borrow_mut().parents.pop();
+
+        result
+    }
+
+    /// Run the given function with all widgets registered inside it clipped to the given rectangle.
+    ///
+    /// Clips are nested, like [Scene::push_clip](crate::vgi::Scene::push_clip).
+    #[inline(always)]
+    pub fn clip<R>(&self, rect: &Rect, f: impl FnOnce() -> R) -> R {
+        {
+            let mut inner = self.inner.borrow_mut();
+
+            let clip = inner
+                .clips
+                .last()
+                .map_or(*rect, |clip| clip.intersect(*rect));
+
+            inner.clips.push(clip);
+        }
+
+        let result = f();
+
+        self.inner.borrow_mut().clips.pop();
+
+        result
+    }
+
+    /// Returns the pointer events routed to the given widget in the current update pass.
+    #[inline(always)]
+    pub fn events(&self, pointer: Pointer) -> Vec<PointerEvent> {
+        self.inner
+            .borrow()
+            .events
+            .iter()
+            .filter(|(id, _)| *id == pointer.id)
+            .map(|(_, event)| *event)
+            .collect()
+    }
+
+    /// Returns if the cursor hovers the given widget.
+    ///
+    /// This is the case for the target and all widgets containing it, regardless of [Propagation].
+    #[inline(always)]
+    pub fn is_hovered(&self, pointer: Pointer) -> bool {
+        self.inner.borrow().path.contains(&pointer.id)
+    }
+
+    /// Returns if the given widget is the topmost widget under the cursor.
+    #[inline(always)]
+    pub fn is_target(&self, pointer: Pointer) -> bool {
+        self.target() == Some(pointer.id)
+    }
+
+    /// Returns if the given widget would be hovered, if the cursor was at the given position.
+    ///
+    /// This can be used to hit-test other pointers, like the fingers on a touchscreen.
+    #[inline(always)]
+    pub fn hits(&self, pointer: Pointer, position: Vector2<f32>) -> bool {
+        let inner = self.inner.borrow();
+
+        hit_path(&inner.previous, position)
+            .into_iter()
+            .any(|index| inner.previous[index].id == pointer.id)
+    }
+
+    /// Request the given [CursorIcon] while the cursor hovers the given widget in the current update pass.
+    ///
+    /// This must be called on every update, like [PointerManager::register].
+    /// Widgets inside the given widget, which don't request an icon themselves, show the same icon.
+    #[inline(always)]
+    pub fn set_cursor_icon(&self, pointer: Pointer, icon: CursorIcon) {
+        self.inner.borrow_mut().regions[pointer.index].cursor = Some(icon);
+    }
+
+    /// Returns the [CursorIcon] requested by the topmost widget under the cursor or the widgets containing it
+    /// in the current update pass.
+    ///
+    /// Returns [None] if the cursor is outside the window or no hovered widget requests an icon.
+    #[inline(always)]
+    pub fn cursor_icon(&self) -> Option<CursorIcon> {
+        let inner = self.inner.borrow();
+        let position = inner.position?;
+
+        hit_path(&inner.regions, position)
+            .into_iter()
+            .find_map(|index| inner.regions[index].cursor)
+    }
+
+    /// Capture the pointer for the given widget during the next update pass.
+    ///
+    /// While captured, all pointer events are delivered to the widget in the [Phase::Target],
+    /// even if the cursor is outside the widget or the window, so it doesn't miss the release ending a drag.
+    /// This must be called on every update while capturing, like [PointerManager::register].
+    #[inline(always)]
+    pub fn capture(&self, pointer: Pointer) {
+        self.inner.borrow_mut().capture = Some(pointer.id);
+    }
+
+    /// Mark the hit regions registered in the current update pass as outdated, because the widgets moved afterward.
+    ///
+    /// The pointer events of the next update pass are held back and routed in the following pass,
+    /// after the widgets registered at their new positions.
+    /// This is done automatically after the widget tree was re-laid out.
+    #[inline(always)]
+    pub fn invalidate(&self) {
+        self.inner.borrow_mut().invalid = true;
+    }
+
+    /// Get the id of the topmost widget under the cursor. Returns [None] if no registered widget is hit.
+    #[inline(always)]
+    pub fn target(&self) -> Option<PointerId> {
+        self.inner.borrow().path.first().copied()
+    }
+
+    /// Prepare a new update pass and route the pointer events of the given [AppInfo]
+    /// using the widgets registered during the previous pass.
+    ///
+    /// If the previous regions are outdated, the events are held back until the next pass.
+    #[tracing::instrument(level = "trace", skip_all)]
+    pub(crate) fn begin(&self, info: &AppInfo) {
+        let mut inner = self.inner.borrow_mut();
+        let inner = &mut *inner;
+
+        inner.previous = std::mem::take(&mut inner.regions);
+        inner.parents.clear();
+        inner.clips.clear();
+        inner.path.clear();
+        inner.events.clear();
+
+        let regions = &inner.previous;
+
+        // the capturing widget must still exist
+        let capture = inner
+            .capture
+            .take()
+            .filter(|id| regions.iter().any(|region| region.id == *id));
+
+        // events outside the window are only delivered to the capturing widget
+        let position = info
+            .cursor_pos
+            .or(inner.position.filter(|_| capture.is_some()));
+
+        inner.position = info.cursor_pos;
+
+        if let Some(cursor) = info.cursor_pos {
+            inner.path = hit_path(regions, cursor)
+                .into_iter()
+                .map(|index| regions[index].id)
+                .collect();
+        }
+
+        if let Some(position) = position {
+            let kinds = info
+                .buttons
+                .iter()
+                .map(|(_, btn, el)| PointerEventKind::Button(*btn, *el))
+                .chain(info.mouse_scroll_delta.map(PointerEventKind::Scroll));
+
+            inner.pending.extend(kinds.map(|kind| (kind, position)));
+        }
+
+        if std::mem::take(&mut inner.invalid) {
+            tracing::trace!("holding back {} pointer events", inner.pending.len());
+            return;
+        }
+
+        for (kind, position) in std::mem::take(&mut inner.pending) {
+            let event = |phase| PointerEvent {
+                kind,
+                position,
+                phase,
+            };
+
+            if let Some(id) = capture {
+                inner.events.push((id, event(Phase::Target)));
+                continue;
+            }
+
+            let path = hit_path(regions, position);
+
+            let Some((&target, parents)) = path.split_first() else {
+                continue;
+            };
+
+            let capture = parents
+                .iter()
+                .rev()
+                .filter(|i| regions[**i].capture)
+                .map(|i| (*i, event(Phase::Capture)));
+            let bubble = parents
+                .iter()
+                .filter(|i| !regions[**i].capture)
+                .map(|i| (*i, event(Phase::Bubble)));
+
+            for (index, event) in capture
+                .chain([(target, event(Phase::Target))])
+                .chain(bubble)
+            {
+                inner.events.push((regions[index].id, event));
+
+                if regions[index].propagation.stops(&kind) {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Finish the current update pass.
+    ///
+    /// Returns if pointer events were held back, so another update pass is required to deliver them.
+    #[inline(always)]
+    pub(crate) fn end(&self) -> bool {
+        !self.inner.borrow().pending.is_empty()
+    }
+}
+
+/// Hit-test the given regions at the given position.
+///
+/// Returns the index of the topmost region containing the position, followed by the indices of its parents.
+fn hit_path(regions: &[Region], position: Vector2<f32>) -> Vec<usize> {
+    // the topmost widget is painted last
+    let point = Point::new(position.x as f64, position.y as f64);
+    let Some(target) = regions.iter().rposition(|r| r.rect.contains(point)) else {
+        return Vec::new();
+    };
+
+    let mut path = vec![target];
+
+    while let Some(parent) = regions[*path.last().unwrap()].parent {
+        path.push(parent);
+    }
+
+    path
+}
+
+impl Debug for PointerManager {
+    #[inline(always)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let inner = self.inner.borrow();
+
+        f.debug_struct("PointerManager")
+            .field("path", &inner.path)
+            .field("events", &inner.events.len())
+            .field("pending", &inner.pending.len())
+            .field("capture", &inner.capture)
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use crate::app::context::AppContext;
+    use crate::app::info::AppInfo;
+    use crate::app::pointer::{Phase, PointerEvent, PointerEventKind, PointerId, Propagation};
+    use crate::app::update::Update;
+    use crate::layout::{
+        Dimension, LayoutNode, LayoutStyle, LengthPercentageAuto, Position, Rect, StyleNode,
+    };
+    use crate::testing::Harness;
+    use crate::vgi::Scene;
+    use crate::vgi::kurbo;
+    use crate::widget::Widget;
+    use maycoon_theme::id::WidgetId;
+    use maycoon_theme::theme::Theme;
+    use maycoon_theme::theme::dummy::DummyTheme;
+    use nalgebra::Vector2;
+    use winit::event::{ElementState, MouseButton, MouseScrollDelta};
+    use winit::window::CursorIcon;
+
+    /// An absolutely positioned widget recording the pointer events it receives.
+    struct Hit {
+        id: PointerId,
+        position: Vector2<f32>,
+        size: Vector2<f32>,
+        capture: bool,
+        propagation: Propagation,
+        clip: bool,
+        cursor: Option<CursorIcon>,
+        jump: Option<Vector2<f32>>,
+        grab: bool,
+        hidden: bool,
+        children: Vec<Hit>,
+        events: Vec<PointerEvent>,
+        hovered: bool,
+    }
+
+    impl Hit {
+        fn new(x: f32, y: f32, size: f32) -> Self {
+            Self {
+                id: PointerId::new(),
+                position: Vector2::new(x, y),
+                size: Vector2::new(size, size),
+                capture: false,
+                propagation: Propagation::Continue,
+                clip: false,
+                cursor: None,
+                jump: None,
+                grab: false,
+                hidden: false,
+                children: Vec::new(),
+                events: Vec::new(),
+                hovered: false,
+            }
+        }
+
+        fn with_children(mut self, children: Vec<Hit>) -> Self {
+            self.children = children;
+            self
+        }
+
+        fn phases(&self) -> Vec<Phase> {
+            self.events.iter().map(|e| e.phase).collect()
+        }
+    }
+
+    impl Widget for Hit {
+        fn render(
+            &mut self,
+            _: &mut dyn Scene,
+            _: &mut dyn Theme,
+            _: &LayoutNode,
+            _: &AppInfo,
+            _: AppContext,
+        ) {
+        }
+
+        fn layout_style(&self) -> StyleNode {
+            StyleNode {
+                style: LayoutStyle {
+                    position: Position::Absolute,
+                    inset: Rect {
+                        left: LengthPercentageAuto::length(self.position.x),
+                        top: LengthPercentageAuto::length(self.position.y),
+                        right: LengthPercentageAuto::auto(),
+                        bottom: LengthPercentageAuto::auto(),
+                    },
+                    size: self.size.map(Dimension::length),
+                    ..Default::default()
+                },
+                children: self.children.iter().map(|c| c.layout_style()).collect(),
+                measure: None,
+            }
+        }
+
+        fn update(&mut self, layout: &LayoutNode, context: AppContext, info: &AppInfo) -> Update {
+            if self.hidden {
+                return Update::empty();
+            }
+
+            let pointer = if self.capture {
+                context
+                    .pointer()
+                    .register_capture(self.id, &layout.layout, self.propagation)
+            } else {
+                context
+                    .pointer()
+                    .register(self.id, &layout.layout, self.propagation)
+            };
+
+            let mut update = Update::empty();
+
+            for event in context.pointer().events(pointer) {
+                if let Some(cursor) = info.cursor_pos {
+                    assert_eq!(event.position, cursor);
+                }
+
+                // move to the jump position when clicked
+                if event.kind == PointerEventKind::Button(MouseButton::Left, ElementState::Released)
+                    && let Some(jump) = self.jump.take()
+                {
+                    self.position = jump;
+                    update |= Update::LAYOUT;
+                }
+
+                self.events.push(event);
+            }
+
+            self.hovered = context.pointer().is_hovered(pointer);
+
+            if let Some(icon) = self.cursor {
+                context.pointer().set_cursor_icon(pointer, icon);
+            }
+
+            if self.grab {
+                context.pointer().capture(pointer);
+            }
+
+            let rect = kurbo::Rect::new(
+                layout.layout.location.x as f64,
+                layout.layout.location.y as f64,
+                (layout.layout.location.x + layout.layout.size.width) as f64,
+                (layout.layout.location.y + layout.layout.size.height) as f64,
+            );
+
+            let mut update_children = || {
+                for (child, layout) in self.children.iter_mut().zip(&layout.children) {
+                    child.update(layout, context.clone(), info);
+                }
+            };
+
+            context.pointer().scope(pointer, || {
+                if self.clip {
+                    context.pointer().clip(&rect, update_children);
+                } else {
+                    update_children();
+                }
+            });
+
+            update
+        }
+
+        fn widget_id(&self) -> WidgetId {
+            WidgetId::new("maycoon-core", "Hit")
+        }
+    }
+
+    /// Tests that only the topmost of overlapping widgets is targeted and events bubble to the containing widget.
+    #[test]
+    fn test_pointer_target() {
+        let root = Hit::new(0.0, 0.0, 100.0)
+            .with_children(vec![Hit::new(0.0, 0.0, 50.0), Hit::new(25.0, 25.0, 50.0)]);
+
+        let mut harness = Harness::new(root, DummyTheme::default(), Vector2::new(100.0, 100.0));
+        let pointer = harness.context().pointer();
+
+        harness.frame();
+
+        harness.click(Vector2::new(30.0, 30.0));
+        harness.frame();
+
+        let root = harness.widget();
+
+        assert_eq!(pointer.target(), Some(root.children[1].id));
+        assert!(root.children[0].events.is_empty());
+        assert!(!root.children[0].hovered);
+        assert!(root.children[1].hovered);
+        assert!(root.hovered);
+        assert_eq!(root.children[1].phases(), vec![Phase::Target; 2]);
+        assert_eq!(root.phases(), vec![Phase::Bubble; 2]);
+        assert_eq!(
+            root.children[1].events[0].kind,
+            PointerEventKind::Button(MouseButton::Left, ElementState::Pressed)
+        );
+        assert_eq!(
+            root.children[1].events[0].position,
+            Vector2::new(30.0, 30.0)
+        );
+
+        // outside both children
+        harness.click(Vector2::new(90.0, 10.0));
+        harness.frame();
+
+        assert_eq!(pointer.target(), Some(harness.widget().id));
+        assert_eq!(
+            harness.widget().phases(),
+            vec![Phase::Bubble, Phase::Bubble, Phase::Target, Phase::Target]
+        );
+    }
+
+    /// Tests stopping the propagation in the capture and bubble phase.
+    #[test]
+    fn test_pointer_propagation() {
+        let mut child = Hit::new(0.0, 0.0, 50.0);
+
+        child.propagation = Propagation::Stop;
+
+        let mut harness = Harness::new(
+            Hit::new(0.0, 0.0, 100.0).with_children(vec![child]),
+            DummyTheme::default(),
+            Vector2::new(100.0, 100.0),
+        );
+
+        harness.frame();
+
+        harness.click(Vector2::new(10.0, 10.0));
+        harness.frame();
+
+        assert!(harness.widget().events.is_empty());
+        assert_eq!(
+            harness.widget().children[0].phases(),
+            vec![Phase::Target; 2]
+        );
+
+        harness.widget_mut().capture = true;
+        harness.widget_mut().propagation = Propagation::Stop;
+        harness.widget_mut().children[0].events.clear();
+
+        harness.force_update();
+        harness.frame();
+
+        harness.click(Vector2::new(10.0, 10.0));
+        harness.frame();
+
+        assert_eq!(harness.widget().phases(), vec![Phase::Capture; 2]);
+        assert!(harness.widget().children[0].events.is_empty());
+    }
+
+    /// Tests stopping only button or scroll events.
+    #[test]
+    fn test_pointer_propagation_kind() {
+        let mut child = Hit::new(0.0, 0.0, 50.0);
+
+        child.propagation = Propagation::StopButtons;
+
+        let mut harness = Harness::new(
+            Hit::new(0.0, 0.0, 100.0).with_children(vec![child]),
+            DummyTheme::default(),
+            Vector2::new(100.0, 100.0),
+        );
+
+        harness.frame();
+
+        harness.click(Vector2::new(10.0, 10.0));
+        harness.scroll(MouseScrollDelta::LineDelta(0.0, 1.0));
+        harness.frame();
+
+        assert_eq!(harness.widget().children[0].events.len(), 3);
+        assert_eq!(harness.widget().events.len(), 1);
+        assert!(matches!(
+            harness.widget().events[0].kind,
+            PointerEventKind::Scroll(_)
+        ));
+
+        harness.widget_mut().children[0].propagation = Propagation::StopScroll;
+        harness.widget_mut().events.clear();
+        harness.force_update();
+        harness.frame();
+
+        harness.click(Vector2::new(10.0, 10.0));
+        harness.scroll(MouseScrollDelta::LineDelta(0.0, 1.0));
+        harness.frame();
+
+        assert_eq!(harness.widget().phases(), vec![Phase::Bubble; 2]);
+        assert!(matches!(
+            harness.widget().events[0].kind,
+            PointerEventKind::Button(..)
+        ));
+    }
+
+    /// Tests that clipped parts of widgets can't be hit.
+    #[test]
+    fn test_pointer_clip() {
+        let mut root = Hit::new(0.0, 0.0, 50.0).with_children(vec![Hit::new(40.0, 40.0, 50.0)]);
+
+        root.clip = true;
+
+        let mut harness = Harness::new(root, DummyTheme::default(), Vector2::new(100.0, 100.0));
+        let pointer = harness.context().pointer();
+
+        harness.frame();
+
+        harness.cursor_move(Vector2::new(60.0, 60.0));
+        harness.frame();
+
+        assert_eq!(pointer.target(), None);
+        assert!(!harness.widget().children[0].hovered);
+
+        harness.cursor_move(Vector2::new(45.0, 45.0));
+        harness.frame();
+
+        assert_eq!(pointer.target(), Some(harness.widget().children[0].id));
+        assert!(harness.widget().children[0].hovered);
+    }
+
+    /// Tests that the cursor icon of the topmost hovered widget requesting one is used.
+    #[test]
+    fn test_pointer_cursor_icon() {
+        let mut root = Hit::new(0.0, 0.0, 100.0)
+            .with_children(vec![Hit::new(0.0, 0.0, 50.0), Hit::new(50.0, 50.0, 50.0)]);
+
+        root.cursor = Some(CursorIcon::Pointer);
+        root.children[1].cursor = Some(CursorIcon::Text);
+
+        let mut harness = Harness::new(root, DummyTheme::default(), Vector2::new(100.0, 100.0));
+        let pointer = harness.context().pointer();
+
+        harness.frame();
+
+        assert_eq!(pointer.cursor_icon(), None);
+
+        // the first child inherits the icon of the root
+        harness.cursor_move(Vector2::new(10.0, 10.0));
+        harness.frame();
+
+        assert_eq!(pointer.cursor_icon(), Some(CursorIcon::Pointer));
+
+        harness.cursor_move(Vector2::new(60.0, 60.0));
+        harness.frame();
+
+        assert_eq!(pointer.cursor_icon(), Some(CursorIcon::Text));
+
+        harness.widget_mut().cursor = None;
+        harness.cursor_move(Vector2::new(10.0, 10.0));
+        harness.frame();
+
+        assert_eq!(pointer.cursor_icon(), None);
+
+        harness.cursor_leave();
+        harness.widget_mut().cursor = Some(CursorIcon::Pointer);
+        harness.frame();
+
+        assert_eq!(pointer.cursor_icon(), None);
+    }
+
+    /// Tests that pointer events are held back after a re-layout, until the hit regions follow the new layout.
+    #[test]
+    fn test_pointer_relayout() {
+        let mut root = Hit::new(0.0, 0.0, 50.0);
+
+        root.jump = Some(Vector2::new(50.0, 50.0));
+
+        let mut harness = Harness::new(root, DummyTheme::default(), Vector2::new(100.0, 100.0));
+
+        harness.frame();
+
+        harness.click(Vector2::new(25.0, 25.0));
+        assert!(harness.frame().contains(Update::LAYOUT));
+        assert_eq!(harness.widget().events.len(), 2);
+
+        // the widget moved away from the cursor
+        assert!(harness.frame().is_empty());
+
+        // the regions are outdated, so the click is delivered in the next update
+        harness.click(Vector2::new(75.0, 75.0));
+        assert!(harness.frame().contains(Update::EVAL));
+        assert_eq!(harness.widget().events.len(), 2);
+
+        harness.frame();
+
+        assert_eq!(harness.widget().events.len(), 4);
+        assert!(
+            harness.widget().events[2..]
+                .iter()
+                .all(|e| e.phase == Phase::Target)
+        );
+        assert!(harness.frame().is_empty());
+    }
+
+    /// Tests that events reach the widget they hit, even if a widget before it stopped taking part in pointer routing.
+    #[test]
+    fn test_pointer_stable_ids() {
+        let root = Hit::new(0.0, 0.0, 100.0)
+            .with_children(vec![Hit::new(0.0, 0.0, 50.0), Hit::new(50.0, 50.0, 50.0)]);
+
+        let mut harness = Harness::new(root, DummyTheme::default(), Vector2::new(100.0, 100.0));
+
+        harness.frame();
+
+        harness.widget_mut().children[0].hidden = true;
+        harness.click(Vector2::new(75.0, 75.0));
+        harness.frame();
+
+        assert_eq!(
+            harness.widget().children[1].phases(),
+            vec![Phase::Target; 2]
+        );
+        assert_eq!(harness.widget().phases(), vec![Phase::Bubble; 2]);
+    }
+
+    /// Tests that a widget capturing the pointer receives all events, even outside the window.
+    #[test]
+    fn test_pointer_capture() {
+        let mut root = Hit::new(0.0, 0.0, 100.0).with_children(vec![Hit::new(0.0, 0.0, 50.0)]);
+
+        root.children[0].grab = true;
+
+        let mut harness = Harness::new(root, DummyTheme::default(), Vector2::new(100.0, 100.0));
+
+        harness.frame();
+
+        harness.click(Vector2::new(75.0, 75.0));
+        harness.frame();
+
+        assert!(harness.widget().events.is_empty());
+        assert_eq!(
+            harness.widget().children[0].phases(),
+            vec![Phase::Target; 2]
+        );
+
+        harness.cursor_leave();
+        harness.mouse_input(MouseButton::Left, ElementState::Released);
+        harness.frame();
+
+        let events = &harness.widget().children[0].events;
+
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[2].position, Vector2::new(75.0, 75.0));
+
+        // without capturing, events outside the window are dropped
+        harness.widget_mut().children[0].grab = false;
+        harness.force_update();
+        harness.frame();
+
+        harness.mouse_input(MouseButton::Left, ElementState::Released);
+        harness.frame();
+
+        assert_eq!(harness.widget().children[0].events.len(), 3);
+        assert!(harness.widget().events.is_empty());
+    }
+}
@@ -8,9 +8,7 @@ use crate::vgi::VectorGraphicsInterface;
 use crate::widget::Widget;
 use maycoon_theme::theme::Theme;
 use peniko::FontData;
-use winit::dpi::{LogicalPosition, LogicalSize, Position, Size};
 use winit::event_loop::EventLoopBuilder;
-use winit::window::WindowAttributes;
 
 /// The core Application structure.
 pub struct MayRunner<T: Theme, V: VectorGraphicsInterface> {
@@ -52,7 +50,7 @@ impl<T: Theme, V: VectorGraphicsInterface> MayRunner<T, V> {
     #[tracing::instrument(level = "info", skip_all)]
     pub fn run<S, W, F>(mut self, state: S, builder: F, mut plugins: PluginManager<T, V>)
     where
-        W: Widget,
+        W: Widget + 'static,
         F: Fn(AppContext, S) -> W,
     {
         tracing::trace!("building event loop");
@@ -60,52 +58,7 @@ impl<T: Theme, V: VectorGraphicsInterface> MayRunner<T, V> {
             .build()
             .expect("Failed to create event loop");
 
-        let mut attrs = WindowAttributes::default()
-            .with_inner_size(LogicalSize::new(
-                self.config.window.size.x,
-                self.config.window.size.y,
-            ))
-            .with_resizable(self.config.window.resizable)
-            .with_enabled_buttons(self.config.window.buttons)
-            .with_title(self.config.window.title.clone())
-            .with_maximized(self.config.window.maximized)
-            .with_visible(self.config.window.visible)
-            .with_transparent(self.config.window.transparent)
-            .with_blur(self.config.window.blur)
-            .with_decorations(self.config.window.decorations)
-            .with_window_icon(self.config.window.icon.clone())
-            .with_content_protected(self.config.window.content_protected)
-            .with_window_level(self.config.window.level)
-            .with_active(self.config.window.active)
-            .with_cursor(self.config.window.cursor.clone());
-
-        // since `with_max_inner_size()` doesn't support `Option` values, we need to manually set it
-        attrs.max_inner_size = self
-            .config
-            .window
-            .max_size
-            .map(|v| Size::Logical(LogicalSize::new(v.x, v.y)));
-
-        // since `with_min_inner_size()` doesn't support `Option` values, we need to manually set it
-        attrs.min_inner_size = self
-            .config
-            .window
-            .min_size
-            .map(|v| Size::Logical(LogicalSize::new(v.x, v.y)));
-
-        // since `with_position()` doesn't support `Option` values, we need to manually set it
-        attrs.position = self
-            .config
-            .window
-            .position
-            .map(|v| Position::Logical(LogicalPosition::new(v.x, v.y)));
-
-        // since `with_resize_increments()` doesn't support `Option` values, we need to manually set it
-        attrs.resize_increments = self
-            .config
-            .window
-            .resize_increments
-            .map(|v| Size::Logical(LogicalSize::new(v.x, v.y)));
+        let mut attrs = self.config.window.attributes();
 
         tracing::trace!("creating update manager");
         let update = UpdateManager::new();
@@ -0,0 +1,545 @@
+use std::cell::RefCell;
+use std::fmt::{Debug, Formatter};
+use std::ops::Range;
+use std::rc::Rc;
+
+use winit::keyboard::{Key, ModifiersState};
+
+use crate::app::info::AppInfo;
+use crate::app::input::KeyInput;
+
+/// A key chord, consisting of a logical key and the modifiers held while pressing it.
+///
+/// Character keys are compared case-insensitively, so `Shift+S` matches a shortcut for `S` with [Shortcut::with_shift].
+///
+/// Example:
+/// ```
+/// # use maycoon_core::app::shortcut::Shortcut;
+/// # use maycoon_core::window::{Key, NamedKey};
+/// // Ctrl+S (Cmd+S on macOS)
+/// let save = Shortcut::char('s').with_primary();
+/// // Ctrl+Shift+Tab
+/// let previous = Shortcut::new(Key::Named(NamedKey::Tab)).with_control().with_shift();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+    key: Key,
+    modifiers: ModifiersState,
+}
+
+impl Shortcut {
+    /// Create a new shortcut for the given logical key without modifiers.
+    #[inline(always)]
+    pub fn new(key: impl Into<Key>) -> Self {
+        let key = match key.into() {
+            Key::Character(c) => Key::Character(c.to_lowercase().into()),
+            key => key,
+        };
+
+        Self {
+            key,
+            modifiers: ModifiersState::empty(),
+        }
+    }
+
+    /// Create a new shortcut for the given character key without modifiers.
+    #[inline(always)]
+    pub fn char(c: char) -> Self {
+        Self::new(Key::Character(c.to_string().into()))
+    }
+
+    /// Add the platform's primary modifier to the shortcut and return self.
+    ///
+    /// This is `Cmd` on macOS and `Ctrl` on other platforms.
+    #[inline(always)]
+    pub fn with_primary(self) -> Self {
+        if cfg!(target_os = "macos") {
+            self.with_super()
+        } else {
+            self.with_control()
+        }
+    }
+
+    /// Add the `Ctrl` modifier to the shortcut and return self.
+    #[inline(always)]
+    pub fn with_control(mut self) -> Self {
+        self.modifiers |= ModifiersState::CONTROL;
+        self
+    }
+
+    /// Add the `Shift` modifier to the shortcut and return self.
+    #[inline(always)]
+    pub fn with_shift(mut self) -> Self {
+        self.modifiers |= ModifiersState::SHIFT;
+        self
+    }
+
+    /// Add the `Alt` (`Option` on macOS) modifier to the shortcut and return self.
+    #[inline(always)]
+    pub fn with_alt(mut self) -> Self {
+        self.modifiers |= ModifiersState::ALT;
+        self
+    }
+
+    /// Add the `Super` (`Cmd` on macOS, `Windows` on Windows) modifier to the shortcut and return self.
+    #[inline(always)]
+    pub fn with_super(mut self) -> Self {
+        self.modifiers |= ModifiersState::SUPER;
+        self
+    }
+
+    /// Get the logical key of the shortcut.
+    #[inline(always)]
+    pub const fn key(&self) -> &Key {
+        &self.key
+    }
+
+    /// Get the modifiers of the shortcut.
+    #[inline(always)]
+    pub const fn modifiers(&self) -> ModifiersState {
+        self.modifiers
+    }
+
+    /// Returns if the given key input with the given modifiers matches the shortcut.
+    ///
+    /// Both pressing and releasing the key match.
+    #[inline(always)]
+    pub fn matches(&self, key: &KeyInput, modifiers: ModifiersState) -> bool {
+        modifiers == self.modifiers
+            && match (&key.logical_key, &self.key) {
+                (Key::Character(a), Key::Character(b)) => a.to_lowercase() == b.as_str(),
+                (a, b) => a == b,
+            }
+    }
+}
+
+/// A handle to a registered shortcut, returned by [ShortcutManager::register].
+///
+/// The handle is only valid for the current update pass and should be re-acquired on every update.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ShortcutHandle(usize);
+
+impl ShortcutHandle {
+    /// Get the index of the shortcut in registration order (tree order).
+    #[inline(always)]
+    pub const fn index(&self) -> usize {
+        self.0
+    }
+}
+
+/// Registers keyboard shortcuts and detects when they are pressed.
+///
+/// Widgets call [ShortcutManager::register] or [ShortcutManager::register_scoped] on every update
+/// and check [ShortcutManager::is_triggered] to run the action of a shortcut.
+/// Global shortcuts are always active, while scoped shortcuts are only active,
+/// if a widget inside the scope is focused. Scopes are created using [FocusManager::scope](crate::app::focus::FocusManager::scope).
+///
+/// If multiple active shortcuts match a key, the one with the innermost scope is triggered.
+/// Shortcuts with the same key chord and scope conflict with each other.
+/// Conflicts are logged and available using [ShortcutManager::conflicts], while the first registered shortcut is triggered.
+///
+/// Key input matching an active shortcut is not returned by [FocusManager::keys](crate::app::focus::FocusManager::keys),
+/// so only unhandled keys fall through to the focused widget.
+///
+/// Triggering uses the shortcuts registered during the previous update pass.
+/// Triggered shortcuts are identified by their key chord and scope,
+/// so registering the shortcuts in a different order does not trigger the wrong shortcut.
+///
+/// The manager belongs to a single window and can be cheaply cloned.
+#[derive(Clone, Default)]
+pub struct ShortcutManager {
+    inner: Rc<RefCell<ShortcutState>>,
+}
+
+#[derive(Debug, Default)]
+struct ShortcutState {
+    registered: Vec<Registration>,
+    previous: Vec<Registration>,
+    triggered: Vec<Registration>,
+    conflicts: Vec<Shortcut>,
+}
+
+/// A shortcut registered for an update pass.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Registration {
+    shortcut: Shortcut,
+    scope: Option<Range<usize>>,
+}
+
+impl Registration {
+    /// Returns if the shortcut is active with the given focused widget.
+    #[inline(always)]
+    fn is_active(&self, focused: Option<usize>) -> bool {
+        match &self.scope {
+            Some(scope) => focused.is_some_and(|focused| scope.contains(&focused)),
+            None => true,
+        }
+    }
+
+    /// Get the number of focusable widgets inside the scope. Global shortcuts have the largest scope.
+    #[inline(always)]
+    fn scope_len(&self) -> usize {
+        self.scope.as_ref().map_or(usize::MAX, |scope| scope.len())
+    }
+}
+
+impl ShortcutManager {
+    /// Create a new shortcut manager with no registered shortcuts.
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a global shortcut for the current update pass.
+    ///
+    /// This must be called on every update, before checking if the shortcut is triggered.
+    #[inline(always)]
+    pub fn register(&self, shortcut: Shortcut) -> ShortcutHandle {
+        self.insert(shortcut, None)
+    }
+
+    /// Register a shortcut for the current update pass, which is only active
+    /// if the focused widget is inside the given scope of focus indices.
+    ///
+    /// This must be called on every update, before checking if the shortcut is triggered.
+    #[inline(always)]
+    pub fn register_scoped(&self, shortcut: Shortcut, scope: Range<usize>) -> ShortcutHandle {
+        self.insert(shortcut, Some(scope))
+    }
+
+    /// Insert a new registration for the current update pass.
+    fn insert(&self, shortcut: Shortcut, scope: Option<Range<usize>>) -> ShortcutHandle {
+        let mut inner = self.inner.borrow_mut();
+
+        inner.registered.push(Registration { shortcut, scope });
+
+        ShortcutHandle(inner.registered.len() - 1)
+    }
+
+    /// Returns if the given shortcut was pressed in the current update pass.
+    #[inline(always)]
+    pub fn is_triggered(&self, handle: ShortcutHandle) -> bool {
+        let inner = self.inner.borrow();
+
+        let Some(reg) = inner.registered.get(handle.0) else {
+            return false;
+        };
+
+        // only the first of conflicting shortcuts is triggered
+        inner.triggered.contains(reg) && !inner.registered[..handle.0].contains(reg)
+    }
+
+    /// Get the key chords of the conflicting shortcuts registered during the last update pass.
+    #[inline(always)]
+    pub fn conflicts(&self) -> Vec<Shortcut> {
+        self.inner.borrow().conflicts.clone()
+    }
+
+    /// Prepare a new update pass and trigger the shortcuts matching the key input of the given [AppInfo],
+    /// using the shortcuts registered during the previous pass.
+    ///
+    /// Returns the indices of the key inputs in [AppInfo::keys] handled by a shortcut.
+    #[tracing::instrument(level = "trace", skip_all)]
+    pub(crate) fn begin(&self, info: &AppInfo, focused: Option<usize>) -> Vec<usize> {
+        let mut inner = self.inner.borrow_mut();
+
+        inner.previous = std::mem::take(&mut inner.registered);
+        inner.triggered.clear();
+
+        let mut handled = Vec::new();
+        let mut triggered = Vec::new();
+
+        for (index, (_, key)) in info.keys.iter().enumerate() {
+            // the first registered shortcut with the innermost scope wins
+            let best = inner
+                .previous
+                .iter()
+                .enumerate()
+                .filter(|(_, reg)| {
+                    reg.is_active(focused) && reg.shortcut.matches(key, info.modifiers)
+                })
+                .min_by_key(|(_, reg)| reg.scope_len())
+                .map(|(i, _)| i);
+
+            if let Some(best) = best {
+                handled.push(index);
+
+                if key.is_pressed() && !key.repeat {
+                    triggered.push(inner.previous[best].clone());
+                }
+            }
+        }
+
+        inner.triggered = triggered;
+
+        handled
+    }
+
+    /// Finish the update pass and detect conflicting shortcuts.
+    #[tracing::instrument(level = "trace", skip_all)]
+    pub(crate) fn end(&self) {
+        let mut inner = self.inner.borrow_mut();
+
+        let mut conflicts = Vec::new();
+
+        for (i, reg) in inner.registered.iter().enumerate() {
+            let conflict = inner.registered[..i]
+                .iter()
+                .any(|other| other.shortcut == reg.shortcut && other.scope == reg.scope);
+
+            if conflict && !conflicts.contains(&reg.shortcut) {
+                conflicts.push(reg.shortcut.clone());
+            }
+        }
+
+        // only log new conflicts, since shortcuts are registered on every pass
+        for shortcut in &conflicts {
+            if !inner.conflicts.contains(shortcut) {
+                tracing::warn!("conflicting shortcuts registered for {shortcut:?}");
+            }
+        }
+
+        inner.conflicts = conflicts;
+    }
+}
+
+impl Debug for ShortcutManager {
+    #[inline(always)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let inner = self.inner.borrow();
+
+        f.debug_struct("ShortcutManager")
+            .field("registered", &inner.previous.len())
+            .field("triggered", &inner.triggered)
+            .field("conflicts", &inner.conflicts)
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use crate::app::context::AppContext;
+    use crate::app::info::AppInfo;
+    use crate::app::shortcut::Shortcut;
+    use crate::app::update::Update;
+    use crate::layout::{FlexDirection, LayoutNode, LayoutStyle, StyleNode};
+    use crate::testing::Harness;
+    use crate::vgi::Scene;
+    use crate::widget::Widget;
+    use maycoon_theme::id::WidgetId;
+    use maycoon_theme::theme::Theme;
+    use maycoon_theme::theme::dummy::DummyTheme;
+    use nalgebra::Vector2;
+    use winit::keyboard::{Key, ModifiersState, NamedKey};
+
+    /// A group of focusable widgets counting the keys they receive,
+    /// which counts how often its shortcut is triggered.
+    #[derive(Default)]
+    struct Group {
+        shortcut: Option<Shortcut>,
+        scoped: bool,
+        focusable: usize,
+        keys: usize,
+        triggered: usize,
+        children: Vec<Group>,
+    }
+
+    impl Group {
+        fn new(shortcut: Option<Shortcut>, scoped: bool, focusable: usize) -> Self {
+            Self {
+                shortcut,
+                scoped,
+                focusable,
+                ..Default::default()
+            }
+        }
+
+        fn with_children(mut self, children: Vec<Group>) -> Self {
+            self.children = children;
+            self
+        }
+    }
+
+    impl Widget for Group {
+        fn render(
+            &mut self,
+            _: &mut dyn Scene,
+            _: &mut dyn Theme,
+            _: &LayoutNode,
+            _: &AppInfo,
+            _: AppContext,
+        ) {
+        }
+
+        fn layout_style(&self) -> StyleNode {
+            StyleNode {
+                style: LayoutStyle {
+                    flex_direction: FlexDirection::Row,
+                    ..Default::default()
+                },
+                children: self.children.iter().map(|c| c.layout_style()).collect(),
+                measure: None,
+            }
+        }
+
+        fn update(&mut self, layout: &LayoutNode, context: AppContext, info: &AppInfo) -> Update {
+            let (_, scope) = context.focus().scope(|| {
+                for _ in 0..self.focusable {
+                    let focus = context.focus().register();
+
+                    self.keys += context
+                        .focus()
+                        .keys(focus, info)
+                        .filter(|k| k.is_pressed())
+                        .count();
+                }
+
+                for (child, layout) in self.children.iter_mut().zip(&layout.children) {
+                    child.update(layout, context.clone(), info);
+                }
+            });
+
+            if let Some(shortcut) = &self.shortcut {
+                let handle = if self.scoped {
+                    context.shortcuts().register_scoped(shortcut.clone(), scope)
+                } else {
+                    context.shortcuts().register(shortcut.clone())
+                };
+
+                if context.shortcuts().is_triggered(handle) {
+                    self.triggered += 1;
+                }
+            }
+
+            Update::empty()
+        }
+
+        fn widget_id(&self) -> WidgetId {
+            WidgetId::new("maycoon-core", "Group")
+        }
+    }
+
+    /// Tests that shortcuts are triggered by their key chord and unhandled keys fall through to the focused widget.
+    #[test]
+    fn test_shortcut_trigger() {
+        let mut harness = Harness::new(
+            Group::new(Some(Shortcut::char('s').with_primary()), false, 1),
+            DummyTheme::default(),
+            Vector2::new(100.0, 100.0),
+        );
+
+        harness.frame();
+
+        harness.press_key(Key::Named(NamedKey::Tab));
+        harness.frame();
+
+        // the modifiers don't match
+        harness.press_key(Key::Character("s".into()));
+        harness.frame();
+
+        assert_eq!(harness.widget().triggered, 0);
+        assert_eq!(harness.widget().keys, 1);
+
+        harness.set_modifiers(Shortcut::char('s').with_primary().modifiers());
+        harness.press_key(Key::Character("S".into()));
+        harness.frame();
+
+        assert_eq!(harness.widget().triggered, 1);
+        assert_eq!(harness.widget().keys, 1);
+
+        harness.press_key(Key::Character("a".into()));
+        harness.frame();
+
+        assert_eq!(harness.widget().triggered, 1);
+        assert_eq!(harness.widget().keys, 2);
+    }
+
+    /// Tests that scoped shortcuts are only triggered while their subtree is focused
+    /// and take precedence over global shortcuts.
+    #[test]
+    fn test_shortcut_scope() {
+        let shortcut = Shortcut::new(Key::Named(NamedKey::F2)).with_shift();
+        let root = Group::new(Some(shortcut.clone()), false, 0).with_children(vec![
+            Group::new(Some(shortcut.clone()), true, 1),
+            Group::new(Some(shortcut.clone()), true, 2),
+        ]);
+
+        let mut harness = Harness::new(root, DummyTheme::default(), Vector2::new(100.0, 100.0));
+
+        harness.frame();
+
+        harness.set_modifiers(ModifiersState::SHIFT);
+        harness.press_key(Key::Named(NamedKey::F2));
+        harness.frame();
+
+        assert_eq!(harness.widget().triggered, 1);
+
+        // focus the second widget, inside the second group
+        harness.set_modifiers(ModifiersState::empty());
+        harness.press_key(Key::Named(NamedKey::Tab));
+        harness.press_key(Key::Named(NamedKey::Tab));
+        harness.frame();
+
+        harness.set_modifiers(ModifiersState::SHIFT);
+        harness.press_key(Key::Named(NamedKey::F2));
+        harness.frame();
+
+        let root = harness.widget();
+
+        assert_eq!(root.triggered, 1);
+        assert_eq!(root.children[0].triggered, 0);
+        assert_eq!(root.children[1].triggered, 1);
+        assert!(harness.context().shortcuts().conflicts().is_empty());
+    }
+
+    /// Tests that shortcuts with the same key chord and scope are reported as conflicts.
+    #[test]
+    fn test_shortcut_conflict() {
+        let shortcut = Shortcut::char('q').with_control();
+        let root = Group::new(None, false, 0).with_children(vec![
+            Group::new(Some(shortcut.clone()), false, 0),
+            Group::new(Some(shortcut.clone()), false, 0),
+        ]);
+
+        let mut harness = Harness::new(root, DummyTheme::default(), Vector2::new(100.0, 100.0));
+
+        harness.frame();
+
+        assert_eq!(harness.context().shortcuts().conflicts(), vec![shortcut]);
+
+        harness.set_modifiers(ModifiersState::CONTROL);
+        harness.press_key(Key::Character("q".into()));
+        harness.frame();
+
+        // the first registered shortcut wins
+        assert_eq!(harness.widget().children[0].triggered, 1);
+        assert_eq!(harness.widget().children[1].triggered, 0);
+    }
+
+    /// Tests that the pressed shortcut is triggered, even if the shortcuts are registered in a different order.
+    #[test]
+    fn test_shortcut_reorder() {
+        let root = Group::new(None, false, 0).with_children(vec![
+            Group::new(Some(Shortcut::char('a').with_control()), false, 0),
+            Group::new(Some(Shortcut::char('b').with_control()), false, 0),
+        ]);
+
+        let mut harness = Harness::new(root, DummyTheme::default(), Vector2::new(100.0, 100.0));
+
+        harness.frame();
+
+        harness.widget_mut().children.swap(0, 1);
+        harness.set_modifiers(ModifiersState::CONTROL);
+        harness.press_key(Key::Character("b".into()));
+        harness.frame();
+
+        let root = harness.widget();
+
+        assert_eq!(
+            root.children[0].shortcut,
+            Some(Shortcut::char('b').with_control())
+        );
+        assert_eq!(root.children[0].triggered, 1);
+        assert_eq!(root.children[1].triggered, 0);
+    }
+}
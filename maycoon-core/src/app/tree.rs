@@ -0,0 +1,578 @@
+use nalgebra::Vector2;
+use taffy::{
+    AvailableSpace, Dimension, NodeId, Point, PrintTree, Size, Style, TaffyResult, TaffyTree,
+    TraversePartialTree,
+};
+
+use crate::app::clipboard::Clipboard;
+use crate::app::context::AppContext;
+use crate::app::drag::DragManager;
+use crate::app::focus::FocusManager;
+use crate::app::info::AppInfo;
+use crate::app::pointer::PointerManager;
+use crate::app::shortcut::ShortcutManager;
+use crate::app::update::{Update, UpdateManager};
+use crate::app::window::{WindowKey, WindowManager};
+use crate::layout::{LayoutNode, MeasureFn, StyleNode};
+use crate::vgi::Scene;
+use crate::widget::Widget;
+use maycoon_theme::theme::Theme;
+use std::rc::Rc;
+
+/// A widget tree together with its layout tree and scene.
+///
+/// This contains the window-independent part of the application pipeline
+/// (layout, update and drawing), so it can be driven by the [AppHandler](crate::app::handler::AppHandler)
+/// as well as by the headless [Harness](crate::testing::Harness).
+pub struct WidgetTree<W: Widget, S: Scene> {
+    /// The layout tree. Nodes with a [MeasureFn] store it as context.
+    pub taffy: TaffyTree<MeasureFn>,
+    /// The root node of the layout tree, sized to the window.
+    pub root: NodeId,
+    /// The scene to draw the widgets on.
+    pub scene: S,
+    widget: Option<W>,
+    layout: Option<LayoutNode>,
+    windows: WindowManager,
+    window: WindowKey,
+    focus: FocusManager,
+    pointer: PointerManager,
+    drag: DragManager,
+    shortcuts: ShortcutManager,
+    clipboard: Clipboard,
+}
+
+impl<W: Widget, S: Scene> WidgetTree<W, S> {
+    /// Create a new widget tree with the given scene and no root widget.
+    ///
+    /// The tree belongs to the [WindowKey::MAIN] window and has its own [WindowManager].
+    #[inline(always)]
+    pub fn new(scene: S) -> Self {
+        Self::with_window(scene, WindowManager::new(), WindowKey::MAIN)
+    }
+
+    /// Create a new widget tree for the given window with the given scene and no root widget.
+    ///
+    /// The [WindowManager] and [WindowKey] are passed to the widgets via the [AppContext].
+    #[inline(always)]
+    #[tracing::instrument(level = "trace", skip_all)]
+    pub fn with_window(scene: S, windows: WindowManager, window: WindowKey) -> Self {
+        tracing::trace!("creating taffy tree");
+        let mut taffy = TaffyTree::with_capacity(16);
+
+        // gets configured on resize
+        let root = taffy
+            .new_leaf(Style::default())
+            .expect("Failed to create root node");
+
+        Self {
+            taffy,
+            root,
+            scene,
+            widget: None,
+            layout: None,
+            windows,
+            window,
+            focus: FocusManager::new(),
+            pointer: PointerManager::new(),
+            drag: DragManager::new(),
+            shortcuts: ShortcutManager::new(),
+            clipboard: Clipboard::default(),
+        }
+    }
+
+    /// Set the [Clipboard] passed to the widgets via the [AppContext] and return self.
+    ///
+    /// By default, the tree has its own in-memory clipboard.
+    #[inline(always)]
+    pub fn with_clipboard(mut self, clipboard: Clipboard) -> Self {
+        self.clipboard = clipboard;
+        self
+    }
+
+    /// Get the key of the window this tree belongs to.
+    #[inline(always)]
+    pub const fn window(&self) -> WindowKey {
+        self.window
+    }
+
+    /// Get the [FocusManager] of this tree.
+    #[inline(always)]
+    pub fn focus(&self) -> FocusManager {
+        self.focus.clone()
+    }
+
+    /// Get the [PointerManager] of this tree.
+    #[inline(always)]
+    pub fn pointer(&self) -> PointerManager {
+        self.pointer.clone()
+    }
+
+    /// Get the [DragManager] of this tree.
+    #[inline(always)]
+    pub fn drag(&self) -> DragManager {
+        self.drag.clone()
+    }
+
+    /// Get the [ShortcutManager] of this tree.
+    #[inline(always)]
+    pub fn shortcuts(&self) -> ShortcutManager {
+        self.shortcuts.clone()
+    }
+
+    /// Create the [AppContext] for the widgets of this tree.
+    #[inline(always)]
+    pub fn context(&self, info: &AppInfo, update: &UpdateManager) -> AppContext {
+        AppContext::new(update.clone(), info.diagnostics)
+            .with_window(self.windows.clone(), self.window)
+            .with_focus(self.focus.clone())
+            .with_pointer(self.pointer.clone())
+            .with_drag(self.drag.clone())
+            .with_shortcuts(self.shortcuts.clone())
+            .with_clipboard(self.clipboard.clone())
+    }
+
+    /// Set the root widget of this tree.
+    ///
+    /// This clears the layout tree, so the next update will completely re-layout the widgets.
+    #[inline(always)]
+    pub fn set_widget(&mut self, widget: W) {
+        if let Ok(node) = self.taffy.child_at_index(self.root, 0) {
+            self.taffy
+                .remove_child(self.root, node)
+                .expect("Failed to remove root widget node");
+
+            self.remove_node(node).expect("Failed to clear layout tree");
+        }
+
+        self.widget = Some(widget);
+        self.layout = None;
+    }
+
+    /// Get a reference to the root widget. Returns [None] if the widget is not built yet.
+    #[inline(always)]
+    pub const fn widget(&self) -> Option<&W> {
+        self.widget.as_ref()
+    }
+
+    /// Get a mutable reference to the root widget. Returns [None] if the widget is not built yet.
+    #[inline(always)]
+    pub const fn widget_mut(&mut self) -> Option<&mut W> {
+        self.widget.as_mut()
+    }
+
+    /// Get the computed layout of the root widget. Returns [None] if the tree was not updated yet.
+    #[inline(always)]
+    pub const fn layout(&self) -> Option<&LayoutNode> {
+        self.layout.as_ref()
+    }
+
+    /// Resize the root layout node to the given size.
+    #[inline(always)]
+    #[tracing::instrument(level = "trace", skip(self))]
+    pub fn resize(&mut self, size: Vector2<f64>) {
+        tracing::trace!("resizing root layout node");
+        self.taffy
+            .set_style(
+                self.root,
+                Style {
+                    size: Size::<Dimension> {
+                        width: Dimension::length(size.x as f32),
+                        height: Dimension::length(size.y as f32),
+                    },
+                    ..Default::default()
+                },
+            )
+            .expect("Failed to set root node style");
+    }
+
+    /// Create a new layout node with the given style and its children.
+    #[tracing::instrument(level = "trace", skip_all)]
+    fn new_node(&mut self, style: &StyleNode) -> TaffyResult<NodeId> {
+        let node = if let Some(measure) = &style.measure {
+            self.taffy
+                .new_leaf_with_context(style.style.clone().into(), measure.clone())?
+        } else {
+            self.taffy.new_leaf(style.style.clone().into())?
+        };
+
+        for child in &style.children {
+            let child = self.new_node(child)?;
+
+            self.taffy.add_child(node, child)?;
+        }
+
+        Ok(node)
+    }
+
+    /// Remove the given node and its children from the layout tree.
+    #[tracing::instrument(level = "trace", skip(self))]
+    fn remove_node(&mut self, node: NodeId) -> TaffyResult<()> {
+        for child in self.taffy.children(node)? {
+            self.remove_node(child)?;
+        }
+
+        self.taffy.remove(node)?;
+        Ok(())
+    }
+
+    /// Update the given node and its children to match the given style.
+    ///
+    /// Only nodes whose style or [MeasureFn] changed are marked dirty,
+    /// so taffy can re-use the cached layout of the other nodes.
+    #[tracing::instrument(level = "trace", skip(self, style))]
+    fn sync_node(&mut self, node: NodeId, style: &StyleNode) -> TaffyResult<()> {
+        let layout_style = style.style.clone().into();
+
+        if self.taffy.style(node)? != &layout_style {
+            tracing::trace!("style of node {node:?} changed");
+            self.taffy.set_style(node, layout_style)?;
+        }
+
+        // measure functions are compared by pointer
+        let measure_changed = match (&style.measure, self.taffy.get_node_context(node)) {
+            (Some(measure), Some(old)) => !Rc::ptr_eq(measure, old),
+            (None, None) => false,
+            _ => true,
+        };
+
+        if measure_changed {
+            tracing::trace!("measure function of node {node:?} changed");
+            self.taffy.set_node_context(node, style.measure.clone())?;
+        }
+
+        let count = self.taffy.child_count(node);
+
+        for (i, child_style) in style.children.iter().enumerate() {
+            if i < count {
+                let child = self.taffy.child_at_index(node, i)?;
+
+                self.sync_node(child, child_style)?;
+            } else {
+                let child = self.new_node(child_style)?;
+
+                self.taffy.add_child(node, child)?;
+            }
+        }
+
+        // remove children that do not exist anymore
+        for i in (style.children.len()..count).rev() {
+            let child = self.taffy.remove_child_at_index(node, i)?;
+
+            self.remove_node(child)?;
+        }
+
+        Ok(())
+    }
+
+    /// Compute the layout of the root node and its children.
+    #[inline(always)]
+    #[tracing::instrument(level = "trace", skip(self))]
+    fn compute_layout(&mut self, size: Vector2<f64>) -> TaffyResult<()> {
+        self.taffy.compute_layout_with_measure(
+            self.root,
+            Size::<AvailableSpace> {
+                width: AvailableSpace::Definite(size.x as f32),
+                height: AvailableSpace::Definite(size.y as f32),
+            },
+            |known, available, _, measure, _| {
+                let Some(measure) = measure else {
+                    return Size::ZERO;
+                };
+
+                let size = measure(
+                    Vector2::new(known.width, known.height),
+                    Vector2::new(available.width, available.height),
+                );
+
+                Size {
+                    width: size.x,
+                    height: size.y,
+                }
+            },
+        )?;
+        Ok(())
+    }
+
+    /// Collect the computed layout of the given node and its children. Make sure to call [WidgetTree::compute_layout] before, to not get dirty results.
+    ///
+    /// The location of the node is offset by the given location of its parent, so the collected locations are absolute.
+    #[inline(always)]
+    #[tracing::instrument(level = "trace", skip(self, style))]
+    fn collect_layout(
+        &mut self,
+        node: NodeId,
+        style: &StyleNode,
+        parent: Point<f32>,
+    ) -> TaffyResult<LayoutNode> {
+        tracing::trace!("collecting node layout {node:?}");
+
+        let mut layout = self.taffy.get_final_layout(node);
+
+        layout.location.x += parent.x;
+        layout.location.y += parent.y;
+
+        let mut children = Vec::with_capacity(style.children.capacity());
+
+        for (i, style) in style.children.iter().enumerate() {
+            let child = self.taffy.child_at_index(node, i)?;
+
+            tracing::trace!("collecting layout of child {child:?}");
+
+            children.push(self.collect_layout(child, style, layout.location)?);
+        }
+
+        Ok(LayoutNode { layout, children })
+    }
+
+    /// Update the layout tree to match the widget and re-compute the layout.
+    ///
+    /// The layout nodes persist between calls, so only changed nodes need to be re-computed.
+    #[inline(always)]
+    #[tracing::instrument(level = "trace", skip_all)]
+    fn relayout(&mut self, size: Vector2<f64>) {
+        let style = self.widget().expect("Widget not built yet").layout_style();
+
+        if let Ok(node) = self.taffy.child_at_index(self.root, 0) {
+            self.sync_node(node, &style)
+                .expect("Failed to update layout tree");
+        } else {
+            let node = self.new_node(&style).expect("Failed to layout window");
+
+            self.taffy
+                .add_child(self.root, node)
+                .expect("Failed to add root widget node");
+        }
+
+        self.compute_layout(size).expect("Failed to compute layout");
+
+        let layout = self
+            .collect_layout(
+                self.taffy.child_at_index(self.root, 0).unwrap(),
+                &style,
+                Point::ZERO,
+            )
+            .expect("Failed to collect layout");
+
+        // the hit regions were registered with the old layout
+        if self.layout.as_ref().is_some_and(|old| *old != layout) {
+            self.pointer.invalidate();
+        }
+
+        self.layout = Some(layout);
+    }
+
+    /// Update the root widget and re-layout the tree if requested.
+    ///
+    /// If the layout changed, the hit regions registered with the [PointerManager] are outdated.
+    /// The pointer events of the next update are then held back for another update,
+    /// so the widgets register their hit regions with the new layout before the events are routed.
+    ///
+    /// The computed layout is available via [WidgetTree::layout] afterward.
+    #[tracing::instrument(level = "trace", skip_all)]
+    pub fn update(&mut self, info: &AppInfo, update: &UpdateManager) {
+        // completely layout widgets if taffy is not set up yet (e.g. during first update)
+        if self.layout.is_none() {
+            tracing::trace_span!("complete layout").in_scope(|| {
+                self.relayout(info.size);
+
+                update.insert(Update::FORCE);
+            });
+        }
+
+        self.update_widget(info, update);
+
+        // check if app should re-evaluate layout
+        if update.is_set(Update::LAYOUT | Update::FORCE) {
+            tracing::trace_span!("layout").in_scope(|| self.relayout(info.size));
+        }
+    }
+
+    /// Run a single update pass of the root widget with the given [AppInfo].
+    #[tracing::instrument(level = "trace", skip_all)]
+    fn update_widget(&mut self, info: &AppInfo, update: &UpdateManager) {
+        // update call to check if app should re-evaluate
+        let context = self.context(info, update);
+
+        self.focus.begin(info);
+        self.focus
+            .set_handled(self.shortcuts.begin(info, self.focus.focused()));
+        self.pointer.begin(info);
+        self.drag.begin(info);
+
+        tracing::trace!("updating widget");
+        let widget_update = self.widget.as_mut().expect("Widget not built yet").update(
+            self.layout.as_ref().unwrap(),
+            context,
+            info,
+        );
+
+        update.insert(widget_update);
+
+        self.shortcuts.end();
+
+        // redraw focus indicators if the focus changed
+        if self.focus.end() {
+            update.insert(Update::DRAW);
+        }
+
+        // redraw the drag preview at the new cursor position
+        if self.drag.end() {
+            update.insert(Update::DRAW);
+        }
+
+        // deliver held back pointer events in another update pass
+        if self.pointer.end() {
+            update.insert(Update::EVAL);
+        }
+    }
+
+    /// Reset the scene and draw the root widget onto it.
+    #[tracing::instrument(level = "trace", skip_all)]
+    pub fn draw(&mut self, theme: &mut dyn Theme, info: &AppInfo, update: &UpdateManager) {
+        // clear scene
+        tracing::trace!("resetting vector graphics interface scene");
+        self.scene.reset();
+
+        let context = self.context(info, update);
+
+        tracing::trace!("drawing root widget");
+        self.widget.as_mut().expect("Widget not built yet").render(
+            &mut self.scene,
+            theme,
+            self.layout.as_ref().expect("Layout not computed yet"),
+            info,
+            context,
+        );
+
+        tracing::trace!("drawing drag preview");
+        self.drag.draw(&mut self.scene, info);
+    }
+}
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use crate::app::context::AppContext;
+    use crate::app::info::AppInfo;
+    use crate::app::tree::WidgetTree;
+    use crate::app::update::{Update, UpdateManager};
+    use crate::layout::{AlignSelf, Dimension, LayoutNode, LayoutStyle, StyleNode};
+    use crate::testing::Harness;
+    use crate::vgi::Scene;
+    use crate::vgi::dummy::DummyScene;
+    use crate::widget::Widget;
+    use maycoon_theme::id::WidgetId;
+    use maycoon_theme::theme::Theme;
+    use maycoon_theme::theme::dummy::DummyTheme;
+    use nalgebra::Vector2;
+    use std::rc::Rc;
+
+    /// A widget with a constant area, like wrapped text.
+    struct Measured {
+        width: Dimension,
+    }
+
+    impl Widget for Measured {
+        fn render(
+            &mut self,
+            _: &mut dyn Scene,
+            _: &mut dyn Theme,
+            _: &LayoutNode,
+            _: &AppInfo,
+            _: AppContext,
+        ) {
+        }
+
+        fn layout_style(&self) -> StyleNode {
+            StyleNode {
+                style: LayoutStyle {
+                    size: Vector2::new(self.width, Dimension::auto()),
+                    align_self: Some(AlignSelf::Start),
+                    ..Default::default()
+                },
+                children: Vec::new(),
+                measure: Some(Rc::new(|known, _| {
+                    let width = known.x.unwrap_or(120.0);
+
+                    Vector2::new(width, known.y.unwrap_or(6000.0 / width))
+                })),
+            }
+        }
+
+        fn update(&mut self, _: &LayoutNode, _: AppContext, _: &AppInfo) -> Update {
+            Update::empty()
+        }
+
+        fn widget_id(&self) -> WidgetId {
+            WidgetId::new("maycoon-core", "Measured")
+        }
+    }
+
+    /// Tests that measure functions determine the size of leaf nodes.
+    #[test]
+    fn test_measure() {
+        let mut harness = Harness::new(
+            Measured {
+                width: Dimension::auto(),
+            },
+            DummyTheme::default(),
+            Vector2::new(800.0, 600.0),
+        );
+
+        harness.frame();
+
+        let layout = &harness.layout().unwrap().layout;
+
+        assert_eq!(layout.size.width, 120.0);
+        assert_eq!(layout.size.height, 50.0);
+
+        // the measured height depends on the known width
+        let mut harness = Harness::new(
+            Measured {
+                width: Dimension::length(60.0),
+            },
+            DummyTheme::default(),
+            Vector2::new(800.0, 600.0),
+        );
+
+        harness.frame();
+
+        let layout = &harness.layout().unwrap().layout;
+
+        assert_eq!(layout.size.width, 60.0);
+        assert_eq!(layout.size.height, 100.0);
+    }
+
+    /// Tests that the layout nodes persist and are updated between layouts.
+    #[test]
+    fn test_incremental_layout() {
+        let mut tree = WidgetTree::new(DummyScene);
+        let info = AppInfo {
+            size: Vector2::new(800.0, 600.0),
+            ..Default::default()
+        };
+        let update = UpdateManager::new();
+
+        tree.resize(info.size);
+        tree.set_widget(Measured {
+            width: Dimension::auto(),
+        });
+        tree.update(&info, &update);
+        update.clear();
+
+        let node = tree.taffy.child_at_index(tree.root, 0).unwrap();
+        let count = tree.taffy.total_node_count();
+
+        tree.widget_mut().unwrap().width = Dimension::length(60.0);
+        update.insert(Update::LAYOUT);
+        tree.update(&info, &update);
+
+        assert_eq!(tree.taffy.child_at_index(tree.root, 0).unwrap(), node);
+        assert_eq!(tree.taffy.total_node_count(), count);
+
+        let layout = &tree.layout().unwrap().layout;
+
+        assert_eq!(layout.size.width, 60.0);
+        assert_eq!(layout.size.height, 100.0);
+    }
+}
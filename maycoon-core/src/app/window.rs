@@ -0,0 +1,425 @@
+use std::cell::RefCell;
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+
+use nalgebra::{Point2, Vector2};
+
+use crate::app::context::AppContext;
+use crate::app::update::{Update, UpdateManager};
+use crate::config::{Cursor, ResizeDirection, WindowConfig, WindowLevel, WindowMode};
+use crate::widget::BoxedWidget;
+
+/// A unique identifier of an application window.
+///
+/// Unlike [winit::window::WindowId], the key is known before the window is actually created.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct WindowKey(u64);
+
+impl WindowKey {
+    /// The key of the main application window.
+    pub const MAIN: WindowKey = WindowKey(0);
+
+    /// Returns if this is the key of the main application window.
+    #[inline(always)]
+    pub const fn is_main(&self) -> bool {
+        self.0 == Self::MAIN.0
+    }
+}
+
+/// A builder function for the root widget of a window.
+pub type WindowBuilder = Box<dyn FnOnce(AppContext) -> BoxedWidget>;
+
+/// A request to the application handler to manage windows.
+pub enum WindowRequest {
+    /// Open a new window with the given key, configuration and root widget builder.
+    Open {
+        /// The key of the new window.
+        key: WindowKey,
+        /// The configuration of the new window.
+        config: Box<WindowConfig>,
+        /// The builder of the root widget.
+        builder: WindowBuilder,
+    },
+    /// Close the window with the given key.
+    ///
+    /// Closing the main window exits the application.
+    Close(WindowKey),
+    /// Allow or disallow IME input for the window with the given key.
+    SetImeAllowed(WindowKey, bool),
+    /// Set the area of the text cursor, so the IME candidate box can be placed next to it.
+    SetImeCursorArea {
+        /// The key of the window.
+        key: WindowKey,
+        /// The position of the area in physical window coordinates.
+        position: Vector2<f64>,
+        /// The physical size of the area.
+        size: Vector2<f64>,
+    },
+    /// Apply the given [WindowCommand] to the window with the given key.
+    Command(WindowKey, WindowCommand),
+}
+
+/// A command to change a window after it has been created.
+#[derive(Clone, Debug)]
+pub enum WindowCommand {
+    /// Set the title of the window.
+    SetTitle(String),
+    /// Request to resize the inner area of the window to the given logical size.
+    SetSize(Vector2<f64>),
+    /// Move the window to the given logical position on the screen.
+    SetPosition(Point2<f64>),
+    /// Minimize or restore the window.
+    SetMinimized(bool),
+    /// Maximize or restore the window.
+    SetMaximized(bool),
+    /// Switch the [WindowMode] of the window.
+    SetMode(WindowMode),
+    /// Set the [WindowLevel] of the window.
+    SetLevel(WindowLevel),
+    /// Enable or disable the decorations (borders and title bar) of the window.
+    SetDecorations(bool),
+    /// Set the cursor of the window.
+    SetCursor(Cursor),
+    /// Start moving the window with the cursor, until the left mouse button is released.
+    Drag,
+    /// Start resizing the window in the given direction with the cursor, until the left mouse button is released.
+    DragResize(ResizeDirection),
+}
+
+impl Debug for WindowRequest {
+    #[inline(always)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WindowRequest::Open { key, config, .. } => f
+                .debug_struct("Open")
+                .field("key", key)
+                .field("config", config)
+                .finish_non_exhaustive(),
+            WindowRequest::Close(key) => f.debug_tuple("Close").field(key).finish(),
+            WindowRequest::SetImeAllowed(key, allowed) => f
+                .debug_tuple("SetImeAllowed")
+                .field(key)
+                .field(allowed)
+                .finish(),
+            WindowRequest::SetImeCursorArea {
+                key,
+                position,
+                size,
+            } => f
+                .debug_struct("SetImeCursorArea")
+                .field("key", key)
+                .field("position", position)
+                .field("size", size)
+                .finish(),
+            WindowRequest::Command(key, command) => {
+                f.debug_tuple("Command").field(key).field(command).finish()
+            },
+        }
+    }
+}
+
+/// Queues [WindowRequest]s, which are applied by the [AppHandler](crate::app::handler::AppHandler) on the event loop.
+///
+/// The manager is shared between all windows of an application and can be cheaply cloned.
+/// It also knows the [UpdateManager]s of all windows, so updates can be requested for the whole application.
+#[derive(Clone, Default)]
+pub struct WindowManager {
+    inner: Rc<RefCell<WindowQueue>>,
+}
+
+#[derive(Default)]
+struct WindowQueue {
+    last_key: u64,
+    requests: Vec<WindowRequest>,
+    updates: Vec<(WindowKey, UpdateManager)>,
+}
+
+impl WindowManager {
+    /// Create a new window manager with no pending requests.
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request to open a new window with the given configuration and root widget builder.
+    ///
+    /// Returns the key of the new window.
+    #[inline(always)]
+    pub fn open(&self, config: WindowConfig, builder: WindowBuilder) -> WindowKey {
+        let mut inner = self.inner.borrow_mut();
+
+        inner.last_key += 1;
+
+        let key = WindowKey(inner.last_key);
+
+        inner.requests.push(WindowRequest::Open {
+            key,
+            config: Box::new(config),
+            builder,
+        });
+
+        key
+    }
+
+    /// Request to close the window with the given key.
+    #[inline(always)]
+    pub fn close(&self, key: WindowKey) {
+        self.inner
+            .borrow_mut()
+            .requests
+            .push(WindowRequest::Close(key));
+    }
+
+    /// Request to allow or disallow IME input for the window with the given key.
+    #[inline(always)]
+    pub fn set_ime_allowed(&self, key: WindowKey, allowed: bool) {
+        self.inner
+            .borrow_mut()
+            .requests
+            .push(WindowRequest::SetImeAllowed(key, allowed));
+    }
+
+    /// Request to set the IME cursor area of the window with the given key.
+    #[inline(always)]
+    pub fn set_ime_cursor_area(&self, key: WindowKey, position: Vector2<f64>, size: Vector2<f64>) {
+        self.inner
+            .borrow_mut()
+            .requests
+            .push(WindowRequest::SetImeCursorArea {
+                key,
+                position,
+                size,
+            });
+    }
+
+    /// Request to apply the given [WindowCommand] to the window with the given key.
+    #[inline(always)]
+    pub fn command(&self, key: WindowKey, command: WindowCommand) {
+        self.inner
+            .borrow_mut()
+            .requests
+            .push(WindowRequest::Command(key, command));
+    }
+
+    /// Returns if there are any pending requests.
+    #[inline(always)]
+    pub fn has_requests(&self) -> bool {
+        !self.inner.borrow().requests.is_empty()
+    }
+
+    /// Takes all pending requests out of the queue.
+    #[inline(always)]
+    pub fn take_requests(&self) -> Vec<WindowRequest> {
+        std::mem::take(&mut self.inner.borrow_mut().requests)
+    }
+
+    /// Attach the [UpdateManager] of the window with the given key,
+    /// so the window receives the updates requested via [WindowManager::update_all].
+    #[inline(always)]
+    pub fn attach(&self, key: WindowKey, update: UpdateManager) {
+        let mut inner = self.inner.borrow_mut();
+
+        inner.updates.retain(|(k, _)| *k != key);
+        inner.updates.push((key, update));
+    }
+
+    /// Detach the [UpdateManager] of the window with the given key.
+    #[inline(always)]
+    pub fn detach(&self, key: WindowKey) {
+        self.inner.borrow_mut().updates.retain(|(k, _)| *k != key);
+    }
+
+    /// Request the given update for all attached windows.
+    #[inline(always)]
+    pub fn update_all(&self, update: Update) {
+        for (_, manager) in &self.inner.borrow().updates {
+            manager.insert(update);
+        }
+    }
+}
+
+impl Debug for WindowManager {
+    #[inline(always)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let inner = self.inner.borrow();
+
+        f.debug_struct("WindowManager")
+            .field("last_key", &inner.last_key)
+            .field("requests", &inner.requests)
+            .field("updates", &inner.updates)
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use crate::app::context::AppContext;
+    use crate::app::diagnostics::Diagnostics;
+    use crate::app::info::AppInfo;
+    use crate::app::update::{Update, UpdateManager};
+    use crate::app::window::{WindowCommand, WindowKey, WindowManager, WindowRequest};
+    use crate::config::{WindowConfig, WindowMode};
+    use crate::layout::{LayoutNode, LayoutStyle, StyleNode};
+    use crate::signal::Signal;
+    use crate::vgi::Scene;
+    use crate::widget::Widget;
+    use maycoon_theme::id::WidgetId;
+    use maycoon_theme::theme::Theme;
+
+    /// A widget that does nothing.
+    struct Empty;
+
+    impl Widget for Empty {
+        fn render(
+            &mut self,
+            _: &mut dyn Scene,
+            _: &mut dyn Theme,
+            _: &LayoutNode,
+            _: &AppInfo,
+            _: AppContext,
+        ) {
+        }
+
+        fn layout_style(&self) -> StyleNode {
+            StyleNode {
+                style: LayoutStyle::default(),
+                children: Vec::new(),
+                measure: None,
+            }
+        }
+
+        fn update(&mut self, _: &LayoutNode, _: AppContext, _: &AppInfo) -> Update {
+            Update::empty()
+        }
+
+        fn widget_id(&self) -> WidgetId {
+            WidgetId::new("maycoon-core", "Empty")
+        }
+    }
+
+    /// Tests that window requests are queued in order with unique keys.
+    #[test]
+    fn test_window_manager() {
+        let manager = WindowManager::new();
+        let context = AppContext::new(UpdateManager::new(), Diagnostics::default())
+            .with_window(manager.clone(), WindowKey::MAIN);
+
+        assert!(!manager.has_requests());
+
+        let first = context.open_window(
+            WindowConfig {
+                title: "Inspector".to_string(),
+                ..Default::default()
+            },
+            |ctx| {
+                assert_eq!(ctx.window(), WindowKey(1));
+                Empty
+            },
+        );
+        let second = context.open_window(WindowConfig::default(), |_| Empty);
+
+        context.close_window(first);
+
+        assert_ne!(first, second);
+        assert!(!first.is_main());
+        assert!(context.update().is_set(Update::EVAL));
+
+        let mut requests = manager.take_requests().into_iter();
+
+        assert!(!manager.has_requests());
+
+        match requests.next() {
+            Some(WindowRequest::Open {
+                key,
+                config,
+                builder,
+            }) => {
+                assert_eq!(key, first);
+                assert_eq!(config.title, "Inspector");
+
+                let widget = builder(context.clone().with_window(manager.clone(), key));
+
+                assert_eq!(widget.widget_id(), WidgetId::new("maycoon-core", "Empty"));
+            },
+            other => panic!("expected open request, got {other:?}"),
+        }
+
+        assert!(matches!(requests.next(), Some(WindowRequest::Open { key, .. }) if key == second));
+        assert!(matches!(requests.next(), Some(WindowRequest::Close(key)) if key == first));
+        assert!(requests.next().is_none());
+    }
+
+    /// Tests that hooked signals update all attached windows.
+    #[test]
+    fn test_window_manager_update_all() {
+        let manager = WindowManager::new();
+        let main = UpdateManager::new();
+        let other = UpdateManager::new();
+
+        manager.attach(WindowKey::MAIN, main.clone());
+        manager.attach(WindowKey(1), other.clone());
+
+        let context = AppContext::new(main.clone(), Diagnostics::default())
+            .with_window(manager.clone(), WindowKey::MAIN);
+        let counter = context.use_state(0);
+
+        main.clear();
+        other.clear();
+
+        counter.set(1);
+
+        assert!(main.is_set(Update::EVAL));
+        assert!(other.is_set(Update::EVAL));
+
+        manager.detach(WindowKey(1));
+        main.clear();
+        other.clear();
+
+        counter.set(2);
+
+        assert!(main.is_set(Update::EVAL));
+        assert!(!other.is_set(Update::EVAL));
+    }
+
+    /// Tests that window commands are queued for the window of the context.
+    #[test]
+    fn test_window_commands() {
+        let manager = WindowManager::new();
+        let context = AppContext::new(UpdateManager::new(), Diagnostics::default())
+            .with_window(manager.clone(), WindowKey::MAIN);
+
+        context.set_window_title("Title");
+        context.set_window_mode(WindowMode::Borderless);
+        context.drag_window();
+
+        let inspector = context.open_window(WindowConfig::default(), |_| Empty);
+
+        context
+            .windows()
+            .command(inspector, WindowCommand::SetMaximized(true));
+
+        let mut requests = manager.take_requests().into_iter();
+
+        assert!(matches!(
+            requests.next(),
+            Some(WindowRequest::Command(WindowKey::MAIN, WindowCommand::SetTitle(title))) if title == "Title"
+        ));
+        assert!(matches!(
+            requests.next(),
+            Some(WindowRequest::Command(
+                WindowKey::MAIN,
+                WindowCommand::SetMode(WindowMode::Borderless)
+            ))
+        ));
+        assert!(matches!(
+            requests.next(),
+            Some(WindowRequest::Command(WindowKey::MAIN, WindowCommand::Drag))
+        ));
+        assert!(matches!(requests.next(), Some(WindowRequest::Open { .. })));
+        assert!(matches!(
+            requests.next(),
+            Some(WindowRequest::Command(key, WindowCommand::SetMaximized(true))) if key == inspector
+        ));
+        assert!(requests.next().is_none());
+    }
+}
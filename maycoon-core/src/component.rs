@@ -75,6 +75,7 @@ impl<C: Component> Widget for Composed<C> {
             StyleNode {
                 style: LayoutStyle::default(),
                 children: Vec::new(),
+                measure: None,
             }
         }
     }
@@ -1,8 +1,14 @@
 use nalgebra::{Point2, Vector2};
 pub use winit::window::{
-    BadIcon, Cursor, CursorIcon, CustomCursor, Icon as WindowIcon, WindowButtons, WindowLevel,
+    BadIcon, Cursor, CursorIcon, CustomCursor, Icon as WindowIcon, ResizeDirection, WindowButtons,
+    WindowLevel,
 };
 
+use winit::dpi::{LogicalPosition, LogicalSize, Position, Size};
+use winit::monitor::MonitorHandle;
+use winit::window::{Fullscreen, WindowAttributes};
+
+use crate::app::clipboard::Clipboard;
 use crate::vgi::VectorGraphicsInterface;
 use maycoon_theme::theme::Theme;
 
@@ -15,6 +21,11 @@ pub struct MayConfig<T: Theme, V: VectorGraphicsInterface> {
     pub theme: T,
     /// The configuration of the vector graphics interface.
     pub graphics: V::Config,
+    /// The clipboard of the application.
+    ///
+    /// Uses the clipboard of the operating system by default, if the `system-clipboard` feature is enabled on native platforms.
+    /// Otherwise, the contents are stored in memory.
+    pub clipboard: Clipboard,
 }
 
 impl<T: Default + Theme, V: VectorGraphicsInterface> Default for MayConfig<T, V> {
@@ -24,6 +35,10 @@ impl<T: Default + Theme, V: VectorGraphicsInterface> Default for MayConfig<T, V>
             window: WindowConfig::default(),
             theme: T::default(),
             graphics: V::Config::default(),
+            #[cfg(system_clipboard)]
+            clipboard: Clipboard::system(),
+            #[cfg(not(system_clipboard))]
+            clipboard: Clipboard::memory(),
         }
     }
 }
@@ -101,8 +116,52 @@ impl Default for WindowConfig {
     }
 }
 
+impl WindowConfig {
+    /// Converts this configuration into [WindowAttributes] for creating a window.
+    #[inline(always)]
+    pub fn attributes(&self) -> WindowAttributes {
+        let mut attrs = WindowAttributes::default()
+            .with_inner_size(LogicalSize::new(self.size.x, self.size.y))
+            .with_resizable(self.resizable)
+            .with_enabled_buttons(self.buttons)
+            .with_title(self.title.clone())
+            .with_maximized(self.maximized)
+            .with_visible(self.visible)
+            .with_transparent(self.transparent)
+            .with_blur(self.blur)
+            .with_decorations(self.decorations)
+            .with_window_icon(self.icon.clone())
+            .with_content_protected(self.content_protected)
+            .with_window_level(self.level)
+            .with_active(self.active)
+            .with_cursor(self.cursor.clone());
+
+        // since `with_max_inner_size()` doesn't support `Option` values, we need to manually set it
+        attrs.max_inner_size = self
+            .max_size
+            .map(|v| Size::Logical(LogicalSize::new(v.x, v.y)));
+
+        // since `with_min_inner_size()` doesn't support `Option` values, we need to manually set it
+        attrs.min_inner_size = self
+            .min_size
+            .map(|v| Size::Logical(LogicalSize::new(v.x, v.y)));
+
+        // since `with_position()` doesn't support `Option` values, we need to manually set it
+        attrs.position = self
+            .position
+            .map(|v| Position::Logical(LogicalPosition::new(v.x, v.y)));
+
+        // since `with_resize_increments()` doesn't support `Option` values, we need to manually set it
+        attrs.resize_increments = self
+            .resize_increments
+            .map(|v| Size::Logical(LogicalSize::new(v.x, v.y)));
+
+        attrs
+    }
+}
+
 /// The window mode.
-#[derive(Clone, Debug, Default)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub enum WindowMode {
     /// The default windowed mode.
     #[default]
@@ -112,3 +171,32 @@ pub enum WindowMode {
     /// Legacy Fullscreen mode.
     Fullscreen,
 }
+
+impl WindowMode {
+    /// Returns the [Fullscreen] mode of a window on the given monitor.
+    ///
+    /// Legacy fullscreen uses the video mode of the monitor with the highest resolution and refresh rate.
+    /// If the monitor is unknown or has no video modes, borderless fullscreen is used instead.
+    #[inline(always)]
+    pub fn fullscreen(&self, monitor: Option<MonitorHandle>) -> Option<Fullscreen> {
+        match self {
+            WindowMode::Windowed => None,
+            WindowMode::Borderless => Some(Fullscreen::Borderless(monitor)),
+            WindowMode::Fullscreen => {
+                let video_mode = monitor.as_ref().and_then(|monitor| {
+                    monitor.video_modes().max_by_key(|mode| {
+                        (
+                            mode.size().width * mode.size().height,
+                            mode.refresh_rate_millihertz(),
+                        )
+                    })
+                });
+
+                match video_mode {
+                    Some(video_mode) => Some(Fullscreen::Exclusive(video_mode)),
+                    None => Some(Fullscreen::Borderless(monitor)),
+                }
+            },
+        }
+    }
+}
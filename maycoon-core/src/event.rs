@@ -0,0 +1,166 @@
+use std::fmt::{Debug, Formatter};
+
+use nalgebra::Vector2;
+use winit::event::MouseButton;
+use winit::keyboard::ModifiersState;
+
+use crate::app::context::AppContext;
+use crate::app::update::Update;
+
+/// A boxed closure handling an event with a payload of type `E`.
+pub type BoxedHandlerFn<E> = Box<dyn FnMut(&E, &AppContext) -> Update>;
+
+/// A handler for widget events with a payload of type `E`.
+///
+/// The handler wraps a closure, which is called with the event payload and the [AppContext] of the widget,
+/// whenever the event occurs. The returned [Update] is applied to the application.
+///
+/// Widgets accept handlers via builder methods like `with_on_pressed`, which take the closure directly,
+/// so the types of the closure parameters can be inferred.
+///
+/// Example:
+/// ```
+/// # use maycoon_core::app::update::Update;
+/// # use maycoon_core::event::{Click, Handler};
+/// # use maycoon_core::signal::Signal;
+/// # use maycoon_core::signal::state::StateSignal;
+/// let clicks = StateSignal::new(0);
+///
+/// let handler = Handler::new(move |_: &Click, _| {
+///     clicks.mutate(|clicks| *clicks += 1);
+///
+///     Update::DRAW
+/// });
+/// ```
+pub struct Handler<E> {
+    handler: Option<BoxedHandlerFn<E>>,
+}
+
+impl<E> Handler<E> {
+    /// Create a new handler calling the given closure.
+    #[inline(always)]
+    pub fn new(handler: impl FnMut(&E, &AppContext) -> Update + 'static) -> Self {
+        Self {
+            handler: Some(Box::new(handler)),
+        }
+    }
+
+    /// Create a new handler, which ignores all events.
+    #[inline(always)]
+    pub const fn none() -> Self {
+        Self { handler: None }
+    }
+
+    /// Returns if the handler ignores all events.
+    #[inline(always)]
+    pub const fn is_none(&self) -> bool {
+        self.handler.is_none()
+    }
+
+    /// Call the handler with the given event and return the [Update] to apply.
+    #[inline(always)]
+    pub fn call(&mut self, event: &E, context: &AppContext) -> Update {
+        match &mut self.handler {
+            Some(handler) => handler(event, context),
+            None => Update::empty(),
+        }
+    }
+}
+
+impl<E> Default for Handler<E> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl<E, F: FnMut(&E, &AppContext) -> Update + 'static> From<F> for Handler<E> {
+    #[inline(always)]
+    fn from(handler: F) -> Self {
+        Self::new(handler)
+    }
+}
+
+impl<E> Debug for Handler<E> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Handler")
+            .field("none", &self.is_none())
+            .finish()
+    }
+}
+
+/// The payload of click and press events.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Click {
+    /// The position of the cursor.
+    ///
+    /// This is [None], if the event was triggered by the keyboard.
+    pub position: Option<Vector2<f32>>,
+    /// The mouse button, which caused the event.
+    ///
+    /// This is [MouseButton::Left], if the event was triggered by the keyboard.
+    pub button: MouseButton,
+    /// The modifier keys held during the event.
+    pub modifiers: ModifiersState,
+}
+
+impl Click {
+    /// Create a new click with the given cursor position, mouse button and modifiers.
+    #[inline(always)]
+    pub const fn new(
+        position: Vector2<f32>,
+        button: MouseButton,
+        modifiers: ModifiersState,
+    ) -> Self {
+        Self {
+            position: Some(position),
+            button,
+            modifiers,
+        }
+    }
+
+    /// Create a new click triggered by the keyboard with the given modifiers.
+    #[inline(always)]
+    pub const fn keyboard(modifiers: ModifiersState) -> Self {
+        Self {
+            position: None,
+            button: MouseButton::Left,
+            modifiers,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use crate::app::context::AppContext;
+    use crate::app::diagnostics::Diagnostics;
+    use crate::app::update::{Update, UpdateManager};
+    use crate::event::Handler;
+
+    /// Test calling handlers with an event.
+    #[test]
+    fn test_handler() {
+        let context = AppContext::new(UpdateManager::new(), Diagnostics::default());
+        let mut sum = 0;
+
+        let mut handler = Handler::new(move |value: &i32, _| {
+            sum += *value;
+
+            if sum > 2 {
+                Update::DRAW
+            } else {
+                Update::empty()
+            }
+        });
+
+        assert!(!handler.is_none());
+        assert_eq!(handler.call(&2, &context), Update::empty());
+        assert_eq!(handler.call(&1, &context), Update::DRAW);
+
+        let mut none = Handler::<i32>::default();
+
+        assert!(none.is_none());
+        assert_eq!(none.call(&1, &context), Update::empty());
+    }
+}
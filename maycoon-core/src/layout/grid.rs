@@ -0,0 +1,357 @@
+use crate::layout::{
+    GridPlacement, GridTemplateArea, GridTemplateComponent, GridTemplateRepetition, Line,
+    MaxTrackSizingFunction, MinTrackSizingFunction, RepetitionCount, TrackSizingFunction,
+};
+
+/// Creates a track, which takes the given fraction of the remaining free space (`<fraction>fr` in CSS).
+///
+/// The track is at least as large as its content (like `minmax(auto, <fraction>fr)` in CSS).
+///
+/// Example:
+/// ```
+/// # use maycoon_core::layout::LayoutStyle;
+/// # use maycoon_core::layout::grid::{fr, length};
+///
+/// // A fixed sidebar and a main column that fills the remaining space.
+/// let style = LayoutStyle {
+///     grid_template_columns: vec![length(200.0), fr(1.0)],
+///     ..Default::default()
+/// };
+/// ```
+#[inline(always)]
+pub fn fr<T: From<TrackSizingFunction>>(fraction: f32) -> T {
+    minmax(
+        MinTrackSizingFunction::auto(),
+        MaxTrackSizingFunction::fr(fraction),
+    )
+}
+
+/// Creates a track with a fixed size in pixels.
+#[inline(always)]
+pub fn length<T: From<TrackSizingFunction>>(length: f32) -> T {
+    minmax(
+        MinTrackSizingFunction::length(length),
+        MaxTrackSizingFunction::length(length),
+    )
+}
+
+/// Creates a track with a size relative to the size of the grid container. `1.0` means 100%.
+#[inline(always)]
+pub fn percent<T: From<TrackSizingFunction>>(percent: f32) -> T {
+    minmax(
+        MinTrackSizingFunction::percent(percent),
+        MaxTrackSizingFunction::percent(percent),
+    )
+}
+
+/// Creates a track, which is sized to its content and grows into the free space (`auto` in CSS).
+#[inline(always)]
+pub fn auto<T: From<TrackSizingFunction>>() -> T {
+    minmax(
+        MinTrackSizingFunction::auto(),
+        MaxTrackSizingFunction::auto(),
+    )
+}
+
+/// Creates a track with the smallest size of its content without overflowing (`min-content` in CSS).
+#[inline(always)]
+pub fn min_content<T: From<TrackSizingFunction>>() -> T {
+    minmax(
+        MinTrackSizingFunction::min_content(),
+        MaxTrackSizingFunction::min_content(),
+    )
+}
+
+/// Creates a track with the largest size of its content (`max-content` in CSS).
+#[inline(always)]
+pub fn max_content<T: From<TrackSizingFunction>>() -> T {
+    minmax(
+        MinTrackSizingFunction::max_content(),
+        MaxTrackSizingFunction::max_content(),
+    )
+}
+
+/// Creates a track, which is sized to its content, but at most the given size in pixels (`fit-content(<limit>)` in CSS).
+#[inline(always)]
+pub fn fit_content<T: From<TrackSizingFunction>>(limit: f32) -> T {
+    minmax(
+        MinTrackSizingFunction::auto(),
+        MaxTrackSizingFunction::fit_content_px(limit),
+    )
+}
+
+/// Creates a track, which is at least `min` and at most `max` large (`minmax(<min>, <max>)` in CSS).
+///
+/// Example:
+/// ```
+/// # use maycoon_core::layout::{LayoutStyle, MaxTrackSizingFunction, MinTrackSizingFunction};
+/// # use maycoon_core::layout::grid::minmax;
+///
+/// // A column that is at least 100 pixels wide and fills the remaining space.
+/// let style = LayoutStyle {
+///     grid_template_columns: vec![minmax(
+///         MinTrackSizingFunction::length(100.0),
+///         MaxTrackSizingFunction::fr(1.0),
+///     )],
+///     ..Default::default()
+/// };
+/// ```
+#[inline(always)]
+pub fn minmax<T: From<TrackSizingFunction>>(
+    min: MinTrackSizingFunction,
+    max: MaxTrackSizingFunction,
+) -> T {
+    T::from(TrackSizingFunction { min, max })
+}
+
+/// Repeats the given tracks `count` times (`repeat(<count>, <tracks>)` in CSS).
+///
+/// Example:
+/// ```
+/// # use maycoon_core::layout::LayoutStyle;
+/// # use maycoon_core::layout::grid::{fr, repeat};
+///
+/// // Three columns of equal width.
+/// let style = LayoutStyle {
+///     grid_template_columns: vec![repeat(3, vec![fr(1.0)])],
+///     ..Default::default()
+/// };
+/// ```
+#[inline(always)]
+pub fn repeat(count: u16, tracks: Vec<TrackSizingFunction>) -> GridTemplateComponent {
+    repetition(RepetitionCount::Count(count), tracks)
+}
+
+/// Repeats the given tracks as often as they fit into the grid container (`repeat(auto-fill, <tracks>)` in CSS).
+///
+/// The tracks must have a fixed size.
+#[inline(always)]
+pub fn repeat_fill(tracks: Vec<TrackSizingFunction>) -> GridTemplateComponent {
+    repetition(RepetitionCount::AutoFill, tracks)
+}
+
+/// Like [repeat_fill], but collapses the empty repeated tracks (`repeat(auto-fit, <tracks>)` in CSS).
+///
+/// The tracks must have a fixed size.
+#[inline(always)]
+pub fn repeat_fit(tracks: Vec<TrackSizingFunction>) -> GridTemplateComponent {
+    repetition(RepetitionCount::AutoFit, tracks)
+}
+
+/// Creates a repetition of the given tracks.
+#[inline(always)]
+fn repetition(count: RepetitionCount, tracks: Vec<TrackSizingFunction>) -> GridTemplateComponent {
+    GridTemplateComponent::Repeat(GridTemplateRepetition {
+        count,
+        tracks,
+        line_names: Vec::new(),
+    })
+}
+
+/// Places an item starting at the given grid line.
+///
+/// Lines are counted from `1`. Negative indices count from the end of the explicit grid.
+#[inline(always)]
+pub fn line(index: i16) -> Line<GridPlacement> {
+    lines(index, 0)
+}
+
+/// Places an item between the given grid lines.
+///
+/// Lines are counted from `1`. Negative indices count from the end of the explicit grid.
+/// The line index `0` stands for an automatic placement.
+#[inline(always)]
+pub fn lines(start: i16, end: i16) -> Line<GridPlacement> {
+    let placement = |index: i16| {
+        if index == 0 {
+            GridPlacement::Auto
+        } else {
+            GridPlacement::Line(index.into())
+        }
+    };
+
+    Line {
+        start: placement(start),
+        end: placement(end),
+    }
+}
+
+/// Places an item spanning the given number of tracks, starting at the next free position.
+#[inline(always)]
+pub fn span(tracks: u16) -> Line<GridPlacement> {
+    Line {
+        start: GridPlacement::Span(tracks),
+        end: GridPlacement::Auto,
+    }
+}
+
+/// Places an item into the grid area with the given name.
+///
+/// Use this for both [grid_row](crate::layout::LayoutStyle::grid_row) and [grid_column](crate::layout::LayoutStyle::grid_column).
+/// The areas are defined by [grid_template_areas](crate::layout::LayoutStyle::grid_template_areas) (see [areas]).
+#[inline(always)]
+pub fn area(name: impl Into<String>) -> Line<GridPlacement> {
+    let name = name.into();
+
+    Line {
+        start: GridPlacement::NamedLine(name.clone(), 0),
+        end: GridPlacement::NamedLine(name, 0),
+    }
+}
+
+/// Creates grid areas from a text representation (like `grid-template-areas` in CSS).
+///
+/// Each string describes a row of the grid, with one area name per column, separated by whitespace.
+/// A `.` marks an empty cell. Each area should form a rectangle.
+///
+/// Example:
+/// ```
+/// # use maycoon_core::layout::{Display, LayoutStyle};
+/// # use maycoon_core::layout::grid::{area, areas, fr, length};
+///
+/// let style = LayoutStyle {
+///     display: Display::Grid,
+///     grid_template_columns: vec![length(200.0), fr(1.0)],
+///     grid_template_areas: areas(&["header header", "sidebar main"]),
+///     ..Default::default()
+/// };
+///
+/// // The style of an item, which is placed in the main area.
+/// let main = LayoutStyle {
+///     grid_row: area("main"),
+///     grid_column: area("main"),
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(style.grid_template_areas.len(), 3);
+/// ```
+pub fn areas(rows: &[&str]) -> Vec<GridTemplateArea> {
+    let mut areas: Vec<GridTemplateArea> = Vec::new();
+
+    for (row, names) in rows.iter().enumerate() {
+        for (column, name) in names.split_whitespace().enumerate() {
+            if name == "." {
+                continue;
+            }
+
+            // grid lines are counted from 1 and the end line is exclusive
+            let (row, column) = (row as u16 + 1, column as u16 + 1);
+
+            if let Some(area) = areas.iter_mut().find(|area| area.name == name) {
+                area.row_start = area.row_start.min(row);
+                area.row_end = area.row_end.max(row + 1);
+                area.column_start = area.column_start.min(column);
+                area.column_end = area.column_end.max(column + 1);
+            } else {
+                areas.push(GridTemplateArea {
+                    name: name.to_string(),
+                    row_start: row,
+                    row_end: row + 1,
+                    column_start: column,
+                    column_end: column + 1,
+                });
+            }
+        }
+    }
+
+    areas
+}
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use crate::layout::grid::{area, areas, fr, length, repeat};
+    use crate::layout::{
+        Display, GridPlacement, GridTemplateArea, GridTemplateComponent, LayoutStyle,
+        TrackSizingFunction,
+    };
+    use taffy::{AvailableSpace, Size, TaffyTree};
+
+    /// Test parsing grid areas with [areas].
+    #[test]
+    fn test_areas() {
+        let areas = areas(&["header header header", "nav main .", "nav footer footer"]);
+
+        let area = |name: &str, row_start, row_end, column_start, column_end| GridTemplateArea {
+            name: name.to_string(),
+            row_start,
+            row_end,
+            column_start,
+            column_end,
+        };
+
+        assert_eq!(
+            areas,
+            vec![
+                area("header", 1, 2, 1, 4),
+                area("nav", 2, 4, 1, 2),
+                area("main", 2, 3, 2, 3),
+                area("footer", 3, 4, 2, 4),
+            ]
+        );
+    }
+
+    /// Test that the track builders can be used for templates and implicit tracks.
+    #[test]
+    fn test_tracks() {
+        let template: GridTemplateComponent = fr(1.0);
+        let auto: TrackSizingFunction = fr(1.0);
+
+        assert_eq!(template, GridTemplateComponent::Single(auto));
+        assert!(matches!(
+            repeat(2, vec![length(10.0)]),
+            GridTemplateComponent::Repeat(_)
+        ));
+        assert!(matches!(area("main").start, GridPlacement::NamedLine(..)));
+    }
+
+    /// Test laying out a grid with templates and named areas.
+    #[test]
+    fn test_grid_layout() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+
+        let item = |name: &str| {
+            LayoutStyle {
+                grid_row: area(name),
+                grid_column: area(name),
+                ..Default::default()
+            }
+            .into()
+        };
+
+        let header = taffy.new_leaf(item("header")).unwrap();
+        let sidebar = taffy.new_leaf(item("sidebar")).unwrap();
+        let main = taffy.new_leaf(item("main")).unwrap();
+
+        let root = taffy
+            .new_with_children(
+                LayoutStyle {
+                    display: Display::Grid,
+                    size: nalgebra::Vector2::new(
+                        taffy::Dimension::length(400.0),
+                        taffy::Dimension::length(300.0),
+                    ),
+                    grid_template_columns: vec![length(100.0), fr(1.0)],
+                    grid_template_rows: vec![length(50.0), fr(1.0)],
+                    grid_template_areas: areas(&["header header", "sidebar main"]),
+                    ..Default::default()
+                }
+                .into(),
+                &[header, sidebar, main],
+            )
+            .unwrap();
+
+        taffy
+            .compute_layout(root, Size::<AvailableSpace>::max_content())
+            .unwrap();
+
+        let header = taffy.layout(header).unwrap();
+        let sidebar = taffy.layout(sidebar).unwrap();
+        let main = taffy.layout(main).unwrap();
+
+        assert_eq!((header.size.width, header.size.height), (400.0, 50.0));
+        assert_eq!((sidebar.location.x, sidebar.location.y), (0.0, 50.0));
+        assert_eq!((sidebar.size.width, sidebar.size.height), (100.0, 250.0));
+        assert_eq!((main.location.x, main.location.y), (100.0, 50.0));
+        assert_eq!((main.size.width, main.size.height), (300.0, 250.0));
+    }
+}
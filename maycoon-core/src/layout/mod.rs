@@ -1,11 +1,27 @@
 use nalgebra::Vector2;
+use std::rc::Rc;
 pub use taffy::{
-    AlignContent, AlignItems, AlignSelf, Dimension, Display, FlexDirection, FlexWrap, GridAutoFlow,
-    GridPlacement, JustifyContent, JustifyItems, JustifySelf, Layout, LengthPercentage,
-    LengthPercentageAuto, Line, NodeId, Overflow, Position, Rect, TaffyError, TaffyResult,
-    TaffyTree,
+    AlignContent, AlignItems, AlignSelf, AvailableSpace, Dimension, Display, FlexDirection,
+    FlexWrap, GridAutoFlow, GridPlacement, JustifyContent, JustifyItems, JustifySelf, Layout,
+    LengthPercentage, LengthPercentageAuto, Line, MaxTrackSizingFunction, MinTrackSizingFunction,
+    NodeId, Overflow, Position, Rect, RepetitionCount, TaffyError, TaffyResult, TaffyTree,
+    TrackSizingFunction,
 };
 
+/// Contains builders for grid tracks, placements and areas.
+pub mod grid;
+
+/// A track definition of a grid template.
+///
+/// Either a single track or a repetition of tracks. See [grid] for builders.
+pub type GridTemplateComponent = taffy::GridTemplateComponent<String>;
+
+/// A repetition of tracks in a grid template. See [grid::repeat] for a builder.
+pub type GridTemplateRepetition = taffy::GridTemplateRepetition<String>;
+
+/// A named area of a grid template. See [grid::areas] for a builder.
+pub type GridTemplateArea = taffy::GridTemplateArea<String>;
+
 /// The tiniest difference two floats should have, in a layout context, to be considered "unequal".
 ///
 /// This value is equal to half a pixel.
@@ -41,6 +57,7 @@ pub const fn equal(x: f32, y: f32) -> bool {
 /// Checks if the given point intersects the given layout.
 ///
 /// This can be used to check if the cursor is hovering on a widget.
+/// Unlike the [PointerManager](crate::app::pointer::PointerManager), this does not respect overlapping widgets.
 ///
 /// Example:
 /// ```
@@ -145,6 +162,34 @@ pub struct LayoutStyle {
     /// 1.0 is the default value, and this value must be positive.
     pub flex_shrink: f32,
 
+    /// Defines the track sizing functions (heights) of the explicit grid rows.
+    pub grid_template_rows: Vec<GridTemplateComponent>,
+
+    /// Defines the track sizing functions (widths) of the explicit grid columns.
+    pub grid_template_columns: Vec<GridTemplateComponent>,
+
+    /// Defines the size of implicitly created rows.
+    pub grid_auto_rows: Vec<TrackSizingFunction>,
+
+    /// Defines the size of implicitly created columns.
+    pub grid_auto_columns: Vec<TrackSizingFunction>,
+
+    /// Defines the named areas of the grid.
+    ///
+    /// Items can be placed into an area by setting [grid_row](Self::grid_row)
+    /// and [grid_column](Self::grid_column) to [grid::area].
+    pub grid_template_areas: Vec<GridTemplateArea>,
+
+    /// Defines the names of the grid row lines.
+    ///
+    /// The first element contains the names of the first line and so on.
+    pub grid_template_row_names: Vec<Vec<String>>,
+
+    /// Defines the names of the grid column lines.
+    ///
+    /// The first element contains the names of the first line and so on.
+    pub grid_template_column_names: Vec<Vec<String>>,
+
     /// Controls how items get placed into the grid for auto-placed items.
     pub grid_auto_flow: GridAutoFlow,
 
@@ -183,6 +228,13 @@ impl Default for LayoutStyle {
             flex_grow: 0.0,
             flex_shrink: 1.0,
             flex_basis: Dimension::auto(),
+            grid_template_rows: Vec::new(),
+            grid_template_columns: Vec::new(),
+            grid_auto_rows: Vec::new(),
+            grid_auto_columns: Vec::new(),
+            grid_template_areas: Vec::new(),
+            grid_template_row_names: Vec::new(),
+            grid_template_column_names: Vec::new(),
             grid_auto_flow: GridAutoFlow::Row,
             grid_row: Line {
                 start: GridPlacement::Auto,
@@ -239,6 +291,13 @@ impl From<LayoutStyle> for taffy::Style {
             flex_grow: value.flex_grow,
             flex_shrink: value.flex_shrink,
             flex_basis: value.flex_basis,
+            grid_template_rows: value.grid_template_rows,
+            grid_template_columns: value.grid_template_columns,
+            grid_auto_rows: value.grid_auto_rows,
+            grid_auto_columns: value.grid_auto_columns,
+            grid_template_areas: value.grid_template_areas,
+            grid_template_row_names: value.grid_template_row_names,
+            grid_template_column_names: value.grid_template_column_names,
             grid_auto_flow: value.grid_auto_flow,
             grid_row: value.grid_row,
             grid_column: value.grid_column,
@@ -248,20 +307,61 @@ impl From<LayoutStyle> for taffy::Style {
 }
 
 /// The computed layout with children nodes.
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct LayoutNode {
     /// The computed layout of this node.
+    ///
+    /// The location is absolute, i.e. relative to the window and not to the parent node.
     pub layout: Layout,
     /// The children of this node.
     pub children: Vec<LayoutNode>,
 }
 
+impl LayoutNode {
+    /// Returns a copy of this node and its children, moved by the given offset.
+    ///
+    /// Useful for widgets that draw their children at another position, like scroll views.
+    pub fn translated(&self, offset: Vector2<f32>) -> LayoutNode {
+        let mut layout = self.layout;
+
+        layout.location.x += offset.x;
+        layout.location.y += offset.y;
+
+        LayoutNode {
+            layout,
+            children: self
+                .children
+                .iter()
+                .map(|child| child.translated(offset))
+                .collect(),
+        }
+    }
+}
+
+/// A function to measure the intrinsic size of a leaf node during layout.
+///
+/// The function is called with the already known dimensions of the node
+/// and the space available to the node and returns the size of the node content.
+/// Known dimensions should be returned as they are.
+///
+/// See [StyleNode::measure] for more.
+pub type MeasureFn = Rc<dyn Fn(Vector2<Option<f32>>, Vector2<AvailableSpace>) -> Vector2<f32>>;
+
 /// The raw layout styles with children nodes.
 pub struct StyleNode {
     /// The layout style of this node.
     pub style: LayoutStyle,
     /// The children of this node.
     pub children: Vec<StyleNode>,
+    /// An optional function to measure the content size of this node.
+    ///
+    /// This is used for leaf nodes, whose size depends on their content (like text or images)
+    /// and is only called, if the size is not already determined by the layout style.
+    /// The function may be called multiple times during a single layout pass, so it should be cheap or cached.
+    ///
+    /// Measure functions are compared by pointer: The cached measurements of the node are only kept,
+    /// if the widget returns the same [Rc] as in the previous layout, so don't create a new function on every call.
+    pub measure: Option<MeasureFn>,
 }
 
 #[cfg(all(test, feature = "test"))]
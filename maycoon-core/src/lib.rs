@@ -27,6 +27,9 @@ pub mod signal;
 /// Contains the core widget functionalities.
 pub mod widget;
 
+/// Contains the [event::Handler] for widget events and common event payloads.
+pub mod event;
+
 /// Contains structures to work with the component architecture.
 pub mod component;
 
@@ -45,5 +48,17 @@ pub mod vgi;
 /// Contains conditional types to provide generic platform specific structures.
 pub mod platform;
 
+/// Contains utilities for shaping, measuring and laying out text.
+pub mod text;
+
+/// Contains the [testing::Harness] for driving widgets without a window.
+pub mod testing;
+
+/// Contains functions for rendering widgets into images without a window.
+///
+/// Requires the `cpu-vg` feature.
+#[cfg(feature = "cpu-vg")]
+pub mod offscreen;
+
 /// The data of the default Noto Sans font.
 pub const DEFAULT_FONT: &[u8] = include_bytes!("NotoSans.ttf");
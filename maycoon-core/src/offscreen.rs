@@ -0,0 +1,143 @@
+use nalgebra::Vector2;
+use peniko::ImageData;
+
+use crate::testing::Harness;
+use crate::vgi::VectorGraphicsInterface as _;
+use crate::vgi::cpu::{RenderMode, VectorGraphicsConfig, VectorGraphicsInterface};
+use crate::vgi::recording::RecordingScene;
+use crate::widget::Widget;
+use maycoon_theme::theme::Theme;
+
+/// Renders the given widget with the given theme and size into an image.
+///
+/// This lays out the widget tree, runs a single update and draw pass
+/// and rasterizes the result on the CPU, including the window background of the theme.
+///
+/// Useful for exporting charts drawn on a canvas or generating documentation screenshots.
+///
+/// The returned image is in (non-premultiplied) RGBA8 format.
+/// The size is clamped to the range `1..=65535` in both dimensions.
+///
+/// If you need to simulate input before rendering, use a [Harness] with a [RecordingScene]
+/// and render its scene using [VectorGraphicsInterface::render_to_pixmap].
+///
+/// Example:
+/// ```
+/// # use maycoon_core::offscreen::render_to_image;
+/// # use maycoon_core::widget::Widget;
+/// # use maycoon_theme::theme::dummy::DummyTheme;
+/// # use nalgebra::Vector2;
+/// # fn test(widget: impl Widget) {
+/// let image = render_to_image(widget, DummyTheme::default(), Vector2::new(800, 600));
+///
+/// assert_eq!(image.width, 800);
+/// assert_eq!(image.height, 600);
+/// # }
+/// ```
+#[tracing::instrument(level = "trace", skip(widget, theme))]
+pub fn render_to_image(widget: impl Widget, theme: impl Theme, size: Vector2<u32>) -> ImageData {
+    let mut harness = Harness::with_scene(
+        widget,
+        theme,
+        Vector2::new(size.x as f64, size.y as f64),
+        RecordingScene::default(),
+    );
+
+    harness.frame();
+
+    let mut vgi = VectorGraphicsInterface::new(VectorGraphicsConfig {
+        render_mode: RenderMode::OptimizeQuality,
+    })
+    .expect("Failed to create vector graphics interface");
+
+    vgi.render_to_pixmap(harness.scene(), size, harness.theme().window_background());
+
+    vgi.to_image_data()
+}
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use crate::app::context::AppContext;
+    use crate::app::info::AppInfo;
+    use crate::app::update::Update;
+    use crate::layout::{Dimension, LayoutNode, LayoutStyle, StyleNode};
+    use crate::offscreen::render_to_image;
+    use crate::vgi::Scene;
+    use crate::vgi::kurbo::Rect;
+    use crate::widget::Widget;
+    use maycoon_theme::id::WidgetId;
+    use maycoon_theme::theme::Theme;
+    use maycoon_theme::theme::dummy::DummyTheme;
+    use nalgebra::Vector2;
+    use peniko::color::palette;
+    use peniko::{Brush, ImageFormat};
+
+    /// A widget that fills its layout with red.
+    struct RedBox;
+
+    impl Widget for RedBox {
+        fn render(
+            &mut self,
+            scene: &mut dyn Scene,
+            _: &mut dyn Theme,
+            layout: &LayoutNode,
+            _: &AppInfo,
+            _: AppContext,
+        ) {
+            scene.draw_rect(
+                &Brush::Solid(palette::css::RED),
+                None,
+                None,
+                &Rect::new(
+                    layout.layout.location.x as f64,
+                    layout.layout.location.y as f64,
+                    (layout.layout.location.x + layout.layout.size.width) as f64,
+                    (layout.layout.location.y + layout.layout.size.height) as f64,
+                ),
+            );
+        }
+
+        fn layout_style(&self) -> StyleNode {
+            StyleNode {
+                style: LayoutStyle {
+                    size: Vector2::new(Dimension::length(10.0), Dimension::length(10.0)),
+                    ..Default::default()
+                },
+                children: Vec::new(),
+                measure: None,
+            }
+        }
+
+        fn update(&mut self, _: &LayoutNode, _: AppContext, _: &AppInfo) -> Update {
+            Update::empty()
+        }
+
+        fn widget_id(&self) -> WidgetId {
+            WidgetId::new("maycoon-core", "RedBox")
+        }
+    }
+
+    /// Tests that a widget is rendered into an image with the theme background.
+    #[test]
+    fn test_render_to_image() {
+        let theme = DummyTheme::default();
+        let background = theme.window_background().to_rgba8();
+
+        let image = render_to_image(RedBox, theme, Vector2::new(20, 20));
+
+        assert_eq!((image.width, image.height), (20, 20));
+        assert_eq!(image.format, ImageFormat::Rgba8);
+        assert_eq!(image.data.data().len(), 20 * 20 * 4);
+
+        let pixel = |x: usize, y: usize| {
+            let i = (y * 20 + x) * 4;
+            image.data.data()[i..i + 4].to_vec()
+        };
+
+        assert_eq!(pixel(5, 5), vec![255, 0, 0, 255]);
+        assert_eq!(
+            pixel(15, 15),
+            vec![background.r, background.g, background.b, background.a]
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use crate::app::info::AppInfo;
 use crate::app::update::UpdateManager;
 use crate::config::MayConfig;
+use crate::layout::MeasureFn;
 use crate::vgi::VectorGraphicsInterface;
 use maycoon_theme::theme::Theme;
 use rpds::HashTrieMap;
@@ -46,12 +47,14 @@ pub trait Plugin<T: Theme, V: VectorGraphicsInterface>: 'static {
     ///
     /// Desktop applications typically don't get suspended and this function is only called once,
     /// while mobile apps can be suspended and resumed.
+    ///
+    /// The layout tree, scene and info are the ones of the main window.
     #[inline(always)]
     fn on_resume(
         &mut self,
         _config: &mut MayConfig<T, V>,
         _scene: &mut V::Scene,
-        _taffy: &mut TaffyTree,
+        _taffy: &mut TaffyTree<MeasureFn>,
         _window_node: NodeId,
         _info: &mut AppInfo,
         _update: &UpdateManager,
@@ -61,13 +64,17 @@ pub trait Plugin<T: Theme, V: VectorGraphicsInterface>: 'static {
 
     /// Called right before the application handler tries to update the application
     /// and figure out what updates to apply.
+    ///
+    /// Every window is updated separately, so this is called once per update of every open window.
+    /// Use the id of the given window to tell the windows apart.
+    /// The layout tree, scene, info and update manager are the ones of the given window.
     #[inline(always)]
     fn on_update(
         &mut self,
         _config: &mut MayConfig<T, V>,
         _window: &Arc<Window>,
         _scene: &mut V::Scene,
-        _taffy: &mut TaffyTree,
+        _taffy: &mut TaffyTree<MeasureFn>,
         _window_node: NodeId,
         _info: &mut AppInfo,
         _update: &UpdateManager,
@@ -75,7 +82,7 @@ pub trait Plugin<T: Theme, V: VectorGraphicsInterface>: 'static {
     ) {
     }
 
-    /// Called when a window event is received.
+    /// Called when a window event is received for any of the application windows.
     #[inline(always)]
     fn on_window_event(
         &mut self,
@@ -83,7 +90,7 @@ pub trait Plugin<T: Theme, V: VectorGraphicsInterface>: 'static {
         _config: &mut MayConfig<T, V>,
         _window: &Arc<Window>,
         _scene: &mut V::Scene,
-        _taffy: &mut TaffyTree,
+        _taffy: &mut TaffyTree<MeasureFn>,
         _window_node: NodeId,
         _info: &mut AppInfo,
         _update: &UpdateManager,
@@ -92,12 +99,15 @@ pub trait Plugin<T: Theme, V: VectorGraphicsInterface>: 'static {
     }
 
     /// Called when the application is suspended.
+    ///
+    /// Secondary windows are already closed at this point.
+    /// The layout tree, scene and info are the ones of the main window.
     #[cold]
     fn on_suspended(
         &mut self,
         _config: &mut MayConfig<T, V>,
         _scene: &mut V::Scene,
-        _taffy: &mut TaffyTree,
+        _taffy: &mut TaffyTree<MeasureFn>,
         _window_node: NodeId,
         _info: &mut AppInfo,
         _update: &UpdateManager,
@@ -0,0 +1,506 @@
+use nalgebra::Vector2;
+use std::path::PathBuf;
+use winit::event::{DeviceId, ElementState, Ime, MouseButton, MouseScrollDelta, TouchPhase};
+use winit::keyboard::{Key, ModifiersState};
+
+use crate::app::context::AppContext;
+use crate::app::info::AppInfo;
+use crate::app::input::{GestureInput, KeyInput, TouchInput};
+use crate::app::tree::WidgetTree;
+use crate::app::update::{Update, UpdateManager};
+use crate::layout::LayoutNode;
+use crate::vgi::Scene;
+use crate::vgi::dummy::DummyScene;
+use crate::widget::Widget;
+use maycoon_theme::theme::Theme;
+
+/// A headless test harness to drive a widget tree without a window.
+///
+/// The harness runs the same layout, update and draw pipeline as the [AppHandler](crate::app::handler::AppHandler),
+/// but uses a virtual window size and lets you inject synthetic input events into the [AppInfo].
+///
+/// Like in the [AppHandler](crate::app::handler::AppHandler), the widget tree is only updated in frames
+/// where [Update::EVAL] is requested, which is the case after injecting input.
+/// Use [Harness::force_update] to update the tree after changing widgets directly.
+///
+/// By default, widgets are drawn onto a [DummyScene], which discards all draw calls.
+///
+/// **NOTE:** The harness does not tick the global task runner.
+///
+/// Example:
+/// ```
+/// # use maycoon_core::app::update::Update;
+/// # use maycoon_core::testing::Harness;
+/// # use maycoon_core::widget::Widget;
+/// # use maycoon_theme::theme::dummy::DummyTheme;
+/// # use nalgebra::Vector2;
+/// # fn test(widget: impl Widget) {
+/// let mut harness = Harness::new(widget, DummyTheme::default(), Vector2::new(800.0, 600.0));
+///
+/// harness.frame();
+///
+/// harness.cursor_move(Vector2::new(10.0, 10.0));
+/// harness.click(Vector2::new(10.0, 10.0));
+///
+/// assert!(harness.frame().contains(Update::DRAW));
+/// # }
+/// ```
+pub struct Harness<W: Widget, T: Theme, S: Scene = DummyScene> {
+    tree: WidgetTree<W, S>,
+    theme: T,
+    info: AppInfo,
+    update: UpdateManager,
+    last_update: Update,
+}
+
+impl<W: Widget, T: Theme> Harness<W, T> {
+    /// Create a new harness with the given root widget, theme and virtual window size.
+    #[inline(always)]
+    pub fn new(widget: W, theme: T, size: Vector2<f64>) -> Self {
+        Self::with_scene(widget, theme, size, DummyScene)
+    }
+}
+
+impl<W: Widget, T: Theme, S: Scene> Harness<W, T, S> {
+    /// Create a new harness with the given root widget, theme, virtual window size and scene to draw on.
+    #[inline(always)]
+    pub fn with_scene(widget: W, theme: T, size: Vector2<f64>, scene: S) -> Self {
+        let mut tree = WidgetTree::new(scene);
+
+        tree.resize(size);
+        tree.set_widget(widget);
+
+        let update = UpdateManager::new();
+
+        // like the initial resize event of a window
+        update.insert(Update::EVAL);
+
+        Self {
+            tree,
+            theme,
+            info: AppInfo {
+                size,
+                ..Default::default()
+            },
+            update,
+            last_update: Update::empty(),
+        }
+    }
+
+    /// Get the application context of this harness.
+    #[inline(always)]
+    pub fn context(&self) -> AppContext {
+        self.tree.context(&self.info, &self.update)
+    }
+
+    /// Get a reference to the root widget.
+    #[inline(always)]
+    pub fn widget(&self) -> &W {
+        self.tree.widget().unwrap()
+    }
+
+    /// Get a mutable reference to the root widget.
+    #[inline(always)]
+    pub fn widget_mut(&mut self) -> &mut W {
+        self.tree.widget_mut().unwrap()
+    }
+
+    /// Get a reference to the theme.
+    #[inline(always)]
+    pub const fn theme(&self) -> &T {
+        &self.theme
+    }
+
+    /// Get a reference to the scene the widgets are drawn on.
+    #[inline(always)]
+    pub const fn scene(&self) -> &S {
+        &self.tree.scene
+    }
+
+    /// Get a reference to the application info.
+    #[inline(always)]
+    pub const fn info(&self) -> &AppInfo {
+        &self.info
+    }
+
+    /// Get a mutable reference to the application info.
+    ///
+    /// Useful to inject input that has no dedicated method.
+    #[inline(always)]
+    pub const fn info_mut(&mut self) -> &mut AppInfo {
+        &mut self.info
+    }
+
+    /// Get the [UpdateManager] of this harness.
+    #[inline(always)]
+    pub fn update_manager(&self) -> UpdateManager {
+        self.update.clone()
+    }
+
+    /// Returns the computed layout of the root widget after the last frame.
+    ///
+    /// Returns [None] if no frame has been simulated yet.
+    #[inline(always)]
+    pub const fn layout(&self) -> Option<&LayoutNode> {
+        self.tree.layout()
+    }
+
+    /// Returns the [Update] flags accumulated during the last frame.
+    #[inline(always)]
+    pub const fn last_update(&self) -> Update {
+        self.last_update
+    }
+
+    /// Resize the virtual window.
+    #[inline(always)]
+    pub fn resize(&mut self, size: Vector2<f64>) {
+        self.info.size = size;
+        self.tree.resize(size);
+        self.update
+            .insert(Update::EVAL | Update::DRAW | Update::LAYOUT);
+    }
+
+    /// Move the cursor to the given position.
+    #[inline(always)]
+    pub fn cursor_move(&mut self, position: Vector2<f32>) {
+        self.info.cursor_pos = Some(position);
+        self.update.insert(Update::EVAL);
+    }
+
+    /// Make the cursor leave the window.
+    #[inline(always)]
+    pub fn cursor_leave(&mut self) {
+        self.info.cursor_pos = None;
+        self.update.insert(Update::EVAL);
+    }
+
+    /// Fire a mouse button event.
+    #[inline(always)]
+    pub fn mouse_input(&mut self, button: MouseButton, state: ElementState) {
+        self.info.buttons.push((DeviceId::dummy(), button, state));
+        self.update.insert(Update::EVAL);
+    }
+
+    /// Move the cursor to the given position and press and release the left mouse button.
+    ///
+    /// Both button events are delivered in the same frame.
+    #[inline(always)]
+    pub fn click(&mut self, position: Vector2<f32>) {
+        self.cursor_move(position);
+        self.mouse_input(MouseButton::Left, ElementState::Pressed);
+        self.mouse_input(MouseButton::Left, ElementState::Released);
+    }
+
+    /// Fire a key event.
+    #[inline(always)]
+    pub fn key_input(&mut self, key: KeyInput) {
+        self.info.keys.push((DeviceId::dummy(), key));
+        self.update.insert(Update::EVAL);
+    }
+
+    /// Press and release the given logical key.
+    ///
+    /// Both key events are delivered in the same frame.
+    #[inline(always)]
+    pub fn press_key(&mut self, key: Key) {
+        self.key_input(KeyInput::new(key.clone(), ElementState::Pressed));
+        self.key_input(KeyInput::new(key, ElementState::Released));
+    }
+
+    /// Fire an IME event.
+    #[inline(always)]
+    pub fn ime(&mut self, ime: Ime) {
+        self.info.ime.push(ime);
+        self.update.insert(Update::EVAL);
+    }
+
+    /// Set the currently held modifier keys.
+    ///
+    /// Unlike other input, the modifiers are kept across frames.
+    #[inline(always)]
+    pub fn set_modifiers(&mut self, modifiers: ModifiersState) {
+        self.info.modifiers = modifiers;
+        self.update.insert(Update::EVAL);
+    }
+
+    /// Fire a mouse wheel event with the given scroll delta.
+    #[inline(always)]
+    pub fn scroll(&mut self, delta: MouseScrollDelta) {
+        self.info.mouse_scroll_delta = Some(delta);
+        self.update.insert(Update::EVAL);
+    }
+
+    /// Fire a touch event for the finger with the given identifier.
+    #[inline(always)]
+    pub fn touch(&mut self, id: u64, phase: TouchPhase, position: Vector2<f32>) {
+        self.info.touches.push(TouchInput::new(id, phase, position));
+        self.update.insert(Update::EVAL);
+    }
+
+    /// Fire a gesture event recognized by the platform.
+    #[inline(always)]
+    pub fn gesture(&mut self, gesture: GestureInput) {
+        self.info.gestures.push(gesture);
+        self.update.insert(Update::EVAL);
+    }
+
+    /// Drag the given files from the operating system over the window.
+    ///
+    /// The files are hovering the window until [Harness::drop_files] or [Harness::cancel_files] is called.
+    #[inline(always)]
+    pub fn hover_files(&mut self, files: impl IntoIterator<Item = PathBuf>) {
+        self.info.hovered_files.extend(files);
+        self.update.insert(Update::EVAL);
+    }
+
+    /// Drop the files hovering the window.
+    #[inline(always)]
+    pub fn drop_files(&mut self) {
+        self.info.dropped_files.append(&mut self.info.hovered_files);
+        self.update.insert(Update::EVAL);
+    }
+
+    /// Cancel dragging the files hovering the window.
+    #[inline(always)]
+    pub fn cancel_files(&mut self) {
+        self.info.hovered_files.clear();
+        self.update.insert(Update::EVAL);
+    }
+
+    /// Request an update of the widget tree in the next frame, even if no input was injected.
+    ///
+    /// Useful after changing the root widget using [Harness::widget_mut].
+    #[inline(always)]
+    pub fn force_update(&mut self) {
+        self.update.insert(Update::EVAL);
+    }
+
+    /// Simulate a single frame.
+    ///
+    /// If [Update::EVAL] is requested, this updates the widget tree,
+    /// re-evaluates the layout and redraws the widgets if requested.
+    /// Afterward, the injected input events and the [Update] flags are cleared,
+    /// except for [Update::EVAL] requested during the update, which updates the tree again in the next frame.
+    /// Otherwise, nothing happens and the pending flags are kept.
+    ///
+    /// Returns the [Update] flags accumulated during this frame.
+    #[tracing::instrument(level = "trace", skip_all)]
+    pub fn frame(&mut self) -> Update {
+        self.info.diagnostics.do_frame();
+
+        if !self.update.is_set(Update::EVAL) {
+            self.last_update = Update::empty();
+            return self.last_update;
+        }
+
+        self.update.remove(Update::EVAL);
+        self.tree.update(&self.info, &self.update);
+
+        if self
+            .update
+            .is_set(Update::DRAW | Update::LAYOUT | Update::FORCE)
+        {
+            self.tree.draw(&mut self.theme, &self.info, &self.update);
+        }
+
+        self.last_update = self.update.get();
+
+        self.info.reset();
+        self.update.remove(!Update::EVAL);
+
+        self.info.diagnostics.first_run = false;
+        self.info.diagnostics.do_update();
+
+        self.last_update
+    }
+
+    /// Simulate frames until no more updates are requested or `max` frames have been run.
+    ///
+    /// Returns the number of simulated frames.
+    #[inline(always)]
+    pub fn settle(&mut self, max: usize) -> usize {
+        for i in 0..max {
+            if self.frame().is_empty() {
+                return i + 1;
+            }
+        }
+
+        max
+    }
+}
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use crate::app::context::AppContext;
+    use crate::app::info::AppInfo;
+    use crate::app::update::Update;
+    use crate::layout::{Dimension, LayoutNode, LayoutStyle, StyleNode};
+    use crate::testing::Harness;
+    use crate::vgi::Scene;
+    use crate::widget::Widget;
+    use maycoon_theme::id::WidgetId;
+    use maycoon_theme::theme::Theme;
+    use maycoon_theme::theme::dummy::DummyTheme;
+    use nalgebra::Vector2;
+    use winit::event::MouseScrollDelta;
+    use winit::keyboard::{Key, NamedKey};
+
+    /// A widget that counts clicks, key presses and scroll events.
+    #[derive(Default)]
+    struct Counter {
+        clicks: usize,
+        keys: usize,
+        scrolls: usize,
+        renders: usize,
+        updates: usize,
+    }
+
+    impl Widget for Counter {
+        fn render(
+            &mut self,
+            _: &mut dyn Scene,
+            _: &mut dyn Theme,
+            _: &LayoutNode,
+            _: &AppInfo,
+            _: AppContext,
+        ) {
+            self.renders += 1;
+        }
+
+        fn layout_style(&self) -> StyleNode {
+            StyleNode {
+                style: LayoutStyle {
+                    size: Vector2::new(Dimension::length(100.0), Dimension::length(50.0)),
+                    ..Default::default()
+                },
+                children: Vec::new(),
+                measure: None,
+            }
+        }
+
+        fn update(&mut self, layout: &LayoutNode, _: AppContext, info: &AppInfo) -> Update {
+            let mut update = Update::empty();
+
+            self.updates += 1;
+
+            if let Some(cursor) = info.cursor_pos
+                && crate::layout::intersects(cursor, &layout.layout)
+            {
+                for (_, _, state) in &info.buttons {
+                    if !state.is_pressed() {
+                        self.clicks += 1;
+                        update |= Update::DRAW;
+                    }
+                }
+            }
+
+            self.keys += info.keys.iter().filter(|(_, k)| k.is_pressed()).count();
+
+            if info.mouse_scroll_delta.is_some() {
+                self.scrolls += 1;
+            }
+
+            update
+        }
+
+        fn widget_id(&self) -> WidgetId {
+            WidgetId::new("maycoon-core", "Counter")
+        }
+    }
+
+    /// Tests that the first frame lays out and draws the widget tree.
+    #[test]
+    fn test_harness_first_frame() {
+        let mut harness = Harness::new(
+            Counter::default(),
+            DummyTheme::default(),
+            Vector2::new(800.0, 600.0),
+        );
+
+        assert!(harness.layout().is_none());
+        assert!(harness.frame().contains(Update::FORCE));
+
+        let layout = harness.layout().unwrap();
+
+        assert_eq!(layout.layout.size.width, 100.0);
+        assert_eq!(layout.layout.size.height, 50.0);
+        assert_eq!(harness.widget().renders, 1);
+
+        // nothing happened, so nothing should be updated
+        assert!(harness.frame().is_empty());
+        assert_eq!(harness.widget().renders, 1);
+    }
+
+    /// Tests that synthetic input events are delivered to the widget.
+    #[test]
+    fn test_harness_input() {
+        let mut harness = Harness::new(
+            Counter::default(),
+            DummyTheme::default(),
+            Vector2::new(800.0, 600.0),
+        );
+
+        harness.frame();
+
+        harness.click(Vector2::new(10.0, 10.0));
+        assert!(harness.frame().contains(Update::DRAW));
+        assert_eq!(harness.widget().clicks, 1);
+        assert_eq!(harness.widget().renders, 2);
+
+        // clicks outside the widget are ignored
+        harness.click(Vector2::new(500.0, 500.0));
+        assert!(!harness.frame().contains(Update::DRAW));
+        assert_eq!(harness.widget().clicks, 1);
+
+        harness.press_key(Key::Named(NamedKey::Enter));
+        harness.frame();
+        assert_eq!(harness.widget().keys, 1);
+
+        harness.scroll(MouseScrollDelta::LineDelta(0.0, 1.0));
+        harness.frame();
+        assert_eq!(harness.widget().scrolls, 1);
+
+        // events are cleared after each frame
+        harness.frame();
+        assert_eq!(harness.widget().keys, 1);
+        assert_eq!(harness.widget().scrolls, 1);
+    }
+
+    /// Tests that resizing the virtual window re-lays out the widget tree.
+    #[test]
+    fn test_harness_resize() {
+        let mut harness = Harness::new(
+            Counter::default(),
+            DummyTheme::default(),
+            Vector2::new(800.0, 600.0),
+        );
+
+        harness.frame();
+        harness.resize(Vector2::new(200.0, 100.0));
+
+        assert!(harness.frame().contains(Update::LAYOUT));
+        assert_eq!(harness.info().size, Vector2::new(200.0, 100.0));
+    }
+
+    /// Tests that the widget tree is only updated if requested, like in the application handler.
+    #[test]
+    fn test_harness_force_update() {
+        let mut harness = Harness::new(
+            Counter::default(),
+            DummyTheme::default(),
+            Vector2::new(800.0, 600.0),
+        );
+
+        harness.frame();
+
+        let updates = harness.widget().updates;
+
+        assert!(harness.frame().is_empty());
+        assert_eq!(harness.widget().updates, updates);
+
+        harness.force_update();
+        harness.frame();
+
+        assert_eq!(harness.widget().updates, updates + 1);
+    }
+}
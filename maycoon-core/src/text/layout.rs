@@ -0,0 +1,693 @@
+use nalgebra::Vector2;
+use peniko::FontData;
+use rustybuzz::{Direction, Face, UnicodeBuffer};
+use skrifa::instance::{LocationRef, NormalizedCoord, Size};
+use skrifa::setting::VariationSetting;
+use skrifa::{FontRef, MetadataProvider};
+use std::ops::Range;
+use unicode_bidi::{Level, ParagraphBidiInfo};
+
+/// A glyph positioned by a [TextLayout].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PositionedGlyph {
+    /// The glyph id inside the font of the [GlyphRun].
+    pub id: u32,
+    /// The x-position of the glyph, relative to the layout.
+    pub x: f32,
+    /// The y-position of the glyph baseline, relative to the layout.
+    pub y: f32,
+}
+
+/// A sequence of glyphs inside a single line, which share the same font and direction.
+#[derive(Clone, Debug)]
+pub struct GlyphRun {
+    /// The font to draw the glyphs with.
+    pub font: FontData,
+    /// The byte range of the text, that was shaped into this run.
+    pub range: Range<usize>,
+    /// If the text of this run is written from right to left.
+    pub rtl: bool,
+    /// The positioned glyphs in visual order.
+    pub glyphs: Vec<PositionedGlyph>,
+    /// The normalized variation coordinates of the font, selecting the default instance of variable fonts.
+    pub coords: Vec<NormalizedCoord>,
+}
+
+/// A single line of a [TextLayout].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextLine {
+    /// The byte range of the line inside the text, excluding the line break.
+    pub range: Range<usize>,
+    /// The horizontal caret position in front of every character in the line,
+    /// followed by the caret position behind the last character.
+    ///
+    /// Contains one more offset than the line has characters.
+    /// For left-to-right text, the offsets are increasing and end with the width of the line.
+    pub offsets: Vec<f32>,
+    /// The top of the line, relative to the layout.
+    pub top: f32,
+    /// The baseline of the line, relative to the layout.
+    pub baseline: f32,
+    width: f32,
+}
+
+impl TextLine {
+    /// Get the width of the line, including trailing whitespace.
+    #[inline(always)]
+    pub fn width(&self) -> f32 {
+        self.width
+    }
+}
+
+/// Shaped and positioned text, ready to be measured and drawn.
+///
+/// The text is shaped using [rustybuzz], so kerning, ligatures and complex scripts are supported.
+/// Mixed left-to-right and right-to-left text is reordered using the Unicode Bidirectional Algorithm.
+///
+/// Lines are broken at `\n` and before a word that would exceed the maximum width.
+/// Words that are wider than the maximum width on their own are not broken.
+///
+/// The first font is used for all characters it supports and for the line metrics.
+/// Characters missing in the first font are drawn using the first fallback font that supports them.
+/// Use [FontContext::font_stack](crate::app::font_ctx::FontContext::font_stack)
+/// to get a font together with the fallback fonts of the application.
+///
+/// A layout can be drawn using [Scene::draw_layout](crate::vgi::Scene::draw_layout).
+#[derive(Clone, Debug)]
+pub struct TextLayout {
+    text: String,
+    font_size: f32,
+    line_gap: f32,
+    max_width: f32,
+    line_height: f32,
+    lines: Vec<TextLine>,
+    runs: Vec<GlyphRun>,
+    size: Vector2<f32>,
+}
+
+impl TextLayout {
+    /// Shapes and lays out the given text.
+    ///
+    /// The first font of `fonts` is the primary font, the others are fallback fonts.
+    ///
+    /// Panics if `fonts` is empty or a font cannot be loaded.
+    #[tracing::instrument(level = "trace", skip(text, fonts))]
+    pub fn new(
+        text: impl Into<String>,
+        fonts: &[FontData],
+        font_size: f32,
+        line_gap: f32,
+        max_width: f32,
+    ) -> Self {
+        let text = text.into();
+        let font = fonts.first().expect("At least one font is required");
+        let (ascent, line_height) = line_metrics(font, font_size);
+
+        let faces = fonts
+            .iter()
+            .map(|font| {
+                Face::from_slice(font.data.as_ref(), font.index).expect("Failed to load font face")
+            })
+            .collect::<Vec<_>>();
+
+        let coords = fonts
+            .iter()
+            .map(|font| {
+                FontRef::from_index(font.data.as_ref(), font.index)
+                    .expect("Failed to load font ref")
+                    .axes()
+                    .location::<&[VariationSetting; 0]>(&[])
+                    .coords()
+                    .to_vec()
+            })
+            .collect::<Vec<_>>();
+
+        let mut builder = LayoutBuilder {
+            text: &text,
+            fonts,
+            faces: &faces,
+            coords: &coords,
+            font_size,
+            max_width,
+            ascent,
+            advance: line_height + line_gap,
+            lines: Vec::new(),
+            runs: Vec::new(),
+            width: 0.0,
+        };
+
+        let mut start = 0;
+
+        for paragraph in text.split('\n') {
+            builder.paragraph(start..start + paragraph.len());
+
+            start += paragraph.len() + 1;
+        }
+
+        let LayoutBuilder {
+            lines, runs, width, ..
+        } = builder;
+
+        let height = lines.len() as f32 * (line_height + line_gap) - line_gap;
+
+        Self {
+            text,
+            font_size,
+            line_gap,
+            max_width,
+            line_height,
+            lines,
+            runs,
+            size: Vector2::new(width, height),
+        }
+    }
+
+    /// Get the laid out text.
+    #[inline(always)]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Get the font size of the text.
+    #[inline(always)]
+    pub fn font_size(&self) -> f32 {
+        self.font_size
+    }
+
+    /// Get the gap between lines.
+    #[inline(always)]
+    pub fn line_gap(&self) -> f32 {
+        self.line_gap
+    }
+
+    /// Get the maximum width of a line, at which the text was wrapped.
+    #[inline(always)]
+    pub fn max_width(&self) -> f32 {
+        self.max_width
+    }
+
+    /// Get the height of a single line, excluding the line gap.
+    #[inline(always)]
+    pub fn line_height(&self) -> f32 {
+        self.line_height
+    }
+
+    /// Get the lines of the layout.
+    ///
+    /// There is always at least one line.
+    #[inline(always)]
+    pub fn lines(&self) -> &[TextLine] {
+        &self.lines
+    }
+
+    /// Get the glyph runs of the layout.
+    #[inline(always)]
+    pub fn runs(&self) -> &[GlyphRun] {
+        &self.runs
+    }
+
+    /// Get the width of the layout, excluding trailing whitespace.
+    #[inline(always)]
+    pub fn width(&self) -> f32 {
+        self.size.x
+    }
+
+    /// Get the height of the layout.
+    #[inline(always)]
+    pub fn height(&self) -> f32 {
+        self.size.y
+    }
+
+    /// Get the size of the layout, excluding trailing whitespace.
+    #[inline(always)]
+    pub fn size(&self) -> Vector2<f32> {
+        self.size
+    }
+
+    /// Returns the index of the line containing the given byte position.
+    #[inline(always)]
+    pub fn line_at(&self, pos: usize) -> usize {
+        self.lines
+            .partition_point(|line| line.range.start <= pos)
+            .saturating_sub(1)
+    }
+
+    /// Returns the horizontal caret position in front of the character at the given byte position.
+    #[inline(always)]
+    pub fn caret_x(&self, pos: usize) -> f32 {
+        let line = &self.lines[self.line_at(pos)];
+        let pos = pos.clamp(line.range.start, line.range.end);
+
+        line.offsets[self.text[line.range.start..pos].chars().count()]
+    }
+
+    /// Returns the byte position of the caret closest to the given point, relative to the layout.
+    pub fn hit(&self, point: Vector2<f32>) -> usize {
+        let line = ((point.y / (self.line_height + self.line_gap)).max(0.0) as usize)
+            .min(self.lines.len() - 1);
+        let line = &self.lines[line];
+
+        let idx = line
+            .offsets
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| (*a - point.x).abs().total_cmp(&(*b - point.x).abs()))
+            .map(|(i, _)| i)
+            .unwrap_or_default();
+
+        self.text[line.range.clone()]
+            .char_indices()
+            .nth(idx)
+            .map(|(i, _)| line.range.start + i)
+            .unwrap_or(line.range.end)
+    }
+}
+
+/// Returns the ascent and the line height of the given font.
+#[inline(always)]
+pub(crate) fn line_metrics(font: &FontData, size: f32) -> (f32, f32) {
+    let font_ref =
+        FontRef::from_index(font.data.as_ref(), font.index).expect("Failed to load font ref");
+    let metrics = font_ref.metrics(Size::new(size), LocationRef::default());
+
+    // the descent is negative
+    (
+        metrics.ascent,
+        metrics.ascent - metrics.descent + metrics.leading,
+    )
+}
+
+/// Returns if the given character should use the font of the previous character,
+/// so whitespace and combining characters do not break up runs.
+#[inline(always)]
+fn inherits_font(c: char) -> bool {
+    c.is_whitespace()
+        || c.is_control()
+        || matches!(c, '\u{200C}' | '\u{200D}' | '\u{0300}'..='\u{036F}' | '\u{FE00}'..='\u{FE0F}')
+}
+
+/// A shaped glyph, before it is positioned.
+struct ShapedGlyph {
+    id: u32,
+    cluster: usize,
+    advance: f32,
+    offset: Vector2<f32>,
+}
+
+/// A run of text shaped with a single font and direction.
+struct ShapedRun {
+    font: usize,
+    level: Level,
+    range: Range<usize>,
+    glyphs: Vec<ShapedGlyph>,
+}
+
+/// The glyphs of one or more characters, which cannot be broken apart (e.g. ligatures).
+struct Cluster {
+    run: usize,
+    range: Range<usize>,
+    glyphs: Range<usize>,
+    advance: f32,
+    whitespace: bool,
+}
+
+/// Builds the lines and glyph runs of a [TextLayout].
+struct LayoutBuilder<'a> {
+    text: &'a str,
+    fonts: &'a [FontData],
+    faces: &'a [Face<'a>],
+    coords: &'a [Vec<NormalizedCoord>],
+    font_size: f32,
+    max_width: f32,
+    ascent: f32,
+    advance: f32,
+    lines: Vec<TextLine>,
+    runs: Vec<GlyphRun>,
+    width: f32,
+}
+
+impl LayoutBuilder<'_> {
+    /// Shapes the given paragraph and breaks it into lines.
+    fn paragraph(&mut self, range: Range<usize>) {
+        let text = &self.text[range.clone()];
+
+        if text.is_empty() {
+            self.line(&[], &[], range.start);
+            return;
+        }
+
+        let bidi = ParagraphBidiInfo::new(text, None);
+        let runs = self.itemize(text, &bidi.levels, range.start);
+
+        let clusters = runs
+            .iter()
+            .enumerate()
+            .flat_map(|(i, run)| self.clusters(run, i))
+            .collect::<Vec<_>>();
+
+        let mut start = 0;
+        let mut pen_x = 0.0;
+        let mut i = 0;
+
+        while i < clusters.len() {
+            let word = i;
+            let mut width = 0.0;
+            let mut trimmed = 0.0;
+
+            // a word ends behind a space
+            for cluster in &clusters[word..] {
+                i += 1;
+                width += cluster.advance;
+
+                if !cluster.whitespace {
+                    trimmed = width;
+                }
+
+                if self.text[cluster.range.clone()].ends_with(' ') {
+                    break;
+                }
+            }
+
+            // trailing spaces may exceed the maximum width
+            if pen_x > 0.0 && pen_x + trimmed > self.max_width {
+                self.line(&runs, &clusters[start..word], range.start);
+
+                start = word;
+                pen_x = 0.0;
+            }
+
+            pen_x += width;
+        }
+
+        self.line(&runs, &clusters[start..], range.start);
+    }
+
+    /// Splits the paragraph into runs of the same font and direction and shapes them.
+    fn itemize(&self, text: &str, levels: &[Level], offset: usize) -> Vec<ShapedRun> {
+        let mut runs = Vec::<ShapedRun>::new();
+        let mut current = None;
+
+        for (i, c) in text.char_indices() {
+            let font = match current {
+                Some(font) if inherits_font(c) => font,
+                _ => self
+                    .faces
+                    .iter()
+                    .position(|face| face.glyph_index(c).is_some())
+                    .or(current)
+                    .unwrap_or_default(),
+            };
+
+            current = Some(font);
+
+            let range = offset + i..offset + i + c.len_utf8();
+
+            match runs.last_mut() {
+                Some(run) if run.font == font && run.level == levels[i] => {
+                    run.range.end = range.end;
+                },
+
+                _ => runs.push(ShapedRun {
+                    font,
+                    level: levels[i],
+                    range,
+                    glyphs: Vec::new(),
+                }),
+            }
+        }
+
+        for run in &mut runs {
+            run.glyphs = self.shape(run);
+        }
+
+        runs
+    }
+
+    /// Shapes the text of the given run into glyphs in visual order.
+    fn shape(&self, run: &ShapedRun) -> Vec<ShapedGlyph> {
+        let face = &self.faces[run.font];
+        let scale = self.font_size / face.units_per_em() as f32;
+
+        let mut buffer = UnicodeBuffer::new();
+
+        buffer.push_str(&self.text[run.range.clone()]);
+        buffer.set_direction(if run.level.is_rtl() {
+            Direction::RightToLeft
+        } else {
+            Direction::LeftToRight
+        });
+        buffer.guess_segment_properties();
+
+        let output = rustybuzz::shape(face, &[], buffer);
+
+        output
+            .glyph_infos()
+            .iter()
+            .zip(output.glyph_positions())
+            .map(|(info, pos)| ShapedGlyph {
+                id: info.glyph_id,
+                cluster: run.range.start + info.cluster as usize,
+                advance: pos.x_advance as f32 * scale,
+                offset: Vector2::new(pos.x_offset as f32 * scale, pos.y_offset as f32 * scale),
+            })
+            .collect()
+    }
+
+    /// Groups the glyphs of the given run into clusters in logical order.
+    fn clusters(&self, run: &ShapedRun, index: usize) -> Vec<Cluster> {
+        let mut clusters = Vec::<Cluster>::new();
+
+        for (i, glyph) in run.glyphs.iter().enumerate() {
+            match clusters.last_mut() {
+                Some(cluster) if cluster.range.start == glyph.cluster => {
+                    cluster.glyphs.end = i + 1;
+                    cluster.advance += glyph.advance;
+                },
+
+                _ => clusters.push(Cluster {
+                    run: index,
+                    range: glyph.cluster..glyph.cluster,
+                    glyphs: i..i + 1,
+                    advance: glyph.advance,
+                    whitespace: false,
+                }),
+            }
+        }
+
+        // glyphs of right-to-left runs are in visual order
+        if run.level.is_rtl() {
+            clusters.reverse();
+        }
+
+        if let Some(first) = clusters.first_mut() {
+            first.range.start = run.range.start;
+        }
+
+        let mut end = run.range.end;
+
+        for cluster in clusters.iter_mut().rev() {
+            cluster.range.end = end;
+            cluster.whitespace = self.text[cluster.range.clone()]
+                .chars()
+                .all(char::is_whitespace);
+
+            end = cluster.range.start;
+        }
+
+        clusters
+    }
+
+    /// Positions the given clusters of a paragraph in visual order as a new line.
+    fn line(&mut self, runs: &[ShapedRun], clusters: &[Cluster], paragraph: usize) {
+        let top = self.lines.len() as f32 * self.advance;
+        let baseline = top + self.ascent;
+
+        let range = match (clusters.first(), clusters.last()) {
+            (Some(first), Some(last)) => first.range.start..last.range.end,
+            _ => paragraph..paragraph,
+        };
+
+        // the index of the first character of every cluster
+        let mut chars = Vec::with_capacity(clusters.len() + 1);
+        let mut count = 0;
+
+        for cluster in clusters {
+            chars.push(count);
+            count += self.text[cluster.range.clone()].chars().count();
+        }
+
+        chars.push(count);
+
+        let mut offsets = vec![0.0; count + 1];
+
+        // consecutive clusters of the same run
+        let mut segments = Vec::<Range<usize>>::new();
+
+        for (i, cluster) in clusters.iter().enumerate() {
+            match segments.last_mut() {
+                Some(segment) if clusters[segment.start].run == cluster.run => segment.end = i + 1,
+                _ => segments.push(i..i + 1),
+            }
+        }
+
+        let levels = segments
+            .iter()
+            .map(|segment| runs[clusters[segment.start].run].level)
+            .collect::<Vec<_>>();
+
+        let mut pen_x = 0.0;
+
+        for segment in ParagraphBidiInfo::reorder_visual(&levels)
+            .into_iter()
+            .map(|i| segments[i].clone())
+        {
+            let run = &runs[clusters[segment.start].run];
+            let rtl = run.level.is_rtl();
+
+            let mut order = segment.clone().collect::<Vec<_>>();
+
+            if rtl {
+                order.reverse();
+            }
+
+            let mut glyphs = Vec::new();
+
+            for i in order {
+                let cluster = &clusters[i];
+                let mut x = pen_x;
+
+                for glyph in &run.glyphs[cluster.glyphs.clone()] {
+                    glyphs.push(PositionedGlyph {
+                        id: glyph.id,
+                        x: x + glyph.offset.x,
+                        y: baseline - glyph.offset.y,
+                    });
+
+                    x += glyph.advance;
+                }
+
+                // split the cluster evenly between its characters
+                let n = chars[i + 1] - chars[i];
+
+                for k in 0..=n {
+                    let x = cluster.advance * k as f32 / n as f32;
+
+                    // the caret behind a character is only set by the last character
+                    if k < n || i == clusters.len() - 1 {
+                        offsets[chars[i] + k] = if rtl {
+                            pen_x + cluster.advance - x
+                        } else {
+                            pen_x + x
+                        };
+                    }
+                }
+
+                pen_x += cluster.advance;
+            }
+
+            self.runs.push(GlyphRun {
+                font: self.fonts[run.font].clone(),
+                range: clusters[segment.start].range.start..clusters[segment.end - 1].range.end,
+                rtl,
+                glyphs,
+                coords: self.coords[run.font].clone(),
+            });
+        }
+
+        let trailing = clusters
+            .iter()
+            .rev()
+            .take_while(|cluster| cluster.whitespace)
+            .map(|cluster| cluster.advance)
+            .sum::<f32>();
+
+        self.width = self.width.max(pen_x - trailing);
+
+        self.lines.push(TextLine {
+            range,
+            offsets,
+            top,
+            baseline,
+            width: pen_x,
+        });
+    }
+}
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use crate::app::font_ctx::FontContext;
+    use crate::text::layout::TextLayout;
+    use crate::text::text_width;
+    use nalgebra::Vector2;
+
+    /// Tests that the text is shaped with kerning.
+    #[test]
+    fn test_text_layout_kerning() {
+        let font_context = FontContext::default();
+        let font = font_context.default_font();
+
+        let separate = text_width(font, "A", 20.0) + text_width(font, "V", 20.0);
+
+        assert!(text_width(font, "AV", 20.0) < separate);
+    }
+
+    /// Tests that right-to-left text is reordered into its own glyph run.
+    #[test]
+    fn test_text_layout_bidi() {
+        let font_context = FontContext::default();
+        let fonts = font_context.font_stack(font_context.default_font());
+
+        let layout = TextLayout::new(
+            "abc \u{5D0}\u{5D1}\u{5D2}",
+            &fonts,
+            20.0,
+            0.0,
+            f32::INFINITY,
+        );
+
+        assert_eq!(layout.lines().len(), 1);
+        assert_eq!(layout.runs().len(), 2);
+        assert!(!layout.runs()[0].rtl);
+        assert!(layout.runs()[1].rtl);
+
+        // the caret moves to the left inside right-to-left text
+        let offsets = &layout.lines()[0].offsets;
+
+        assert!(offsets[4] > offsets[5]);
+        assert!(offsets[5] > offsets[6]);
+        assert!(offsets[6] > offsets[7]);
+        assert_eq!(offsets[4], layout.lines()[0].width());
+    }
+
+    /// Tests the line positions, measurement and hit testing.
+    #[test]
+    fn test_text_layout_lines() {
+        let font_context = FontContext::default();
+        let fonts = font_context.font_stack(font_context.default_font());
+
+        let layout = TextLayout::new("Hello World\nfoo", &fonts, 20.0, 5.0, f32::INFINITY);
+
+        assert_eq!(layout.lines().len(), 2);
+        assert_eq!(layout.lines()[1].top, layout.line_height() + 5.0);
+        assert!(layout.lines()[0].baseline > 0.0);
+        assert!(layout.lines()[0].baseline < layout.line_height());
+        assert_eq!(layout.height(), layout.line_height() * 2.0 + 5.0);
+        assert_eq!(layout.width(), layout.lines()[0].width());
+
+        assert_eq!(layout.line_at(3), 0);
+        assert_eq!(layout.line_at(12), 1);
+        assert_eq!(layout.caret_x(12), 0.0);
+
+        let x = layout.caret_x(4);
+        let y = layout.line_height() / 2.0;
+
+        assert_eq!(layout.hit(Vector2::new(x + 1.0, y)), 4);
+        assert_eq!(layout.hit(Vector2::new(1000.0, y)), 11);
+        assert_eq!(layout.hit(Vector2::new(0.0, 1000.0)), 12);
+
+        // trailing whitespace is not measured
+        let layout = TextLayout::new("Hello ", &fonts, 20.0, 0.0, f32::INFINITY);
+
+        assert!(layout.width() < layout.lines()[0].width());
+    }
+}
@@ -0,0 +1,107 @@
+use crate::text::layout::{TextLayout, TextLine, line_metrics};
+use peniko::FontData;
+
+/// Contains the [layout::TextLayout] for shaping and positioning text.
+pub mod layout;
+
+/// Breaks the given text into lines.
+///
+/// Lines are broken at `\n` and before a word that would exceed the given `max_width`.
+/// Words that are wider than `max_width` on their own are not broken.
+///
+/// This is the same line breaking as used by [Scene::draw_text](crate::vgi::Scene::draw_text),
+/// so it can be used to measure and place carets in wrapped text.
+/// See [TextLayout] for more.
+#[inline(always)]
+pub fn wrap_lines(font: &FontData, text: &str, size: f32, max_width: f32) -> Vec<TextLine> {
+    TextLayout::new(text, std::slice::from_ref(font), size, 0.0, max_width)
+        .lines()
+        .to_vec()
+}
+
+/// Measures the horizontal caret position in front of every character in the given single line of text.
+///
+/// Returns `text.chars().count() + 1` offsets, starting with `0.0` and ending with the width of the whole text,
+/// if the text is written from left to right.
+/// The offsets match the glyph positions of [Scene::draw_text](crate::vgi::Scene::draw_text).
+///
+/// Useful for placing carets and selections in text input widgets.
+#[inline(always)]
+pub fn char_offsets(font: &FontData, text: &str, size: f32) -> Vec<f32> {
+    let mut lines = wrap_lines(font, text, size, f32::INFINITY);
+
+    lines.swap_remove(0).offsets
+}
+
+/// Measures the width of the given single line of text.
+#[inline(always)]
+pub fn text_width(font: &FontData, text: &str, size: f32) -> f32 {
+    wrap_lines(font, text, size, f32::INFINITY)[0].width()
+}
+
+/// Returns the distance between two lines of text drawn by [Scene::draw_text](crate::vgi::Scene::draw_text),
+/// excluding the line gap.
+#[inline(always)]
+pub fn line_height(font: &FontData, size: f32) -> f32 {
+    line_metrics(font, size).1
+}
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use crate::app::font_ctx::FontContext;
+    use crate::text::{char_offsets, line_height, text_width, wrap_lines};
+
+    /// Tests that character offsets are monotonic and end at the text width.
+    #[test]
+    fn test_char_offsets() {
+        let font_context = FontContext::default();
+        let font = font_context.default_font();
+
+        let offsets = char_offsets(font, "Hello, World", 20.0);
+
+        assert_eq!(offsets.len(), 13);
+        assert_eq!(offsets[0], 0.0);
+        assert!(offsets.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(offsets[12], text_width(font, "Hello, World", 20.0));
+
+        assert!(char_offsets(font, "", 20.0) == vec![0.0]);
+        assert!(line_height(font, 20.0) > 0.0);
+    }
+
+    /// Tests that lines are broken at line breaks and before words exceeding the maximum width.
+    #[test]
+    fn test_wrap_lines() {
+        let font_context = FontContext::default();
+        let font = font_context.default_font();
+
+        let text = "Hello World\nfoo";
+        let lines = wrap_lines(font, text, 20.0, f32::INFINITY);
+        let ranges = lines
+            .iter()
+            .map(|l| &text[l.range.clone()])
+            .collect::<Vec<_>>();
+
+        assert_eq!(ranges, vec!["Hello World", "foo"]);
+        assert_eq!(lines[0].offsets.len(), 12);
+        assert_eq!(lines[0].width(), text_width(font, "Hello World", 20.0));
+
+        // "Hello " fits, but "World" does not
+        let max_width = text_width(font, "Hello Wor", 20.0);
+        let lines = wrap_lines(font, text, 20.0, max_width);
+        let ranges = lines
+            .iter()
+            .map(|l| &text[l.range.clone()])
+            .collect::<Vec<_>>();
+
+        assert_eq!(ranges, vec!["Hello ", "World", "foo"]);
+
+        // words wider than the maximum width are not broken and do not produce empty lines
+        let lines = wrap_lines(font, "Hello", 20.0, 1.0);
+        assert_eq!(lines.len(), 1);
+
+        // a trailing line break starts a new empty line
+        let lines = wrap_lines(font, "a\n", 20.0, f32::INFINITY);
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1].range, 2..2);
+    }
+}
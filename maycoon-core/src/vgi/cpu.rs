@@ -0,0 +1,861 @@
+use crate::text::layout::TextLayout;
+use crate::vgi;
+use crate::vgi::kurbo::{Affine, Rect, Shape, Stroke, Vec2};
+use crate::vgi::recording::{DrawCommand, RecordingScene};
+use nalgebra::Vector2;
+use peniko::{Blob, Brush, Color, Fill, ImageAlphaType, ImageBrush, ImageData, ImageFormat};
+use softbuffer::{SoftBufferError, Surface};
+use std::fmt::{Debug, Display, Formatter};
+use std::num::NonZeroU32;
+use std::path::Path;
+use std::sync::Arc;
+use vello_cpu::{Glyph, Image, ImageSource, PaintType, RenderContext, RenderSettings};
+pub use vello_cpu::{Pixmap, RenderMode};
+use winit::event_loop::ActiveEventLoop;
+use winit::window::{Window, WindowId};
+
+/// The scene used by the CPU [VectorGraphicsInterface].
+///
+/// Draw calls are recorded and rasterized when the scene gets rendered.
+pub type Scene = RecordingScene;
+
+/// A vector graphics interface rasterizing on the CPU using [vello_cpu].
+///
+/// Requires the `cpu-vg` feature.
+///
+/// The scene is rasterized into an RGBA [Pixmap], which is presented to the window using [softbuffer].
+/// Additionally, scenes can be rendered without a window using [VectorGraphicsInterface::render_to_pixmap]
+/// and exported as PNG using [VectorGraphicsInterface::to_png].
+///
+/// All windows share the same render context and [Pixmap], while each window has its own surface.
+pub struct VectorGraphicsInterface {
+    config: VectorGraphicsConfig,
+    context: Option<RenderContext>,
+    pixmap: Pixmap,
+    surfaces: Vec<(WindowId, Surface<Arc<Window>, Arc<Window>>)>,
+}
+
+impl VectorGraphicsInterface {
+    /// Rasterizes the given scene with the given size and background color into the internal [Pixmap].
+    ///
+    /// The size is clamped to the range `1..=65535` in both dimensions.
+    ///
+    /// Returns a reference to the rendered [Pixmap].
+    #[tracing::instrument(level = "trace", skip(self, scene))]
+    pub fn render_to_pixmap(
+        &mut self,
+        scene: &Scene,
+        size: Vector2<u32>,
+        bg_color: Color,
+    ) -> &Pixmap {
+        let width = size.x.clamp(1, u16::MAX as u32) as u16;
+        let height = size.y.clamp(1, u16::MAX as u32) as u16;
+
+        let context = match &mut self.context {
+            Some(context) if context.width() == width && context.height() == height => {
+                context.reset();
+                context
+            },
+
+            context => context.insert(RenderContext::new_with(
+                width,
+                height,
+                RenderSettings {
+                    render_mode: self.config.render_mode,
+                    ..Default::default()
+                },
+            )),
+        };
+
+        context.set_paint(bg_color);
+        context.fill_rect(&Rect::new(0.0, 0.0, width as f64, height as f64));
+
+        replay(context, scene.commands(), Affine::IDENTITY);
+
+        context.flush();
+
+        if self.pixmap.width() != width || self.pixmap.height() != height {
+            self.pixmap = Pixmap::new(width, height);
+        }
+
+        context.render_to_pixmap(&mut self.pixmap);
+
+        &self.pixmap
+    }
+
+    /// Returns the last rendered [Pixmap].
+    ///
+    /// The pixel data is in premultiplied RGBA8 format.
+    #[inline(always)]
+    pub const fn pixmap(&self) -> &Pixmap {
+        &self.pixmap
+    }
+
+    /// Returns the last rendered [Pixmap] as [ImageData] in (non-premultiplied) RGBA8 format.
+    #[inline(always)]
+    pub fn to_image_data(&self) -> ImageData {
+        let width = self.pixmap.width() as u32;
+        let height = self.pixmap.height() as u32;
+        let pixels = self.pixmap.clone().take_unpremultiplied();
+
+        ImageData {
+            data: Blob::from(bytemuck::cast_slice::<_, u8>(&pixels).to_vec()),
+            format: ImageFormat::Rgba8,
+            alpha_type: ImageAlphaType::Alpha,
+            width,
+            height,
+        }
+    }
+
+    /// Encodes the last rendered [Pixmap] as PNG.
+    #[inline(always)]
+    pub fn to_png(&self) -> Result<Vec<u8>, Error> {
+        Ok(self.pixmap.clone().into_png()?)
+    }
+
+    /// Encodes the last rendered [Pixmap] as PNG and writes it to the given path.
+    #[inline(always)]
+    pub fn save_png(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        std::fs::write(path, self.to_png()?)?;
+
+        Ok(())
+    }
+}
+
+impl vgi::VectorGraphicsInterface for VectorGraphicsInterface {
+    type Error = Error;
+    type Scene = Scene;
+    type Config = VectorGraphicsConfig;
+
+    #[inline(always)]
+    fn new(config: Self::Config) -> Result<Self, Self::Error>
+    where
+        Self: Sized,
+    {
+        Ok(Self {
+            config,
+            context: None,
+            pixmap: Pixmap::new(1, 1),
+            surfaces: Vec::new(),
+        })
+    }
+
+    #[inline(always)]
+    fn init(&mut self, window: Arc<Window>, _: &ActiveEventLoop) -> Result<(), Self::Error> {
+        let context = softbuffer::Context::new(window.clone())?;
+
+        self.surfaces
+            .push((window.id(), Surface::new(&context, window)?));
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn render(
+        &mut self,
+        window: Arc<Window>,
+        _: &ActiveEventLoop,
+        scene: &Self::Scene,
+        bg_color: Color,
+    ) -> Result<(), Self::Error> {
+        let size = window.inner_size();
+
+        let (Some(width), Some(height)) =
+            (NonZeroU32::new(size.width), NonZeroU32::new(size.height))
+        else {
+            // nothing to present on a minimized window
+            return Ok(());
+        };
+
+        self.render_to_pixmap(scene, Vector2::new(size.width, size.height), bg_color);
+
+        let surface = self
+            .surfaces
+            .iter_mut()
+            .find(|(id, _)| *id == window.id())
+            .map(|(_, surface)| surface)
+            .expect("Vector graphics not initialized yet");
+
+        surface.resize(width, height)?;
+
+        let mut buffer = surface.buffer_mut()?;
+
+        let pixmap_width = self.pixmap.width() as usize;
+
+        // the pixmap might be smaller than the window, if the window exceeds the maximum pixmap size
+        for (y, row) in self
+            .pixmap
+            .data()
+            .chunks_exact(pixmap_width)
+            .enumerate()
+            .take(height.get() as usize)
+        {
+            let offset = y * width.get() as usize;
+
+            for (x, pixel) in row.iter().enumerate().take(width.get() as usize) {
+                buffer[offset + x] =
+                    ((pixel.r as u32) << 16) | ((pixel.g as u32) << 8) | pixel.b as u32;
+            }
+        }
+
+        window.pre_present_notify();
+
+        buffer.present()?;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn resize(
+        &mut self,
+        window: Arc<Window>,
+        _: &ActiveEventLoop,
+        size: Vector2<u32>,
+    ) -> Result<(), Self::Error> {
+        if let (Some((_, surface)), Some(width), Some(height)) = (
+            self.surfaces.iter_mut().find(|(id, _)| *id == window.id()),
+            NonZeroU32::new(size.x),
+            NonZeroU32::new(size.y),
+        ) {
+            surface.resize(width, height)?;
+        }
+
+        Ok(())
+    }
+
+    #[cold]
+    fn uninit(&mut self, window: Arc<Window>, _: &ActiveEventLoop) -> Result<(), Self::Error> {
+        self.surfaces.retain(|(id, _)| *id != window.id());
+
+        Ok(())
+    }
+
+    #[cold]
+    fn destroy(&mut self, window: Arc<Window>, _: &ActiveEventLoop) -> Result<(), Self::Error> {
+        self.surfaces.retain(|(id, _)| *id != window.id());
+
+        // the render context is still in use by the other windows
+        if self.surfaces.is_empty() {
+            self.context = None;
+        }
+
+        Ok(())
+    }
+}
+
+impl Debug for VectorGraphicsInterface {
+    #[inline(always)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VectorGraphicsInterface")
+            .field("config", &self.config)
+            .field("context", &"<hidden>")
+            .field("pixmap", &(self.pixmap.width(), self.pixmap.height()))
+            .field("surfaces", &self.surfaces.len())
+            .finish()
+    }
+}
+
+/// A configuration struct for initializing the [VectorGraphicsInterface].
+///
+/// This struct is passed to [VectorGraphicsInterface::new](vgi::VectorGraphicsInterface::new).
+#[derive(Debug, Copy, Clone, Default)]
+pub struct VectorGraphicsConfig {
+    /// Whether to prioritize speed or quality when rasterizing. Defaults to [RenderMode::OptimizeSpeed].
+    ///
+    /// Use [RenderMode::OptimizeQuality] for pixel-level regression tests.
+    pub render_mode: RenderMode,
+}
+
+/// An error of the CPU [VectorGraphicsInterface].
+#[derive(Debug)]
+pub enum Error {
+    /// The window surface could not be created or presented.
+    Surface(SoftBufferError),
+    /// The pixmap could not be encoded as PNG.
+    Png(png::EncodingError),
+    /// The PNG could not be written.
+    Io(std::io::Error),
+}
+
+impl Display for Error {
+    #[inline(always)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Surface(err) => write!(f, "surface error: {err}"),
+            Error::Png(err) => write!(f, "PNG encoding error: {err}"),
+            Error::Io(err) => write!(f, "IO error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    #[inline(always)]
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Surface(err) => Some(err),
+            Error::Png(err) => Some(err),
+            Error::Io(err) => Some(err),
+        }
+    }
+}
+
+impl From<SoftBufferError> for Error {
+    #[inline(always)]
+    fn from(err: SoftBufferError) -> Self {
+        Self::Surface(err)
+    }
+}
+
+impl From<png::EncodingError> for Error {
+    #[inline(always)]
+    fn from(err: png::EncodingError) -> Self {
+        Self::Png(err)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    #[inline(always)]
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Replays the recorded draw commands onto the render context.
+fn replay(context: &mut RenderContext, commands: &[DrawCommand], base: Affine) {
+    for cmd in commands {
+        match cmd {
+            DrawCommand::Append {
+                transform,
+                commands,
+            } => replay(context, commands, base * transform.unwrap_or_default()),
+
+            DrawCommand::PushLayer {
+                clip,
+                transform,
+                opacity,
+                blend,
+            } => {
+                context.set_transform(base * transform.unwrap_or_default());
+                context.push_layer(Some(clip), Some(*blend), Some(*opacity), None);
+            },
+
+            DrawCommand::PopLayer => context.pop_layer(),
+
+            DrawCommand::Rect {
+                brush,
+                transform,
+                stroke,
+                rect,
+            } => draw_shape(context, base, brush, transform, stroke, rect),
+
+            DrawCommand::RoundedRect {
+                brush,
+                transform,
+                stroke,
+                rect,
+            } => draw_shape(context, base, brush, transform, stroke, rect),
+
+            DrawCommand::Circle {
+                brush,
+                transform,
+                stroke,
+                circle,
+            } => draw_shape(context, base, brush, transform, stroke, circle),
+
+            DrawCommand::CircleSegment {
+                brush,
+                transform,
+                stroke,
+                circle_segment,
+            } => draw_shape(context, base, brush, transform, stroke, circle_segment),
+
+            DrawCommand::Ellipse {
+                brush,
+                transform,
+                stroke,
+                ellipse,
+            } => draw_shape(context, base, brush, transform, stroke, ellipse),
+
+            DrawCommand::CubicBezier {
+                brush,
+                transform,
+                stroke,
+                cubic_bez,
+            } => draw_shape(context, base, brush, transform, stroke, cubic_bez),
+
+            DrawCommand::QuadraticBezier {
+                brush,
+                transform,
+                stroke,
+                quad_bez,
+            } => draw_shape(context, base, brush, transform, stroke, quad_bez),
+
+            DrawCommand::Triangle {
+                brush,
+                transform,
+                stroke,
+                triangle,
+            } => draw_shape(context, base, brush, transform, stroke, triangle),
+
+            DrawCommand::Image {
+                image,
+                transform,
+                position,
+            } => draw_image(context, base, image, transform, *position),
+
+            DrawCommand::Text {
+                brush,
+                transform,
+                position,
+                text,
+                hinting,
+                font,
+                size,
+                line_gap,
+                max_width,
+            } => draw_layout(
+                context,
+                base,
+                brush,
+                transform,
+                *position,
+                &TextLayout::new(
+                    text.as_str(),
+                    std::slice::from_ref(font),
+                    *size,
+                    *line_gap,
+                    *max_width,
+                ),
+                *hinting,
+            ),
+
+            DrawCommand::Layout {
+                brush,
+                transform,
+                position,
+                layout,
+                hinting,
+            } => draw_layout(context, base, brush, transform, *position, layout, *hinting),
+
+            #[cfg(feature = "svg")]
+            DrawCommand::Svg { svg, transform } => {
+                svg::draw_group(context, svg.root(), base * transform.unwrap_or_default())
+            },
+        }
+    }
+}
+
+/// Fills or strokes the given shape.
+#[inline(always)]
+fn draw_shape(
+    context: &mut RenderContext,
+    base: Affine,
+    brush: &Brush,
+    transform: &Option<Affine>,
+    stroke: &Option<Stroke>,
+    shape: &impl Shape,
+) {
+    context.set_transform(base * transform.unwrap_or_default());
+    context.reset_paint_transform();
+    context.set_paint(to_paint(brush));
+
+    let path = shape.to_path(0.1);
+
+    if let Some(stroke) = stroke {
+        context.set_stroke(stroke.clone());
+        context.stroke_path(&path);
+    } else {
+        context.set_fill_rule(Fill::NonZero);
+        context.fill_path(&path);
+    }
+}
+
+/// Draws the given image at the given position.
+#[inline(always)]
+fn draw_image(
+    context: &mut RenderContext,
+    base: Affine,
+    image: &ImageBrush,
+    transform: &Option<Affine>,
+    position: Vector2<f32>,
+) {
+    let transform = transform
+        .unwrap_or_default()
+        .with_translation(Vec2::new(position.x as f64, position.y as f64));
+
+    context.set_transform(base * transform);
+    context.reset_paint_transform();
+    context.set_paint(to_paint(&Brush::Image(image.clone())));
+    context.fill_rect(&Rect::new(
+        0.0,
+        0.0,
+        image.image.width as f64,
+        image.image.height as f64,
+    ));
+}
+
+/// Draws the glyph runs of the given text layout.
+#[inline(always)]
+fn draw_layout(
+    context: &mut RenderContext,
+    base: Affine,
+    brush: &Brush,
+    transform: &Option<Affine>,
+    position: Vector2<f32>,
+    layout: &TextLayout,
+    hinting: bool,
+) {
+    context.set_transform(base * transform.unwrap_or_default());
+    context.reset_paint_transform();
+    context.set_paint(to_paint(brush));
+
+    for run in layout.runs() {
+        context
+            .glyph_run(&run.font)
+            .font_size(layout.font_size())
+            .hint(hinting)
+            .normalized_coords(bytemuck::cast_slice(&run.coords))
+            .fill_glyphs(run.glyphs.iter().map(|glyph| Glyph {
+                id: glyph.id,
+                x: position.x + glyph.x,
+                y: position.y + glyph.y,
+            }));
+    }
+}
+
+/// Converts the given brush into a [PaintType].
+#[inline(always)]
+fn to_paint(brush: &Brush) -> PaintType {
+    match brush {
+        Brush::Solid(color) => PaintType::Solid(*color),
+        Brush::Gradient(gradient) => PaintType::Gradient(gradient.clone()),
+        Brush::Image(image) => PaintType::Image(Image {
+            image: ImageSource::from_peniko_image_data(&image.image),
+            sampler: image.sampler,
+        }),
+    }
+}
+
+/// Rasterizes [usvg] trees.
+///
+/// Supports paths with solid and gradient paints as well as group opacity.
+/// Patterns, clip paths, masks, filters and embedded images are not supported yet.
+#[cfg(feature = "svg")]
+mod svg {
+    use crate::vgi::kurbo::{Affine, BezPath, Cap, Join, Stroke};
+    use peniko::{Color, ColorStop, Extend, Fill, Gradient};
+    use usvg::tiny_skia_path::PathSegment;
+    use usvg::{Group, LineCap, LineJoin, Node, Paint, PaintOrder, Path, SpreadMethod};
+    use vello_cpu::{PaintType, RenderContext};
+
+    /// Draws the given group and its children.
+    pub fn draw_group(context: &mut RenderContext, group: &Group, base: Affine) {
+        let opacity = group.opacity().get();
+
+        if opacity < 1.0 {
+            context.push_opacity_layer(opacity);
+        }
+
+        for node in group.children() {
+            match node {
+                Node::Group(group) => draw_group(context, group, base),
+                Node::Path(path) => draw_path(context, path, base),
+                Node::Text(text) => draw_group(context, text.flattened(), base),
+                Node::Image(_) => tracing::debug!("embedded SVG images are not supported"),
+            }
+        }
+
+        if opacity < 1.0 {
+            context.pop_layer();
+        }
+    }
+
+    /// Fills and strokes the given path.
+    fn draw_path(context: &mut RenderContext, path: &Path, base: Affine) {
+        if !path.is_visible() {
+            return;
+        }
+
+        let bez_path = to_bez_path(path);
+
+        context.set_transform(base * to_affine(path.abs_transform()));
+
+        let fill = |context: &mut RenderContext| {
+            if let Some(fill) = path.fill()
+                && set_paint(context, fill.paint(), fill.opacity().get())
+            {
+                context.set_fill_rule(match fill.rule() {
+                    usvg::FillRule::NonZero => Fill::NonZero,
+                    usvg::FillRule::EvenOdd => Fill::EvenOdd,
+                });
+                context.fill_path(&bez_path);
+            }
+        };
+
+        let stroke = |context: &mut RenderContext| {
+            if let Some(stroke) = path.stroke()
+                && set_paint(context, stroke.paint(), stroke.opacity().get())
+            {
+                let mut style = Stroke::new(stroke.width().get() as f64)
+                    .with_miter_limit(stroke.miterlimit().get() as f64)
+                    .with_join(match stroke.linejoin() {
+                        LineJoin::Miter | LineJoin::MiterClip => Join::Miter,
+                        LineJoin::Round => Join::Round,
+                        LineJoin::Bevel => Join::Bevel,
+                    })
+                    .with_caps(match stroke.linecap() {
+                        LineCap::Butt => Cap::Butt,
+                        LineCap::Round => Cap::Round,
+                        LineCap::Square => Cap::Square,
+                    });
+
+                if let Some(dashes) = stroke.dasharray() {
+                    style = style
+                        .with_dashes(stroke.dashoffset() as f64, dashes.iter().map(|d| *d as f64));
+                }
+
+                context.set_stroke(style);
+                context.stroke_path(&bez_path);
+            }
+        };
+
+        match path.paint_order() {
+            PaintOrder::FillAndStroke => {
+                fill(context);
+                stroke(context);
+            },
+
+            PaintOrder::StrokeAndFill => {
+                stroke(context);
+                fill(context);
+            },
+        }
+    }
+
+    /// Sets the paint of the context. Returns `false`, if the paint is not supported.
+    fn set_paint(context: &mut RenderContext, paint: &Paint, opacity: f32) -> bool {
+        let (paint, transform): (PaintType, Affine) = match paint {
+            Paint::Color(color) => (
+                Color::from_rgba8(color.red, color.green, color.blue, 255)
+                    .multiply_alpha(opacity)
+                    .into(),
+                Affine::IDENTITY,
+            ),
+
+            Paint::LinearGradient(gradient) => (
+                Gradient::new_linear(
+                    (gradient.x1() as f64, gradient.y1() as f64),
+                    (gradient.x2() as f64, gradient.y2() as f64),
+                )
+                .with_extend(to_extend(gradient.spread_method()))
+                .with_stops(to_stops(gradient.stops(), opacity).as_slice())
+                .into(),
+                to_affine(gradient.transform()),
+            ),
+
+            Paint::RadialGradient(gradient) => (
+                Gradient::new_two_point_radial(
+                    (gradient.fx() as f64, gradient.fy() as f64),
+                    0.0,
+                    (gradient.cx() as f64, gradient.cy() as f64),
+                    gradient.r().get(),
+                )
+                .with_extend(to_extend(gradient.spread_method()))
+                .with_stops(to_stops(gradient.stops(), opacity).as_slice())
+                .into(),
+                to_affine(gradient.transform()),
+            ),
+
+            Paint::Pattern(_) => {
+                tracing::debug!("SVG patterns are not supported");
+                return false;
+            },
+        };
+
+        context.set_paint(paint);
+        context.set_paint_transform(transform);
+
+        true
+    }
+
+    /// Converts the gradient stops into color stops.
+    fn to_stops(stops: &[usvg::Stop], opacity: f32) -> Vec<ColorStop> {
+        stops
+            .iter()
+            .map(|stop| {
+                let color = stop.color();
+
+                ColorStop::from((
+                    stop.offset().get(),
+                    Color::from_rgba8(color.red, color.green, color.blue, 255)
+                        .multiply_alpha(stop.opacity().get() * opacity),
+                ))
+            })
+            .collect()
+    }
+
+    /// Converts the spread method into an [Extend].
+    fn to_extend(method: SpreadMethod) -> Extend {
+        match method {
+            SpreadMethod::Pad => Extend::Pad,
+            SpreadMethod::Reflect => Extend::Reflect,
+            SpreadMethod::Repeat => Extend::Repeat,
+        }
+    }
+
+    /// Converts the [usvg::Transform] into an [Affine].
+    fn to_affine(transform: usvg::Transform) -> Affine {
+        Affine::new([
+            transform.sx as f64,
+            transform.ky as f64,
+            transform.kx as f64,
+            transform.sy as f64,
+            transform.tx as f64,
+            transform.ty as f64,
+        ])
+    }
+
+    /// Converts the path data into a [BezPath].
+    fn to_bez_path(path: &Path) -> BezPath {
+        let mut bez_path = BezPath::new();
+
+        for segment in path.data().segments() {
+            match segment {
+                PathSegment::MoveTo(p) => bez_path.move_to((p.x as f64, p.y as f64)),
+                PathSegment::LineTo(p) => bez_path.line_to((p.x as f64, p.y as f64)),
+                PathSegment::QuadTo(p1, p2) => {
+                    bez_path.quad_to((p1.x as f64, p1.y as f64), (p2.x as f64, p2.y as f64))
+                },
+                PathSegment::CubicTo(p1, p2, p3) => bez_path.curve_to(
+                    (p1.x as f64, p1.y as f64),
+                    (p2.x as f64, p2.y as f64),
+                    (p3.x as f64, p3.y as f64),
+                ),
+                PathSegment::Close => bez_path.close_path(),
+            }
+        }
+
+        bez_path
+    }
+}
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use crate::vgi::Scene as _;
+    use crate::vgi::VectorGraphicsInterface as _;
+    use crate::vgi::cpu::{Scene, VectorGraphicsInterface};
+    use crate::vgi::kurbo::{Affine, Rect, Vec2};
+    use nalgebra::Vector2;
+    use peniko::Brush;
+    use peniko::color::palette;
+
+    /// Tests that a scene is rasterized into the pixmap.
+    #[test]
+    fn test_cpu_render_to_pixmap() {
+        let mut vgi = VectorGraphicsInterface::new(Default::default()).unwrap();
+        let mut scene = Scene::new();
+
+        scene.draw_rect(
+            &Brush::Solid(palette::css::RED),
+            None,
+            None,
+            &Rect::new(0.0, 0.0, 4.0, 4.0),
+        );
+
+        let mut child = Scene::new();
+
+        child.draw_rect(
+            &Brush::Solid(palette::css::BLUE),
+            None,
+            None,
+            &Rect::new(0.0, 0.0, 4.0, 4.0),
+        );
+
+        scene.append(&child, Some(Affine::translate(Vec2::new(4.0, 4.0))));
+
+        let pixmap = vgi.render_to_pixmap(&scene, Vector2::new(8, 8), palette::css::WHITE);
+
+        assert_eq!((pixmap.width(), pixmap.height()), (8, 8));
+
+        let red = pixmap.sample(1, 1);
+        let blue = pixmap.sample(6, 6);
+        let white = pixmap.sample(6, 1);
+
+        assert_eq!((red.r, red.g, red.b, red.a), (255, 0, 0, 255));
+        assert_eq!((blue.r, blue.g, blue.b, blue.a), (0, 0, 255, 255));
+        assert_eq!((white.r, white.g, white.b, white.a), (255, 255, 255, 255));
+    }
+
+    /// Tests that drawing is clipped between pushing and popping a clip.
+    #[test]
+    fn test_cpu_render_clip() {
+        let mut vgi = VectorGraphicsInterface::new(Default::default()).unwrap();
+        let mut scene = Scene::new();
+
+        scene.push_clip(None, &Rect::new(0.0, 0.0, 4.0, 8.0));
+
+        scene.draw_rect(
+            &Brush::Solid(palette::css::RED),
+            None,
+            None,
+            &Rect::new(0.0, 0.0, 8.0, 4.0),
+        );
+
+        scene.pop_layer();
+
+        scene.draw_rect(
+            &Brush::Solid(palette::css::BLUE),
+            None,
+            None,
+            &Rect::new(0.0, 4.0, 8.0, 8.0),
+        );
+
+        let pixmap = vgi.render_to_pixmap(&scene, Vector2::new(8, 8), palette::css::WHITE);
+
+        let red = pixmap.sample(1, 1);
+        let clipped = pixmap.sample(6, 1);
+        let blue = pixmap.sample(6, 6);
+
+        assert_eq!((red.r, red.g, red.b, red.a), (255, 0, 0, 255));
+        assert_eq!(
+            (clipped.r, clipped.g, clipped.b, clipped.a),
+            (255, 255, 255, 255)
+        );
+        assert_eq!((blue.r, blue.g, blue.b, blue.a), (0, 0, 255, 255));
+    }
+
+    /// Tests that the rendered pixmap can be encoded as PNG.
+    #[test]
+    fn test_cpu_to_png() {
+        let mut vgi = VectorGraphicsInterface::new(Default::default()).unwrap();
+
+        vgi.render_to_pixmap(&Scene::new(), Vector2::new(16, 16), palette::css::WHITE);
+
+        let png = vgi.to_png().unwrap();
+
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    }
+
+    /// Tests that SVG paths are rasterized.
+    #[test]
+    fn test_cpu_render_svg() {
+        let svg = usvg::Tree::from_str(
+            r##"<svg xmlns="http://www.w3.org/2000/svg" width="8" height="8"><rect x="4" width="4" height="8" fill="#00ff00"/></svg>"##,
+            &usvg::Options::default(),
+        )
+        .unwrap();
+
+        let mut vgi = VectorGraphicsInterface::new(Default::default()).unwrap();
+        let mut scene = Scene::new();
+
+        scene.draw_svg(&svg, None);
+
+        let pixmap = vgi.render_to_pixmap(&scene, Vector2::new(8, 8), palette::css::WHITE);
+
+        let green = pixmap.sample(6, 4);
+        let white = pixmap.sample(1, 4);
+
+        assert_eq!((green.r, green.g, green.b, green.a), (0, 255, 0, 255));
+        assert_eq!((white.r, white.g, white.b, white.a), (255, 255, 255, 255));
+    }
+}
@@ -1,9 +1,11 @@
+use crate::text::layout::TextLayout;
 use crate::vgi::kurbo::{
-    Affine, Circle, CircleSegment, CubicBez, Ellipse, QuadBez, Rect, RoundedRect, Stroke, Triangle,
+    Affine, BezPath, Circle, CircleSegment, CubicBez, Ellipse, QuadBez, Rect, RoundedRect, Stroke,
+    Triangle,
 };
 use crate::vgi::{FontData, Scene, VectorGraphicsInterface};
 use nalgebra::Vector2;
-use peniko::{Brush, Color, ImageBrush};
+use peniko::{BlendMode, Brush, Color, ImageBrush};
 use std::any::Any;
 use std::convert::Infallible;
 use std::fmt::Debug;
@@ -83,7 +85,7 @@ impl Scene for DummyScene {
     where
         Self: Sized,
     {
-        unimplemented!("This is a dummy scene")
+        Self
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -102,6 +104,19 @@ impl Scene for DummyScene {
 
     fn append(&mut self, _other: &dyn Scene, _transform: Option<Affine>) {}
 
+    #[inline(always)]
+    fn push_layer(
+        &mut self,
+        _clip: &BezPath,
+        _transform: Option<Affine>,
+        _opacity: f32,
+        _blend: BlendMode,
+    ) {
+    }
+
+    #[inline(always)]
+    fn pop_layer(&mut self) {}
+
     fn draw_rect(
         &mut self,
         _brush: &Brush,
@@ -196,6 +211,16 @@ impl Scene for DummyScene {
     ) {
     }
 
+    fn draw_layout(
+        &mut self,
+        _brush: &Brush,
+        _transform: Option<Affine>,
+        _position: Vector2<f32>,
+        _layout: &TextLayout,
+        _hinting: bool,
+    ) {
+    }
+
     #[cfg(feature = "svg")]
     fn draw_svg(&mut self, _svg: &usvg::Tree, _transform: Option<Affine>) {}
 }
@@ -6,8 +6,10 @@ use std::sync::Arc;
 use winit::event_loop::ActiveEventLoop;
 use winit::window::Window;
 
+use crate::text::layout::TextLayout;
 use crate::vgi::kurbo::{
-    Affine, Circle, CircleSegment, CubicBez, Ellipse, QuadBez, Rect, RoundedRect, Stroke, Triangle,
+    Affine, BezPath, Circle, CircleSegment, CubicBez, Ellipse, QuadBez, Rect, RoundedRect, Shape,
+    Stroke, Triangle,
 };
 pub use peniko::*;
 
@@ -22,11 +24,24 @@ pub use usvg as svg;
 #[cfg(feature = "vello-vg")]
 pub mod vello;
 
+/// Contains the [cpu::VectorGraphicsInterface] which rasterizes on the CPU using [vello_cpu].
+///
+/// Useful for rendering without a GPU (e.g. on CI machines).
+///
+/// Requires the `cpu-vg` feature.
+#[cfg(feature = "cpu-vg")]
+pub mod cpu;
+
 /// Contains the [dummy::DummyGraphics] & [dummy::DummyGraphics] structs.
 ///
 /// Useful for testing.
 pub mod dummy;
 
+/// Contains the [recording::RecordingScene] which records draw calls.
+///
+/// Useful for snapshot tests.
+pub mod recording;
+
 /// The default graphics backend for maycoon.
 ///
 /// See [vello::VectorGraphicsInterface] for more.
@@ -38,6 +53,9 @@ pub type DefaultGraphics = vello::VectorGraphicsInterface;
 /// A trait describing ways to render vector graphics.
 ///
 /// This is a universal interface for 2D vector graphics rendering.
+///
+/// A single interface is shared by all windows of an application,
+/// so the window-specific methods should only operate on the surface of the given window.
 pub trait VectorGraphicsInterface: Debug + 'static {
     /// The error used by most graphics operations.
     type Error: Error;
@@ -53,9 +71,10 @@ pub trait VectorGraphicsInterface: Debug + 'static {
     where
         Self: Sized;
 
-    /// Initializes the interface.
+    /// Initializes the interface for the given window.
     ///
-    /// This will be called on [winit::event::Event::Resumed] to initialize the graphics interface.
+    /// This will be called on [winit::event::Event::Resumed] to initialize the graphics interface
+    /// and for every newly opened window.
     ///
     /// Returns an [Err] if the interface could not be initialized.
     fn init(
@@ -91,7 +110,7 @@ pub trait VectorGraphicsInterface: Debug + 'static {
         size: Vector2<u32>,
     ) -> Result<(), Self::Error>;
 
-    /// Uninitializes the interface.
+    /// Uninitializes the interface for the given window.
     ///
     /// This will be called on [winit::event::Event::Suspended] to uninitialize the graphics interface.
     ///
@@ -102,9 +121,10 @@ pub trait VectorGraphicsInterface: Debug + 'static {
         event_loop: &ActiveEventLoop,
     ) -> Result<(), Self::Error>;
 
-    /// Destroys the interface.
+    /// Destroys the interface for the given window.
     ///
     /// This will be called on [winit::event::WindowEvent::Destroyed] to destroy the graphics interface.
+    /// Resources shared between windows should only be destroyed with the last window.
     ///
     /// Returns an [Err] if the interface could not be destroyed.
     fn destroy(
@@ -144,6 +164,36 @@ pub trait Scene: 'static {
     /// Apply an optional transform to the scene.
     fn append(&mut self, other: &dyn Scene, transform: Option<Affine>);
 
+    /// Pushes a layer onto the [Scene].
+    ///
+    /// Everything drawn afterward is clipped to the given shape, until [Scene::pop_layer] is called.
+    /// When the layer is popped, its content is composited onto the [Scene]
+    /// with the given opacity (between `0.0` and `1.0`) and blend mode.
+    /// Layers can be nested.
+    ///
+    /// Apply an optional transform to the clip shape.
+    fn push_layer(
+        &mut self,
+        clip: &BezPath,
+        transform: Option<Affine>,
+        opacity: f32,
+        blend: BlendMode,
+    );
+
+    /// Pops the last layer pushed via [Scene::push_layer] or [Scene::push_clip].
+    fn pop_layer(&mut self);
+
+    /// Pushes a layer, which clips everything drawn afterward to the given rectangle, onto the [Scene].
+    ///
+    /// Apply an optional transform to the rectangle.
+    ///
+    /// Shorthand for [Scene::push_layer] with full opacity and the default blend mode.
+    /// The clip must be popped using [Scene::pop_layer].
+    #[inline(always)]
+    fn push_clip(&mut self, transform: Option<Affine>, rect: &Rect) {
+        self.push_layer(&rect.to_path(0.1), transform, 1.0, BlendMode::default());
+    }
+
     /// Draws a rectangle onto the [Scene] with the given brush.
     ///
     /// Apply an optional transform to the rectangle.
@@ -260,6 +310,10 @@ pub trait Scene: 'static {
     /// You can also specify hinting, size, and the line gap.
     ///
     /// Furthermore, you can choose if the text should be wrapped by passing `max_width`.
+    ///
+    /// The text is laid out using a [TextLayout] without fallback fonts and drawn via [Scene::draw_layout].
+    /// Prefer laying out the text yourself, if you need to measure it or draw it more than once.
+    #[inline(always)]
     fn draw_text(
         &mut self,
         brush: &Brush,
@@ -271,6 +325,28 @@ pub trait Scene: 'static {
         size: f32,
         line_gap: f32,
         max_width: f32,
+    ) {
+        self.draw_layout(
+            brush,
+            transform,
+            position,
+            &TextLayout::new(text, std::slice::from_ref(font), size, line_gap, max_width),
+            hinting,
+        );
+    }
+
+    /// Draws a prepared [TextLayout] onto the [Scene] with the given brush.
+    ///
+    /// The top-left corner of the layout is placed at the given position.
+    ///
+    /// Apply an optional transform to the text (after the position is inserted).
+    fn draw_layout(
+        &mut self,
+        brush: &Brush,
+        transform: Option<Affine>,
+        position: Vector2<f32>,
+        layout: &TextLayout,
+        hinting: bool,
     );
 
     // TODO: add `Affine` transform arg as soon as vello_svg supports it
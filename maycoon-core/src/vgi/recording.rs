@@ -0,0 +1,895 @@
+use crate::text::layout::TextLayout;
+use crate::vgi::kurbo::{
+    Affine, BezPath, Circle, CircleSegment, CubicBez, Ellipse, QuadBez, Rect, RoundedRect, Shape,
+    Stroke, Triangle,
+};
+use crate::vgi::{FontData, Scene};
+use nalgebra::Vector2;
+use peniko::{BlendMode, Brush, Color, ImageBrush};
+use std::any::Any;
+use std::fmt::{Display, Formatter, Write};
+
+/// A scene that records every draw call instead of rendering it.
+///
+/// Useful for snapshot tests of widgets without a GPU.
+/// The recorded commands can be inspected via [RecordingScene::commands]
+/// or serialized into a stable text format via [RecordingScene::snapshot].
+#[derive(Clone, Debug, Default)]
+pub struct RecordingScene {
+    commands: Vec<DrawCommand>,
+}
+
+impl RecordingScene {
+    /// Returns the recorded draw commands.
+    #[inline(always)]
+    pub fn commands(&self) -> &[DrawCommand] {
+        &self.commands
+    }
+
+    /// Serializes the recorded draw commands into a stable text format.
+    ///
+    /// Every command is written on its own line, appended scenes are indented.
+    /// Floating point numbers are rounded to two decimal places,
+    /// so the output is suitable for golden-file comparisons.
+    #[inline(always)]
+    pub fn snapshot(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Display for RecordingScene {
+    #[inline(always)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for cmd in &self.commands {
+            cmd.write(f, 0)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Scene for RecordingScene {
+    #[inline(always)]
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        Self::default()
+    }
+
+    #[inline(always)]
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    #[inline(always)]
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    #[inline(always)]
+    fn dyn_clone(&self) -> Box<dyn Scene> {
+        Box::new(self.clone())
+    }
+
+    #[inline(always)]
+    fn reset(&mut self) {
+        self.commands.clear();
+    }
+
+    #[inline(always)]
+    fn append(&mut self, other: &dyn Scene, transform: Option<Affine>) {
+        let commands = other
+            .as_any()
+            .downcast_ref::<RecordingScene>()
+            .map(|scene| scene.commands.clone())
+            .unwrap_or_default();
+
+        self.commands.push(DrawCommand::Append {
+            transform,
+            commands,
+        });
+    }
+
+    #[inline(always)]
+    fn push_layer(
+        &mut self,
+        clip: &BezPath,
+        transform: Option<Affine>,
+        opacity: f32,
+        blend: BlendMode,
+    ) {
+        self.commands.push(DrawCommand::PushLayer {
+            clip: clip.clone(),
+            transform,
+            opacity,
+            blend,
+        });
+    }
+
+    #[inline(always)]
+    fn pop_layer(&mut self) {
+        self.commands.push(DrawCommand::PopLayer);
+    }
+
+    #[inline(always)]
+    fn draw_rect(
+        &mut self,
+        brush: &Brush,
+        transform: Option<Affine>,
+        stroke: Option<&Stroke>,
+        rect: &Rect,
+    ) {
+        self.commands.push(DrawCommand::Rect {
+            brush: brush.clone(),
+            transform,
+            stroke: stroke.cloned(),
+            rect: *rect,
+        });
+    }
+
+    #[inline(always)]
+    fn draw_rounded_rect(
+        &mut self,
+        brush: &Brush,
+        transform: Option<Affine>,
+        stroke: Option<&Stroke>,
+        rect: &RoundedRect,
+    ) {
+        self.commands.push(DrawCommand::RoundedRect {
+            brush: brush.clone(),
+            transform,
+            stroke: stroke.cloned(),
+            rect: *rect,
+        });
+    }
+
+    #[inline(always)]
+    fn draw_circle(
+        &mut self,
+        brush: &Brush,
+        transform: Option<Affine>,
+        stroke: Option<&Stroke>,
+        circle: &Circle,
+    ) {
+        self.commands.push(DrawCommand::Circle {
+            brush: brush.clone(),
+            transform,
+            stroke: stroke.cloned(),
+            circle: *circle,
+        });
+    }
+
+    #[inline(always)]
+    fn draw_circle_segment(
+        &mut self,
+        brush: &Brush,
+        transform: Option<Affine>,
+        stroke: Option<&Stroke>,
+        circle_segment: &CircleSegment,
+    ) {
+        self.commands.push(DrawCommand::CircleSegment {
+            brush: brush.clone(),
+            transform,
+            stroke: stroke.cloned(),
+            circle_segment: *circle_segment,
+        });
+    }
+
+    #[inline(always)]
+    fn draw_ellipse(
+        &mut self,
+        brush: &Brush,
+        transform: Option<Affine>,
+        stroke: Option<&Stroke>,
+        ellipse: &Ellipse,
+    ) {
+        self.commands.push(DrawCommand::Ellipse {
+            brush: brush.clone(),
+            transform,
+            stroke: stroke.cloned(),
+            ellipse: *ellipse,
+        });
+    }
+
+    #[inline(always)]
+    fn draw_cubic_bezier(
+        &mut self,
+        brush: &Brush,
+        transform: Option<Affine>,
+        stroke: Option<&Stroke>,
+        cubic_bez: &CubicBez,
+    ) {
+        self.commands.push(DrawCommand::CubicBezier {
+            brush: brush.clone(),
+            transform,
+            stroke: stroke.cloned(),
+            cubic_bez: *cubic_bez,
+        });
+    }
+
+    #[inline(always)]
+    fn draw_quadratic_bezier(
+        &mut self,
+        brush: &Brush,
+        transform: Option<Affine>,
+        stroke: Option<&Stroke>,
+        quad_bez: &QuadBez,
+    ) {
+        self.commands.push(DrawCommand::QuadraticBezier {
+            brush: brush.clone(),
+            transform,
+            stroke: stroke.cloned(),
+            quad_bez: *quad_bez,
+        });
+    }
+
+    #[inline(always)]
+    fn draw_triangle(
+        &mut self,
+        brush: &Brush,
+        transform: Option<Affine>,
+        stroke: Option<&Stroke>,
+        triangle: &Triangle,
+    ) {
+        self.commands.push(DrawCommand::Triangle {
+            brush: brush.clone(),
+            transform,
+            stroke: stroke.cloned(),
+            triangle: *triangle,
+        });
+    }
+
+    #[inline(always)]
+    fn draw_image(&mut self, img: &ImageBrush, transform: Option<Affine>, position: Vector2<f32>) {
+        self.commands.push(DrawCommand::Image {
+            image: img.clone(),
+            transform,
+            position,
+        });
+    }
+
+    #[inline(always)]
+    fn draw_text(
+        &mut self,
+        brush: &Brush,
+        transform: Option<Affine>,
+        position: Vector2<f32>,
+        text: &str,
+        hinting: bool,
+        font: &FontData,
+        size: f32,
+        line_gap: f32,
+        max_width: f32,
+    ) {
+        self.commands.push(DrawCommand::Text {
+            brush: brush.clone(),
+            transform,
+            position,
+            text: text.to_string(),
+            hinting,
+            font: font.clone(),
+            size,
+            line_gap,
+            max_width,
+        });
+    }
+
+    #[inline(always)]
+    fn draw_layout(
+        &mut self,
+        brush: &Brush,
+        transform: Option<Affine>,
+        position: Vector2<f32>,
+        layout: &TextLayout,
+        hinting: bool,
+    ) {
+        self.commands.push(DrawCommand::Layout {
+            brush: brush.clone(),
+            transform,
+            position,
+            layout: layout.clone(),
+            hinting,
+        });
+    }
+
+    #[inline(always)]
+    #[cfg(feature = "svg")]
+    fn draw_svg(&mut self, svg: &usvg::Tree, transform: Option<Affine>) {
+        self.commands.push(DrawCommand::Svg {
+            svg: svg.clone(),
+            transform,
+        });
+    }
+}
+
+/// A draw call recorded by the [RecordingScene].
+#[derive(Clone, Debug)]
+pub enum DrawCommand {
+    /// A call to [Scene::append]. Contains the commands of the appended scene.
+    ///
+    /// If the appended scene is not a [RecordingScene], the commands are empty.
+    Append {
+        /// The transform of the appended scene.
+        transform: Option<Affine>,
+        /// The commands of the appended scene.
+        commands: Vec<DrawCommand>,
+    },
+    /// A call to [Scene::push_layer].
+    PushLayer {
+        /// The clip shape of the layer.
+        clip: BezPath,
+        /// The transform of the clip shape.
+        transform: Option<Affine>,
+        /// The opacity of the layer.
+        opacity: f32,
+        /// The blend mode of the layer.
+        blend: BlendMode,
+    },
+    /// A call to [Scene::pop_layer].
+    PopLayer,
+    /// A call to [Scene::draw_rect].
+    Rect {
+        /// The brush of the shape.
+        brush: Brush,
+        /// The transform of the shape.
+        transform: Option<Affine>,
+        /// The stroke of the shape. [None] if the shape is filled.
+        stroke: Option<Stroke>,
+        /// The rectangle.
+        rect: Rect,
+    },
+    /// A call to [Scene::draw_rounded_rect].
+    RoundedRect {
+        /// The brush of the shape.
+        brush: Brush,
+        /// The transform of the shape.
+        transform: Option<Affine>,
+        /// The stroke of the shape. [None] if the shape is filled.
+        stroke: Option<Stroke>,
+        /// The rounded rectangle.
+        rect: RoundedRect,
+    },
+    /// A call to [Scene::draw_circle].
+    Circle {
+        /// The brush of the shape.
+        brush: Brush,
+        /// The transform of the shape.
+        transform: Option<Affine>,
+        /// The stroke of the shape. [None] if the shape is filled.
+        stroke: Option<Stroke>,
+        /// The circle.
+        circle: Circle,
+    },
+    /// A call to [Scene::draw_circle_segment].
+    CircleSegment {
+        /// The brush of the shape.
+        brush: Brush,
+        /// The transform of the shape.
+        transform: Option<Affine>,
+        /// The stroke of the shape. [None] if the shape is filled.
+        stroke: Option<Stroke>,
+        /// The circle segment.
+        circle_segment: CircleSegment,
+    },
+    /// A call to [Scene::draw_ellipse].
+    Ellipse {
+        /// The brush of the shape.
+        brush: Brush,
+        /// The transform of the shape.
+        transform: Option<Affine>,
+        /// The stroke of the shape. [None] if the shape is filled.
+        stroke: Option<Stroke>,
+        /// The ellipse.
+        ellipse: Ellipse,
+    },
+    /// A call to [Scene::draw_cubic_bezier].
+    CubicBezier {
+        /// The brush of the shape.
+        brush: Brush,
+        /// The transform of the shape.
+        transform: Option<Affine>,
+        /// The stroke of the shape. [None] if the shape is filled.
+        stroke: Option<Stroke>,
+        /// The cubic bezier.
+        cubic_bez: CubicBez,
+    },
+    /// A call to [Scene::draw_quadratic_bezier].
+    QuadraticBezier {
+        /// The brush of the shape.
+        brush: Brush,
+        /// The transform of the shape.
+        transform: Option<Affine>,
+        /// The stroke of the shape. [None] if the shape is filled.
+        stroke: Option<Stroke>,
+        /// The quadratic bezier.
+        quad_bez: QuadBez,
+    },
+    /// A call to [Scene::draw_triangle].
+    Triangle {
+        /// The brush of the shape.
+        brush: Brush,
+        /// The transform of the shape.
+        transform: Option<Affine>,
+        /// The stroke of the shape. [None] if the shape is filled.
+        stroke: Option<Stroke>,
+        /// The triangle.
+        triangle: Triangle,
+    },
+    /// A call to [Scene::draw_image].
+    Image {
+        /// The image brush.
+        image: ImageBrush,
+        /// The transform of the image.
+        transform: Option<Affine>,
+        /// The position of the image.
+        position: Vector2<f32>,
+    },
+    /// A call to [Scene::draw_text].
+    Text {
+        /// The brush of the text.
+        brush: Brush,
+        /// The transform of the text.
+        transform: Option<Affine>,
+        /// The position of the text.
+        position: Vector2<f32>,
+        /// The text.
+        text: String,
+        /// If hinting is enabled.
+        hinting: bool,
+        /// The font of the text.
+        font: FontData,
+        /// The font size.
+        size: f32,
+        /// The line gap.
+        line_gap: f32,
+        /// The maximum width of a line.
+        max_width: f32,
+    },
+    /// A call to [Scene::draw_layout].
+    Layout {
+        /// The brush of the text.
+        brush: Brush,
+        /// The transform of the text.
+        transform: Option<Affine>,
+        /// The position of the layout.
+        position: Vector2<f32>,
+        /// The text layout.
+        layout: TextLayout,
+        /// If hinting is enabled.
+        hinting: bool,
+    },
+    /// A call to [Scene::draw_svg].
+    #[cfg(feature = "svg")]
+    Svg {
+        /// The SVG tree.
+        svg: usvg::Tree,
+        /// The transform of the SVG.
+        transform: Option<Affine>,
+    },
+}
+
+impl DrawCommand {
+    /// Writes the command and its nested commands with the given indentation level.
+    fn write(&self, f: &mut impl Write, indent: usize) -> std::fmt::Result {
+        write!(f, "{:1$}", "", indent * 2)?;
+
+        match self {
+            DrawCommand::Append {
+                transform,
+                commands,
+            } => {
+                writeln!(f, "append transform={}", FmtAffine(transform))?;
+
+                for cmd in commands {
+                    cmd.write(f, indent + 1)?;
+                }
+
+                Ok(())
+            },
+
+            DrawCommand::PushLayer {
+                clip,
+                transform,
+                opacity,
+                blend,
+            } => {
+                let bounds = clip.bounding_box();
+
+                writeln!(
+                    f,
+                    "push_layer clip=({}) transform={} opacity={:.2} blend={:?}/{:?}",
+                    FmtFloats(&[bounds.x0, bounds.y0, bounds.x1, bounds.y1]),
+                    FmtAffine(transform),
+                    opacity,
+                    blend.mix,
+                    blend.compose,
+                )
+            },
+
+            DrawCommand::PopLayer => writeln!(f, "pop_layer"),
+
+            DrawCommand::Rect {
+                brush,
+                transform,
+                stroke,
+                rect,
+            } => writeln!(
+                f,
+                "rect brush={} transform={} stroke={} rect=({})",
+                FmtBrush(brush),
+                FmtAffine(transform),
+                FmtStroke(stroke),
+                FmtFloats(&[rect.x0, rect.y0, rect.x1, rect.y1]),
+            ),
+
+            DrawCommand::RoundedRect {
+                brush,
+                transform,
+                stroke,
+                rect,
+            } => {
+                let r = rect.rect();
+                let radii = rect.radii();
+
+                writeln!(
+                    f,
+                    "rounded_rect brush={} transform={} stroke={} rect=({}) radii=({})",
+                    FmtBrush(brush),
+                    FmtAffine(transform),
+                    FmtStroke(stroke),
+                    FmtFloats(&[r.x0, r.y0, r.x1, r.y1]),
+                    FmtFloats(&[
+                        radii.top_left,
+                        radii.top_right,
+                        radii.bottom_right,
+                        radii.bottom_left
+                    ]),
+                )
+            },
+
+            DrawCommand::Circle {
+                brush,
+                transform,
+                stroke,
+                circle,
+            } => writeln!(
+                f,
+                "circle brush={} transform={} stroke={} center=({}) radius={:.2}",
+                FmtBrush(brush),
+                FmtAffine(transform),
+                FmtStroke(stroke),
+                FmtFloats(&[circle.center.x, circle.center.y]),
+                circle.radius,
+            ),
+
+            DrawCommand::CircleSegment {
+                brush,
+                transform,
+                stroke,
+                circle_segment,
+            } => writeln!(
+                f,
+                "circle_segment brush={} transform={} stroke={} center=({}) radii=({}) angles=({})",
+                FmtBrush(brush),
+                FmtAffine(transform),
+                FmtStroke(stroke),
+                FmtFloats(&[circle_segment.center.x, circle_segment.center.y]),
+                FmtFloats(&[circle_segment.outer_radius, circle_segment.inner_radius]),
+                FmtFloats(&[circle_segment.start_angle, circle_segment.sweep_angle]),
+            ),
+
+            DrawCommand::Ellipse {
+                brush,
+                transform,
+                stroke,
+                ellipse,
+            } => {
+                let center = ellipse.center();
+                let radii = ellipse.radii();
+
+                writeln!(
+                    f,
+                    "ellipse brush={} transform={} stroke={} center=({}) radii=({}) rotation={:.2}",
+                    FmtBrush(brush),
+                    FmtAffine(transform),
+                    FmtStroke(stroke),
+                    FmtFloats(&[center.x, center.y]),
+                    FmtFloats(&[radii.x, radii.y]),
+                    ellipse.rotation(),
+                )
+            },
+
+            DrawCommand::CubicBezier {
+                brush,
+                transform,
+                stroke,
+                cubic_bez,
+            } => writeln!(
+                f,
+                "cubic_bezier brush={} transform={} stroke={} points=({})",
+                FmtBrush(brush),
+                FmtAffine(transform),
+                FmtStroke(stroke),
+                FmtFloats(&[
+                    cubic_bez.p0.x,
+                    cubic_bez.p0.y,
+                    cubic_bez.p1.x,
+                    cubic_bez.p1.y,
+                    cubic_bez.p2.x,
+                    cubic_bez.p2.y,
+                    cubic_bez.p3.x,
+                    cubic_bez.p3.y
+                ]),
+            ),
+
+            DrawCommand::QuadraticBezier {
+                brush,
+                transform,
+                stroke,
+                quad_bez,
+            } => writeln!(
+                f,
+                "quadratic_bezier brush={} transform={} stroke={} points=({})",
+                FmtBrush(brush),
+                FmtAffine(transform),
+                FmtStroke(stroke),
+                FmtFloats(&[
+                    quad_bez.p0.x,
+                    quad_bez.p0.y,
+                    quad_bez.p1.x,
+                    quad_bez.p1.y,
+                    quad_bez.p2.x,
+                    quad_bez.p2.y
+                ]),
+            ),
+
+            DrawCommand::Triangle {
+                brush,
+                transform,
+                stroke,
+                triangle,
+            } => writeln!(
+                f,
+                "triangle brush={} transform={} stroke={} points=({})",
+                FmtBrush(brush),
+                FmtAffine(transform),
+                FmtStroke(stroke),
+                FmtFloats(&[
+                    triangle.a.x,
+                    triangle.a.y,
+                    triangle.b.x,
+                    triangle.b.y,
+                    triangle.c.x,
+                    triangle.c.y
+                ]),
+            ),
+
+            DrawCommand::Image {
+                image,
+                transform,
+                position,
+            } => writeln!(
+                f,
+                "image size=({}x{}) transform={} position=({})",
+                image.image.width,
+                image.image.height,
+                FmtAffine(transform),
+                FmtFloats(&[position.x as f64, position.y as f64]),
+            ),
+
+            DrawCommand::Text {
+                brush,
+                transform,
+                position,
+                text,
+                hinting,
+                font: _,
+                size,
+                line_gap,
+                max_width,
+            } => writeln!(
+                f,
+                "text brush={} transform={} position=({}) text={:?} hinting={} size={:.2} line_gap={:.2} max_width={:.2}",
+                FmtBrush(brush),
+                FmtAffine(transform),
+                FmtFloats(&[position.x as f64, position.y as f64]),
+                text,
+                hinting,
+                size,
+                line_gap,
+                max_width,
+            ),
+
+            DrawCommand::Layout {
+                brush,
+                transform,
+                position,
+                layout,
+                hinting,
+            } => writeln!(
+                f,
+                "layout brush={} transform={} position=({}) text={:?} hinting={} size={:.2} line_gap={:.2} max_width={:.2} lines={}",
+                FmtBrush(brush),
+                FmtAffine(transform),
+                FmtFloats(&[position.x as f64, position.y as f64]),
+                layout.text(),
+                hinting,
+                layout.font_size(),
+                layout.line_gap(),
+                layout.max_width(),
+                layout.lines().len(),
+            ),
+
+            #[cfg(feature = "svg")]
+            DrawCommand::Svg { svg, transform } => writeln!(
+                f,
+                "svg size=({}) transform={}",
+                FmtFloats(&[svg.size().width() as f64, svg.size().height() as f64]),
+                FmtAffine(transform),
+            ),
+        }
+    }
+}
+
+/// Formats a list of floats with two decimal places, separated by commas.
+struct FmtFloats<'a>(&'a [f64]);
+
+impl Display for FmtFloats<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, v) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+
+            // avoid printing "-0.00"
+            let v = if v.abs() < 0.005 { 0.0 } else { *v };
+
+            write!(f, "{v:.2}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats an optional affine transform.
+struct FmtAffine<'a>(&'a Option<Affine>);
+
+impl Display for FmtAffine<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Some(affine) => write!(f, "affine({})", FmtFloats(&affine.as_coeffs())),
+            None => f.write_str("none"),
+        }
+    }
+}
+
+/// Formats an optional stroke.
+struct FmtStroke<'a>(&'a Option<Stroke>);
+
+impl Display for FmtStroke<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Some(stroke) => write!(f, "{:.2}", stroke.width),
+            None => f.write_str("none"),
+        }
+    }
+}
+
+/// Formats a brush.
+struct FmtBrush<'a>(&'a Brush);
+
+impl Display for FmtBrush<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Brush::Solid(color) => write!(f, "{}", FmtColor(color)),
+            Brush::Gradient(gradient) => {
+                f.write_str("gradient(")?;
+
+                for (i, stop) in gradient.stops.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+
+                    let color: Color = stop.color.to_alpha_color();
+
+                    write!(f, "{:.2} {}", stop.offset, FmtColor(&color))?;
+                }
+
+                f.write_str(")")
+            },
+            Brush::Image(image) => {
+                write!(f, "image({}x{})", image.image.width, image.image.height)
+            },
+        }
+    }
+}
+
+/// Formats a color as `#rrggbbaa`.
+struct FmtColor<'a>(&'a Color);
+
+impl Display for FmtColor<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let rgba = self.0.to_rgba8();
+
+        write!(
+            f,
+            "#{:02x}{:02x}{:02x}{:02x}",
+            rgba.r, rgba.g, rgba.b, rgba.a
+        )
+    }
+}
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use crate::vgi::Scene;
+    use crate::vgi::kurbo::{Affine, Circle, Rect, RoundedRect, Shape, Stroke, Vec2};
+    use crate::vgi::recording::RecordingScene;
+    use peniko::color::palette;
+    use peniko::{BlendMode, Brush, Compose, Mix};
+
+    /// Tests that draw calls are recorded and serialized in order.
+    #[test]
+    fn test_recording_scene_snapshot() {
+        let mut scene = RecordingScene::new();
+
+        scene.draw_rect(
+            &Brush::Solid(palette::css::RED),
+            None,
+            None,
+            &Rect::new(0.0, 0.0, 100.0, 50.0),
+        );
+
+        let mut child = scene.dyn_clone();
+        child.reset();
+
+        child.draw_rounded_rect(
+            &Brush::Solid(palette::css::BLUE),
+            None,
+            Some(&Stroke::new(2.0)),
+            &RoundedRect::new(10.0, 10.0, 20.0, 20.0, 5.0),
+        );
+
+        scene.append(
+            child.as_ref(),
+            Some(Affine::translate(Vec2::new(5.0, -0.001))),
+        );
+
+        assert_eq!(scene.commands().len(), 2);
+        assert_eq!(
+            scene.snapshot(),
+            "rect brush=#ff0000ff transform=none stroke=none rect=(0.00, 0.00, 100.00, 50.00)\n\
+             append transform=affine(1.00, 0.00, 0.00, 1.00, 5.00, 0.00)\n  \
+             rounded_rect brush=#0000ffff transform=none stroke=2.00 rect=(10.00, 10.00, 20.00, 20.00) radii=(5.00, 5.00, 5.00, 5.00)\n"
+        );
+
+        scene.reset();
+
+        assert!(scene.commands().is_empty());
+        assert_eq!(scene.snapshot(), "");
+    }
+
+    /// Tests that layers are recorded with their clip bounds, opacity and blend mode.
+    #[test]
+    fn test_recording_scene_layers() {
+        let mut scene = RecordingScene::new();
+
+        scene.push_layer(
+            &Circle::new((50.0, 50.0), 25.0).to_path(0.1),
+            None,
+            0.5,
+            BlendMode::new(Mix::Multiply, Compose::SrcOver),
+        );
+        scene.push_clip(None, &Rect::new(0.0, 0.0, 40.0, 40.0));
+        scene.pop_layer();
+        scene.pop_layer();
+
+        assert_eq!(
+            scene.snapshot(),
+            "push_layer clip=(25.00, 25.00, 75.00, 75.00) transform=none opacity=0.50 blend=Multiply/SrcOver\n\
+             push_layer clip=(0.00, 0.00, 40.00, 40.00) transform=none opacity=1.00 blend=Normal/SrcOver\n\
+             pop_layer\n\
+             pop_layer\n"
+        );
+    }
+}
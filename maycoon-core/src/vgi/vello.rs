@@ -1,26 +1,23 @@
-use crate::vgi::FontData;
+use crate::text::layout::TextLayout;
 use crate::vgi::kurbo::Stroke;
 use crate::{tasks, vgi};
 use nalgebra::Vector2;
 use peniko::kurbo::{CubicBez, Triangle};
-use peniko::{Brush, Color, Fill, ImageBrush};
-use skrifa::instance::Size;
-use skrifa::setting::VariationSetting;
-use skrifa::{FontRef, MetadataProvider};
+use peniko::{BlendMode, Brush, Color, Fill, ImageBrush};
 use std::any::Any;
 use std::fmt::Debug;
 use std::num::NonZeroUsize;
 use std::sync::Arc;
 pub use vello::AaConfig as Antialiasing;
 use vello::kurbo::{
-    Affine, Circle, CircleSegment, Ellipse, QuadBez, Rect, RoundedRect, Shape, Vec2,
+    Affine, BezPath, Circle, CircleSegment, Ellipse, QuadBez, Rect, RoundedRect, Shape, Vec2,
 };
 use vello::util::{DeviceHandle, RenderContext, RenderSurface};
 use vello::{AaSupport, Error, Glyph, RenderParams, Renderer, RendererOptions};
 pub use wgpu_types::PresentMode;
 use wgpu_types::{CommandEncoderDescriptor, TextureViewDescriptor};
 use winit::event_loop::ActiveEventLoop;
-use winit::window::Window;
+use winit::window::{Window, WindowId};
 
 /// A vector graphics interface using [vello] as the backend.
 ///
@@ -29,14 +26,26 @@ use winit::window::Window;
 /// Requires the `vello-vg` feature (enabled by default).
 ///
 /// Uses [vello] and [wgpu](https://crates.io/crates/wgpu) to render vector graphics.
+///
+/// All windows share the same device and renderer, while each window has its own surface.
 pub struct VectorGraphicsInterface {
     config: VectorGraphicsConfig,
     context: RenderContext,
     renderer: Option<Renderer>,
-    surface: Option<RenderSurface<'static>>,
+    surfaces: Vec<(WindowId, RenderSurface<'static>)>,
     device: usize,
 }
 
+impl VectorGraphicsInterface {
+    /// Removes the surface of the given window and returns whether any surfaces are left.
+    #[inline(always)]
+    fn remove_surface(&mut self, window: WindowId) -> bool {
+        self.surfaces.retain(|(id, _)| *id != window);
+
+        !self.surfaces.is_empty()
+    }
+}
+
 impl vgi::VectorGraphicsInterface for VectorGraphicsInterface {
     type Error = Error;
     type Scene = Scene;
@@ -51,7 +60,7 @@ impl vgi::VectorGraphicsInterface for VectorGraphicsInterface {
             config,
             context: RenderContext::new(),
             renderer: None,
-            surface: None,
+            surfaces: Vec::new(),
             device: 0,
         })
     }
@@ -59,13 +68,21 @@ impl vgi::VectorGraphicsInterface for VectorGraphicsInterface {
     #[inline(always)]
     fn init(&mut self, window: Arc<Window>, _: &ActiveEventLoop) -> Result<(), Self::Error> {
         let size = window.inner_size();
+        let id = window.id();
 
-        self.surface = Some(tasks::block_on(self.context.create_surface(
+        let surface = tasks::block_on(self.context.create_surface(
             window,
             size.width,
             size.height,
             self.config.present_mode,
-        ))?);
+        ))?;
+
+        self.surfaces.push((id, surface));
+
+        // the renderer is shared by all windows
+        if self.renderer.is_some() {
+            return Ok(());
+        }
 
         self.device = (self.config.device_selector)(&self.context.devices);
         let device = &self.context.devices[self.device];
@@ -108,8 +125,10 @@ impl vgi::VectorGraphicsInterface for VectorGraphicsInterface {
             .as_mut()
             .expect("Vector graphics not initialized yet");
         let surface = self
-            .surface
-            .as_ref()
+            .surfaces
+            .iter()
+            .find(|(id, _)| *id == window.id())
+            .map(|(_, surface)| surface)
             .expect("Vector graphics not initialized yet");
         let device_handle = &self.context.devices[self.device];
 
@@ -158,31 +177,40 @@ impl vgi::VectorGraphicsInterface for VectorGraphicsInterface {
     #[inline(always)]
     fn resize(
         &mut self,
-        _: Arc<Window>,
+        window: Arc<Window>,
         _: &ActiveEventLoop,
         size: Vector2<u32>,
     ) -> Result<(), Self::Error> {
-        self.context.resize_surface(
-            self.surface
-                .as_mut()
-                .expect("Vector graphics not initialized yet"),
-            size.x,
-            size.y,
-        );
+        let surface = self
+            .surfaces
+            .iter_mut()
+            .find(|(id, _)| *id == window.id())
+            .map(|(_, surface)| surface)
+            .expect("Vector graphics not initialized yet");
+
+        self.context.resize_surface(surface, size.x, size.y);
 
         Ok(())
     }
 
     #[cold]
-    fn uninit(&mut self, _: Arc<Window>, _: &ActiveEventLoop) -> Result<(), Self::Error> {
-        self.renderer = None;
-        self.surface = None;
+    fn uninit(&mut self, window: Arc<Window>, _: &ActiveEventLoop) -> Result<(), Self::Error> {
+        if !self.remove_surface(window.id()) {
+            self.renderer = None;
+        }
 
         Ok(())
     }
 
     #[cold]
-    fn destroy(&mut self, _: Arc<Window>, _: &ActiveEventLoop) -> Result<(), Self::Error> {
+    fn destroy(&mut self, window: Arc<Window>, _: &ActiveEventLoop) -> Result<(), Self::Error> {
+        // the devices are still in use by the other windows
+        if self.remove_surface(window.id()) {
+            return Ok(());
+        }
+
+        self.renderer = None;
+
         for device in &self.context.devices {
             device.device.destroy();
         }
@@ -198,7 +226,7 @@ impl Debug for VectorGraphicsInterface {
             .field("config", &self.config)
             .field("context", &"<hidden>")
             .field("renderer", &"<hidden>")
-            .field("surface", &self.surface)
+            .field("surfaces", &self.surfaces)
             .field("device", &self.device)
             .finish()
     }
@@ -311,6 +339,23 @@ impl vgi::Scene for Scene {
         self.scene.append(&scene.scene, transform)
     }
 
+    #[inline(always)]
+    fn push_layer(
+        &mut self,
+        clip: &BezPath,
+        transform: Option<Affine>,
+        opacity: f32,
+        blend: BlendMode,
+    ) {
+        self.scene
+            .push_layer(blend, opacity, transform.unwrap_or_default(), clip);
+    }
+
+    #[inline(always)]
+    fn pop_layer(&mut self) {
+        self.scene.pop_layer();
+    }
+
     #[inline(always)]
     fn draw_rect(
         &mut self,
@@ -408,82 +453,31 @@ impl vgi::Scene for Scene {
         self.scene.draw_image(img, transform);
     }
 
-    fn draw_text(
+    fn draw_layout(
         &mut self,
         brush: &Brush,
         transform: Option<Affine>,
         position: Vector2<f32>,
-        text: &str,
+        layout: &TextLayout,
         hinting: bool,
-        font: &FontData,
-        size: f32,
-        line_gap: f32,
-        max_width: f32,
     ) {
-        let font_ref = FontRef::new(font.data.as_ref()).expect("Failed to load font ref");
-
-        let location = font_ref.axes().location::<&[VariationSetting; 0]>(&[]);
-        let metrics = font_ref.metrics(Size::new(size), &location);
-        let glyph_metrics = font_ref.glyph_metrics(Size::new(size), &location);
-        let line_height = metrics.ascent + metrics.descent + metrics.leading;
-        let charmap = font_ref.charmap();
-        let max_pen_x = position.x + max_width;
-
-        let mut pen_x = position.x;
-        let mut pen_y = position.y + size;
-
-        // TODO: use char counting, but more performant than just `text.chars().count()`
-        let mut glyphs = Vec::with_capacity(text.len());
-
-        for word in text.split_inclusive(|c| [' ', '\n'].contains(&c)) {
-            if word.contains('\n') {
-                pen_y += line_height + line_gap;
-                pen_x = position.x;
-                continue;
-            }
-
-            let word_width: f32 = word
-                .chars()
-                .filter_map(|c| {
-                    let gid = charmap.map(c)?;
-                    glyph_metrics.advance_width(gid)
-                })
-                .sum();
-
-            if pen_x + word_width > max_pen_x {
-                pen_y += line_height + line_gap;
-                pen_x = position.x;
-            }
-
-            for c in word.chars() {
-                if c == ' ' {
-                    pen_x += glyph_metrics
-                        .advance_width(charmap.map(' ').unwrap_or_default())
-                        .unwrap_or_default();
-                    continue;
-                }
-
-                let gid = charmap.map(c).unwrap_or_default();
-                let advance = glyph_metrics.advance_width(gid).unwrap_or_default();
-
-                glyphs.push(Glyph {
-                    id: gid.to_u32(),
-                    x: pen_x,
-                    y: pen_y,
-                });
-
-                pen_x += advance;
-            }
+        for run in layout.runs() {
+            self.scene
+                .draw_glyphs(&run.font)
+                .font_size(layout.font_size())
+                .transform(transform.unwrap_or_default())
+                .brush(brush)
+                .normalized_coords(bytemuck::cast_slice(&run.coords))
+                .hint(hinting)
+                .draw(
+                    &peniko::Style::Fill(Fill::NonZero),
+                    run.glyphs.iter().map(|glyph| Glyph {
+                        id: glyph.id,
+                        x: position.x + glyph.x,
+                        y: position.y + glyph.y,
+                    }),
+                );
         }
-
-        self.scene
-            .draw_glyphs(font)
-            .font_size(size)
-            .transform(transform.unwrap_or_default())
-            .brush(brush)
-            .normalized_coords(bytemuck::cast_slice(location.coords()))
-            .hint(hinting)
-            .draw(&peniko::Style::Fill(Fill::NonZero), glyphs.into_iter());
     }
 
     #[inline(always)]
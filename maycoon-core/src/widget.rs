@@ -13,6 +13,9 @@ pub type BoxedWidget = Box<dyn Widget>;
 /// The base trait for all widgets.
 pub trait Widget {
     /// Render the widget to the canvas.
+    ///
+    /// The locations in the [LayoutNode] are absolute, so the widget and its children should be drawn
+    /// at their location without translating the scene by the location of the parent.
     fn render(
         &mut self,
         scene: &mut dyn Scene,
@@ -32,6 +35,36 @@ pub trait Widget {
     fn widget_id(&self) -> WidgetId;
 }
 
+impl<W: Widget + ?Sized> Widget for Box<W> {
+    #[inline(always)]
+    fn render(
+        &mut self,
+        scene: &mut dyn Scene,
+        theme: &mut dyn Theme,
+        layout_node: &LayoutNode,
+        info: &AppInfo,
+        context: AppContext,
+    ) {
+        self.as_mut()
+            .render(scene, theme, layout_node, info, context)
+    }
+
+    #[inline(always)]
+    fn layout_style(&self) -> StyleNode {
+        self.as_ref().layout_style()
+    }
+
+    #[inline(always)]
+    fn update(&mut self, layout: &LayoutNode, context: AppContext, info: &AppInfo) -> Update {
+        self.as_mut().update(layout, context, info)
+    }
+
+    #[inline(always)]
+    fn widget_id(&self) -> WidgetId {
+        self.as_ref().widget_id()
+    }
+}
+
 /// An extension trait for widgets with a single child widget.
 pub trait WidgetChildExt {
     /// Sets the child widget of the widget.
@@ -246,10 +246,13 @@ pub struct DefaultInteractiveStyles {
     inactive: Color,
     hover: Color,
     disabled: Color,
+    focus: Color,
 }
 
 impl DefaultInteractiveStyles {
     /// Create new default interactive styles with given colors.
+    ///
+    /// The focus color is equal to the active color. Use [DefaultInteractiveStyles::with_focus] to change it.
     #[inline(always)]
     pub const fn new(active: Color, inactive: Color, hover: Color, disabled: Color) -> Self {
         Self {
@@ -257,9 +260,17 @@ impl DefaultInteractiveStyles {
             inactive,
             hover,
             disabled,
+            focus: active,
         }
     }
 
+    /// Set the color of the focus ring around focused widgets and return self.
+    #[inline(always)]
+    pub const fn with_focus(mut self, focus: Color) -> Self {
+        self.focus = focus;
+        self
+    }
+
     /// Get the default active widget color.
     #[inline(always)]
     pub const fn active(&self) -> Color {
@@ -283,6 +294,12 @@ impl DefaultInteractiveStyles {
     pub const fn disabled(&self) -> Color {
         self.disabled
     }
+
+    /// Get the default focus ring color.
+    #[inline(always)]
+    pub const fn focus(&self) -> Color {
+        self.focus
+    }
 }
 
 /// A style value.
@@ -35,6 +35,10 @@ impl Theme for CelesteTheme {
                 "Button" => Some(Style::from_values(light::BUTTON)),
                 "Checkbox" => Some(Style::from_values(light::CHECKBOX)),
                 "Slider" => Some(Style::from_values(light::SLIDER)),
+                "TextInput" => Some(Style::from_values(light::TEXT_INPUT)),
+                "TextArea" => Some(Style::from_values(light::TEXT_AREA)),
+                "ScrollView" | "ListView" => Some(Style::from_values(light::SCROLL_VIEW)),
+                "Table" => Some(Style::from_values(light::TABLE)),
                 _ => None,
             },
             _ => None,
@@ -51,7 +55,8 @@ impl Theme for CelesteTheme {
                 Color::from_rgb8(150, 170, 250),
                 Color::from_rgb8(140, 160, 240),
                 Color::from_rgb8(110, 110, 110),
-            ),
+            )
+            .with_focus(Color::from_rgb8(90, 100, 210)),
         )
     }
 
@@ -85,7 +90,7 @@ mod light {
         ("color_invert", StyleVal::Color(palette::css::WHITE)),
     ];
 
-    pub const BUTTON: [(&str, StyleVal); 3] = [
+    pub const BUTTON: [(&str, StyleVal); 4] = [
         (
             "color_idle",
             StyleVal::Color(Color::from_rgb8(150, 170, 250)),
@@ -98,9 +103,13 @@ mod light {
             "color_hovered",
             StyleVal::Color(Color::from_rgb8(140, 160, 240)),
         ),
+        (
+            "color_focus",
+            StyleVal::Color(Color::from_rgb8(90, 100, 210)),
+        ),
     ];
 
-    pub const CHECKBOX: [(&str, StyleVal); 2] = [
+    pub const CHECKBOX: [(&str, StyleVal); 3] = [
         (
             "color_checked",
             StyleVal::Color(Color::from_rgb8(130, 130, 230)),
@@ -109,13 +118,113 @@ mod light {
             "color_unchecked",
             StyleVal::Color(Color::from_rgb8(170, 170, 250)),
         ),
+        (
+            "color_focus",
+            StyleVal::Color(Color::from_rgb8(90, 100, 210)),
+        ),
     ];
 
-    pub const SLIDER: [(&str, StyleVal); 2] = [
+    pub const SLIDER: [(&str, StyleVal); 3] = [
         ("color", StyleVal::Color(Color::from_rgb8(130, 130, 230))),
         (
             "color_ball",
             StyleVal::Color(Color::from_rgb8(170, 170, 250)),
         ),
+        (
+            "color_focus",
+            StyleVal::Color(Color::from_rgb8(90, 100, 210)),
+        ),
+    ];
+
+    pub const TEXT_INPUT: [(&str, StyleVal); 6] = [
+        ("color_background", StyleVal::Color(palette::css::WHITE)),
+        (
+            "color_border",
+            StyleVal::Color(Color::from_rgb8(150, 170, 250)),
+        ),
+        ("color_text", StyleVal::Color(palette::css::BLACK)),
+        (
+            "color_placeholder",
+            StyleVal::Color(Color::from_rgb8(140, 140, 140)),
+        ),
+        (
+            "color_selection",
+            StyleVal::Color(Color::from_rgb8(190, 200, 250)),
+        ),
+        (
+            "color_focus",
+            StyleVal::Color(Color::from_rgb8(90, 100, 210)),
+        ),
+    ];
+
+    pub const TEXT_AREA: [(&str, StyleVal); 6] = [
+        ("color_background", StyleVal::Color(palette::css::WHITE)),
+        (
+            "color_border",
+            StyleVal::Color(Color::from_rgb8(150, 170, 250)),
+        ),
+        ("color_text", StyleVal::Color(palette::css::BLACK)),
+        (
+            "color_line_number",
+            StyleVal::Color(Color::from_rgb8(140, 140, 140)),
+        ),
+        (
+            "color_selection",
+            StyleVal::Color(Color::from_rgb8(190, 200, 250)),
+        ),
+        (
+            "color_focus",
+            StyleVal::Color(Color::from_rgb8(90, 100, 210)),
+        ),
+    ];
+
+    pub const SCROLL_VIEW: [(&str, StyleVal); 3] = [
+        (
+            "color_track",
+            StyleVal::Color(Color::from_rgb8(235, 235, 245)),
+        ),
+        (
+            "color_thumb",
+            StyleVal::Color(Color::from_rgb8(170, 170, 200)),
+        ),
+        (
+            "color_thumb_active",
+            StyleVal::Color(Color::from_rgb8(130, 150, 230)),
+        ),
+    ];
+
+    pub const TABLE: [(&str, StyleVal); 8] = [
+        (
+            "color_header",
+            StyleVal::Color(Color::from_rgb8(235, 235, 245)),
+        ),
+        (
+            "color_border",
+            StyleVal::Color(Color::from_rgb8(215, 215, 230)),
+        ),
+        (
+            "color_hover",
+            StyleVal::Color(Color::from_rgb8(242, 244, 253)),
+        ),
+        (
+            "color_selection",
+            StyleVal::Color(Color::from_rgb8(190, 200, 250)),
+        ),
+        (
+            "color_sort",
+            StyleVal::Color(Color::from_rgb8(90, 100, 210)),
+        ),
+        (
+            "color_track",
+            StyleVal::Color(Color::from_rgb8(235, 235, 245)),
+        ),
+        (
+            "color_thumb",
+            StyleVal::Color(Color::from_rgb8(170, 170, 200)),
+        ),
+        (
+            "color_thumb_active",
+            StyleVal::Color(Color::from_rgb8(130, 150, 230)),
+        ),
     ];
 }
@@ -1,18 +1,29 @@
 use maycoon_core::app::context::AppContext;
+use maycoon_core::app::focus::Focus;
 use maycoon_core::app::info::AppInfo;
+use maycoon_core::app::pointer::{PointerEventKind, PointerId, Propagation};
 use maycoon_core::app::update::Update;
+use maycoon_core::event::{Click, Handler};
 use maycoon_core::layout;
 use maycoon_core::layout::{LayoutNode, LayoutStyle, LengthPercentage, StyleNode};
 use maycoon_core::signal::MaybeSignal;
-use maycoon_core::vgi::kurbo::{Affine, Rect, RoundedRect, RoundedRectRadii, Vec2};
+use maycoon_core::vgi::kurbo::{Rect, RoundedRect, RoundedRectRadii};
 use maycoon_core::vgi::{Brush, Scene};
 use maycoon_core::widget::{BoxedWidget, Widget, WidgetChildExt, WidgetLayoutExt};
-use maycoon_core::window::{ElementState, MouseButton};
+use maycoon_core::window::{CursorIcon, ElementState, Key, MouseButton, NamedKey};
 use maycoon_theme::id::WidgetId;
 use maycoon_theme::theme::Theme;
 
+use crate::focus_ring::draw_focus_ring;
+
 /// An interactive area with a child widget that runs a closure when pressed.
 ///
+/// The `on_pressed` handler is called with a [Click] when the button is released after being pressed.
+///
+/// The button is focusable and can also be pressed by releasing `Space` or `Enter` while focused.
+/// It stops the propagation of button events, so widgets containing the button don't receive its clicks, but can still be scrolled.
+/// The cursor changes to a pointing hand while hovering the button.
+///
 /// See the [counter](https://github.com/maycoon-ui/maycoon/blob/master/examples/counter/src/main.rs) example for how to use it in practice.
 ///
 /// ### Theming
@@ -20,13 +31,16 @@ use maycoon_theme::theme::Theme;
 /// - `color_pressed` -  The color of the button when pressed.
 /// - `color_idle` - The color of the button when not pressed and not hovered (idling).
 /// - `color_hovered` - The color of the button when hovered on.
+/// - `color_focus` - The color of the focus ring, when the button is focused.
 ///
 /// The [WidgetId] is equal to `maycoon-widgets:Button`.
 pub struct Button {
     child: BoxedWidget,
     state: ButtonState,
-    on_pressed: MaybeSignal<Update>,
+    on_pressed: Handler<Click>,
     layout_style: MaybeSignal<LayoutStyle>,
+    focus: Option<Focus>,
+    pointer: PointerId,
 }
 
 impl Button {
@@ -36,7 +50,7 @@ impl Button {
         Self {
             child: Box::new(child),
             state: ButtonState::Idle,
-            on_pressed: MaybeSignal::value(Update::empty()),
+            on_pressed: Handler::none(),
             layout_style: LayoutStyle {
                 padding: layout::Rect::<LengthPercentage> {
                     left: LengthPercentage::length(12.0),
@@ -47,13 +61,18 @@ impl Button {
                 ..Default::default()
             }
             .into(),
+            focus: None,
+            pointer: PointerId::new(),
         }
     }
 
     /// Sets the function to be called when the button is pressed.
     #[inline(always)]
-    pub fn with_on_pressed(mut self, on_pressed: impl Into<MaybeSignal<Update>>) -> Self {
-        self.on_pressed = on_pressed.into();
+    pub fn with_on_pressed(
+        mut self,
+        on_pressed: impl FnMut(&Click, &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_pressed = Handler::new(on_pressed);
         self
     }
 }
@@ -112,27 +131,18 @@ impl Widget for Button {
             ),
         );
 
+        if self
+            .focus
+            .is_some_and(|focus| context.focus().is_focused(focus))
         {
-            theme.globals_mut().invert_text_color = true;
-
-            let mut child_scene = scene.dyn_clone();
-            child_scene.reset();
+            draw_focus_ring(scene, theme, self.widget_id(), &layout_node.layout, 10.0);
+        }
 
-            self.child.render(
-                child_scene.as_mut(),
-                theme,
-                &layout_node.children[0],
-                info,
-                context,
-            );
+        {
+            theme.globals_mut().invert_text_color = true;
 
-            scene.append(
-                child_scene.as_ref(),
-                Some(Affine::translate(Vec2::new(
-                    layout_node.layout.location.x as f64,
-                    layout_node.layout.location.y as f64,
-                ))),
-            );
+            self.child
+                .render(scene, theme, &layout_node.children[0], info, context);
 
             theme.globals_mut().invert_text_color = false;
         }
@@ -143,43 +153,83 @@ impl Widget for Button {
         StyleNode {
             style: self.layout_style.get().clone(),
             children: vec![self.child.layout_style()],
+            measure: None,
         }
     }
 
-    fn update(&mut self, layout: &LayoutNode, _: AppContext, info: &AppInfo) -> Update {
+    fn update(&mut self, layout: &LayoutNode, context: AppContext, info: &AppInfo) -> Update {
         let mut update = Update::empty();
         let old_state = self.state;
+        let focus = context.focus().register();
+
+        self.focus = Some(focus);
+
+        let pointer =
+            context
+                .pointer()
+                .register(self.pointer, &layout.layout, Propagation::StopButtons);
+
+        context
+            .pointer()
+            .set_cursor_icon(pointer, CursorIcon::Pointer);
 
         // check for hovering
-        if let Some(cursor) = info.cursor_pos
-            && layout::intersects(cursor, &layout.layout)
-        {
+        if context.pointer().is_hovered(pointer) {
             // fixes state going to hover if the button is pressed but not yet released
             if self.state != ButtonState::Pressed {
                 self.state = ButtonState::Hovered;
             }
 
             // check for click
-            for (_, btn, el) in &info.buttons {
-                if *btn == MouseButton::Left {
+            for event in context.pointer().events(pointer) {
+                if let PointerEventKind::Button(MouseButton::Left, el) = event.kind {
                     match el {
                         ElementState::Pressed => {
                             self.state = ButtonState::Pressed;
+                            context.focus().request(focus);
                         },
 
                         // actually fire the event if the button is released
                         ElementState::Released => {
                             self.state = ButtonState::Released;
-                            update |= *self.on_pressed.get();
+                            update |= self.on_pressed.call(
+                                &Click::new(event.position, MouseButton::Left, info.modifiers),
+                                &context,
+                            );
                         },
                     }
                 }
             }
         } else {
-            // cursor is not in window, so button is idle
+            // cursor is not on the button, so button is idle
             self.state = ButtonState::Idle;
         }
 
+        // check for keyboard presses while focused
+        for key in context.focus().keys(focus, info) {
+            if !key.repeat
+                && matches!(
+                    key.logical_key,
+                    Key::Named(NamedKey::Space | NamedKey::Enter)
+                )
+            {
+                if key.is_pressed() {
+                    self.state = ButtonState::Pressed;
+                } else {
+                    self.state = ButtonState::Released;
+                    update |= self
+                        .on_pressed
+                        .call(&Click::keyboard(info.modifiers), &context);
+                }
+            }
+        }
+
+        // update the child, so it can measure itself (e.g. shape its text)
+        update |= context.pointer().scope(pointer, || {
+            self.child
+                .update(&layout.children[0], context.clone(), info)
+        });
+
         // update on state change, due to re-coloring
         if old_state != self.state {
             update |= Update::DRAW;
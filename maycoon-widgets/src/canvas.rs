@@ -59,6 +59,7 @@ impl Widget for Canvas {
         StyleNode {
             style: LayoutStyle::default(),
             children: Vec::new(),
+            measure: None,
         }
     }
 
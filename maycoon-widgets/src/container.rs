@@ -1,9 +1,10 @@
 use maycoon_core::app::context::AppContext;
 use maycoon_core::app::info::AppInfo;
 use maycoon_core::app::update::Update;
-use maycoon_core::layout::{LayoutNode, LayoutStyle, StyleNode};
+use maycoon_core::layout::{Layout, LayoutNode, LayoutStyle, Overflow, StyleNode};
 use maycoon_core::signal::MaybeSignal;
 use maycoon_core::vgi::Scene;
+use maycoon_core::vgi::kurbo::Rect;
 use maycoon_core::widget::{BoxedWidget, Widget, WidgetChildrenExt, WidgetLayoutExt};
 use maycoon_theme::id::WidgetId;
 use maycoon_theme::theme::Theme;
@@ -12,6 +13,9 @@ use maycoon_theme::theme::Theme;
 ///
 /// The layout of the children (row, column, etc.) depends on the [LayoutStyle] of the container.
 ///
+/// If the `overflow` of the [LayoutStyle] is not [Overflow::Visible],
+/// the children are clipped to the bounds of the container, so their clipped parts can't be hit by the pointer either.
+///
 /// See the [counter](https://github.com/maycoon-ui/maycoon/blob/master/examples/counter/src/main.rs) example for how to use it in practice.
 ///
 /// ### Theming
@@ -33,6 +37,21 @@ impl Container {
             children: children.into_iter().collect(),
         }
     }
+
+    /// Get the rectangle to clip the children to, if the children overflowing the container are not visible.
+    #[inline(always)]
+    fn clip(&self, layout: &Layout) -> Option<Rect> {
+        if self.style.get().overflow == (Overflow::Visible, Overflow::Visible) {
+            return None;
+        }
+
+        Some(Rect::new(
+            layout.location.x as f64,
+            layout.location.y as f64,
+            (layout.location.x + layout.size.width) as f64,
+            (layout.location.y + layout.size.height) as f64,
+        ))
+    }
 }
 
 impl WidgetChildrenExt for Container {
@@ -64,6 +83,12 @@ impl Widget for Container {
         info: &AppInfo,
         context: AppContext,
     ) {
+        let clip = self.clip(&layout_node.layout);
+
+        if let Some(clip) = &clip {
+            scene.push_clip(None, clip);
+        }
+
         for (i, child) in self.children.iter_mut().enumerate() {
             child.render(
                 scene,
@@ -73,6 +98,10 @@ impl Widget for Container {
                 context.clone(),
             );
         }
+
+        if clip.is_some() {
+            scene.pop_layer();
+        }
     }
 
     #[inline(always)]
@@ -85,15 +114,27 @@ impl Widget for Container {
             children.push(child.layout_style());
         }
 
-        StyleNode { style, children }
+        StyleNode {
+            style,
+            children,
+            measure: None,
+        }
     }
 
     #[inline(always)]
     fn update(&mut self, layout: &LayoutNode, context: AppContext, info: &AppInfo) -> Update {
         let mut update = Update::empty();
+        let clip = self.clip(&layout.layout);
+        let mut update_children = || {
+            for (i, child) in self.children.iter_mut().enumerate() {
+                update.insert(child.update(&layout.children[i], context.clone(), info));
+            }
+        };
 
-        for (i, child) in self.children.iter_mut().enumerate() {
-            update.insert(child.update(&layout.children[i], context.clone(), info));
+        if let Some(clip) = clip {
+            context.pointer().clip(&clip, update_children);
+        } else {
+            update_children();
         }
 
         update
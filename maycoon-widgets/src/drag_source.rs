@@ -0,0 +1,213 @@
+use maycoon_core::app::context::AppContext;
+use maycoon_core::app::info::AppInfo;
+use maycoon_core::app::pointer::{PointerEventKind, PointerId, Propagation};
+use maycoon_core::app::update::Update;
+use maycoon_core::event::Handler;
+use maycoon_core::layout::{LayoutNode, StyleNode};
+use maycoon_core::vgi::kurbo::{Rect, Shape};
+use maycoon_core::vgi::{BlendMode, Scene};
+use maycoon_core::widget::{BoxedWidget, Widget};
+use maycoon_core::window::{ElementState, MouseButton};
+use maycoon_theme::id::WidgetId;
+use maycoon_theme::theme::Theme;
+use nalgebra::Vector2;
+
+/// The distance in pixels the cursor has to move while pressed, before a drag starts.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+/// A widget to drag a payload to a [DropTarget](crate::drop_target::DropTarget).
+/// It contains a child widget, which is the handle to drag.
+///
+/// A drag starts when the child is pressed with the left mouse button and the cursor is moved.
+/// The payload is created by the given function when the drag starts
+/// and passed to the [DragManager](maycoon_core::app::drag::DragManager), until it is dropped by releasing the button.
+/// Pressing escape cancels the drag.
+///
+/// While dragging, the child is drawn translucent at its place and a preview of it follows the cursor.
+///
+/// The [DragSource] has different callbacks that are called on different events:
+/// - `on_drag_start` is called when the drag starts.
+/// - `on_drag_end` is called when the payload is dropped or the drag is cancelled.
+///
+/// See the [drag_and_drop](https://github.com/maycoon-ui/maycoon/blob/master/examples/drag_and_drop/src/main.rs) example for how to use it in practice.
+///
+/// ### Theming
+/// The opacity of the child while dragging is taken from the `opacity_dragging` style property (defaults to `0.5`)
+/// and the opacity of the preview from the `opacity_preview` style property (defaults to `0.8`).
+///
+/// The [WidgetId] is equal to `maycoon-widgets:DragSource`.
+pub struct DragSource<T: 'static> {
+    child: BoxedWidget,
+    payload: Box<dyn Fn() -> T>,
+    on_drag_start: Handler<()>,
+    on_drag_end: Handler<()>,
+    press: Option<Vector2<f32>>,
+    anchor: Option<Vector2<f32>>,
+    pointer: PointerId,
+}
+
+impl<T: 'static> DragSource<T> {
+    /// Creates a new [DragSource] with the given child widget and function to create the payload.
+    #[inline(always)]
+    pub fn new(child: impl Widget + 'static, payload: impl Fn() -> T + 'static) -> Self {
+        Self {
+            child: Box::new(child),
+            payload: Box::new(payload),
+            on_drag_start: Handler::none(),
+            on_drag_end: Handler::none(),
+            press: None,
+            anchor: None,
+            pointer: PointerId::new(),
+        }
+    }
+
+    /// Sets the child widget of the [DragSource] and returns self.
+    #[inline(always)]
+    pub fn with_child(mut self, child: impl Widget + 'static) -> Self {
+        self.child = Box::new(child);
+        self
+    }
+
+    /// Sets the function to create the payload of the [DragSource] and returns self.
+    #[inline(always)]
+    pub fn with_payload(mut self, payload: impl Fn() -> T + 'static) -> Self {
+        self.payload = Box::new(payload);
+        self
+    }
+
+    /// Sets the `on_drag_start` callback of the [DragSource] and returns self.
+    #[inline(always)]
+    pub fn with_on_drag_start(
+        mut self,
+        on_drag_start: impl FnMut(&(), &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_drag_start = Handler::new(on_drag_start);
+        self
+    }
+
+    /// Sets the `on_drag_end` callback of the [DragSource] and returns self.
+    #[inline(always)]
+    pub fn with_on_drag_end(
+        mut self,
+        on_drag_end: impl FnMut(&(), &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_drag_end = Handler::new(on_drag_end);
+        self
+    }
+
+    /// Returns if the payload of the [DragSource] is currently dragged.
+    #[inline(always)]
+    pub const fn is_dragging(&self) -> bool {
+        self.anchor.is_some()
+    }
+}
+
+impl<T: 'static> Widget for DragSource<T> {
+    fn render(
+        &mut self,
+        scene: &mut dyn Scene,
+        theme: &mut dyn Theme,
+        layout_node: &LayoutNode,
+        info: &AppInfo,
+        context: AppContext,
+    ) {
+        let Some(anchor) = self.anchor else {
+            self.child.render(scene, theme, layout_node, info, context);
+            return;
+        };
+
+        let style = theme.of(self.widget_id());
+        let opacity = |name, default| {
+            style
+                .as_ref()
+                .and_then(|style| style.get_float(name))
+                .unwrap_or(default)
+        };
+
+        // the layers shouldn't clip anything drawn outside the child (like focus rings)
+        let clip = Rect::new(0.0, 0.0, info.size.x, info.size.y).to_path(0.1);
+
+        scene.push_layer(
+            &clip,
+            None,
+            opacity("opacity_dragging", 0.5),
+            BlendMode::default(),
+        );
+        self.child
+            .render(scene, theme, layout_node, info, context.clone());
+        scene.pop_layer();
+
+        let mut preview = scene.dyn_clone();
+        preview.reset();
+
+        preview.push_layer(
+            &clip,
+            None,
+            opacity("opacity_preview", 0.8),
+            BlendMode::default(),
+        );
+        self.child
+            .render(preview.as_mut(), theme, layout_node, info, context.clone());
+        preview.pop_layer();
+
+        context.drag().set_preview(preview, anchor);
+    }
+
+    #[inline(always)]
+    fn layout_style(&self) -> StyleNode {
+        self.child.layout_style()
+    }
+
+    fn update(&mut self, layout: &LayoutNode, context: AppContext, info: &AppInfo) -> Update {
+        let mut update = Update::empty();
+        let pointer =
+            context
+                .pointer()
+                .register(self.pointer, &layout.layout, Propagation::Continue);
+
+        for event in context.pointer().events(pointer) {
+            if event.kind == PointerEventKind::Button(MouseButton::Left, ElementState::Pressed) {
+                self.press = Some(event.position);
+            }
+        }
+
+        // the button might be released outside the widget, so the raw input is used
+        let released = info
+            .buttons
+            .iter()
+            .any(|(_, btn, el)| *btn == MouseButton::Left && !el.is_pressed());
+
+        if self.anchor.is_some() {
+            // the drag manager discards the payload after dropping or cancelling it
+            if released || !context.drag().is_dragging() {
+                self.anchor = None;
+                self.press = None;
+
+                update |= self.on_drag_end.call(&(), &context) | Update::DRAW;
+            }
+        } else if let (Some(press), Some(cursor)) = (self.press, info.cursor_pos)
+            && !released
+            && (cursor - press).norm() > DRAG_THRESHOLD
+        {
+            context.drag().start((self.payload)());
+            self.anchor = Some(press);
+
+            update |= self.on_drag_start.call(&(), &context) | Update::DRAW;
+        }
+
+        if released {
+            self.press = None;
+        }
+
+        update |= context
+            .pointer()
+            .scope(pointer, || self.child.update(layout, context.clone(), info));
+
+        update
+    }
+
+    #[inline(always)]
+    fn widget_id(&self) -> WidgetId {
+        WidgetId::new("maycoon-widgets", "DragSource")
+    }
+}
@@ -0,0 +1,199 @@
+use maycoon_core::app::context::AppContext;
+use maycoon_core::app::info::AppInfo;
+use maycoon_core::app::pointer::{PointerId, Propagation};
+use maycoon_core::app::update::Update;
+use maycoon_core::event::Handler;
+use maycoon_core::layout::{LayoutNode, StyleNode};
+use maycoon_core::vgi::kurbo::{Rect, Stroke};
+use maycoon_core::vgi::{Brush, Scene};
+use maycoon_core::widget::{BoxedWidget, Widget};
+use maycoon_theme::id::WidgetId;
+use maycoon_theme::theme::Theme;
+
+/// A widget to accept payloads of the given type, which are dropped onto it.
+/// It contains a child widget.
+///
+/// Payloads are dragged by a [DragSource](crate::drag_source::DragSource)
+/// or any other widget using the [DragManager](maycoon_core::app::drag::DragManager).
+/// Use [Files](maycoon_core::app::drag::Files) as payload type to accept files dropped onto the window by the operating system.
+///
+/// If drop targets are nested, the innermost target accepting the payload receives it.
+///
+/// The [DropTarget] has different callbacks that are called on different events:
+/// - `on_drop` is called with the payload, when it is dropped onto the widget.
+/// - `on_drag_enter` and `on_drag_leave` are called when an acceptable payload is dragged into or out of the widget.
+///
+/// See the [drag_and_drop](https://github.com/maycoon-ui/maycoon/blob/master/examples/drag_and_drop/src/main.rs) example for how to use it in practice.
+///
+/// ### Theming
+/// While an acceptable payload is dragged over the widget, a frame is drawn around it.
+/// The color is taken from the `color_drop` style property,
+/// falling back to [DefaultInteractiveStyles::active](maycoon_theme::style::DefaultInteractiveStyles::active).
+///
+/// The [WidgetId] is equal to `maycoon-widgets:DropTarget`.
+pub struct DropTarget<T: 'static> {
+    child: BoxedWidget,
+    on_drop: Handler<T>,
+    on_drag_enter: Handler<()>,
+    on_drag_leave: Handler<()>,
+    filter: Box<dyn Fn(&T) -> bool>,
+    hovered: bool,
+    pointer: PointerId,
+}
+
+impl<T: 'static> DropTarget<T> {
+    /// Creates a new [DropTarget] with the given child widget and `on_drop` callback.
+    #[inline(always)]
+    pub fn new(
+        child: impl Widget + 'static,
+        on_drop: impl FnMut(&T, &AppContext) -> Update + 'static,
+    ) -> Self {
+        Self {
+            child: Box::new(child),
+            on_drop: Handler::new(on_drop),
+            on_drag_enter: Handler::none(),
+            on_drag_leave: Handler::none(),
+            filter: Box::new(|_| true),
+            hovered: false,
+            pointer: PointerId::new(),
+        }
+    }
+
+    /// Sets the child widget of the [DropTarget] and returns self.
+    #[inline(always)]
+    pub fn with_child(mut self, child: impl Widget + 'static) -> Self {
+        self.child = Box::new(child);
+        self
+    }
+
+    /// Sets the `on_drop` callback of the [DropTarget] and returns self.
+    #[inline(always)]
+    pub fn with_on_drop(
+        mut self,
+        on_drop: impl FnMut(&T, &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_drop = Handler::new(on_drop);
+        self
+    }
+
+    /// Sets the `on_drag_enter` callback of the [DropTarget] and returns self.
+    #[inline(always)]
+    pub fn with_on_drag_enter(
+        mut self,
+        on_drag_enter: impl FnMut(&(), &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_drag_enter = Handler::new(on_drag_enter);
+        self
+    }
+
+    /// Sets the `on_drag_leave` callback of the [DropTarget] and returns self.
+    #[inline(always)]
+    pub fn with_on_drag_leave(
+        mut self,
+        on_drag_leave: impl FnMut(&(), &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_drag_leave = Handler::new(on_drag_leave);
+        self
+    }
+
+    /// Sets the filter of the [DropTarget] and returns self.
+    ///
+    /// Only payloads, for which the filter returns `true`, are accepted.
+    /// By default, all payloads of the given type are accepted.
+    #[inline(always)]
+    pub fn with_filter(mut self, filter: impl Fn(&T) -> bool + 'static) -> Self {
+        self.filter = Box::new(filter);
+        self
+    }
+
+    /// Returns if an acceptable payload is currently dragged over the [DropTarget].
+    #[inline(always)]
+    pub const fn is_hovered(&self) -> bool {
+        self.hovered
+    }
+}
+
+impl<T: 'static> Widget for DropTarget<T> {
+    fn render(
+        &mut self,
+        scene: &mut dyn Scene,
+        theme: &mut dyn Theme,
+        layout_node: &LayoutNode,
+        info: &AppInfo,
+        context: AppContext,
+    ) {
+        self.child.render(scene, theme, layout_node, info, context);
+
+        if self.hovered {
+            let color = theme
+                .of(self.widget_id())
+                .and_then(|style| style.get_color("color_drop"))
+                .unwrap_or_else(|| theme.defaults().interactive().active());
+
+            let layout = &layout_node.layout;
+
+            scene.draw_rect(
+                &Brush::Solid(color),
+                None,
+                Some(&Stroke::new(2.0)),
+                &Rect::new(
+                    layout.location.x as f64 + 1.0,
+                    layout.location.y as f64 + 1.0,
+                    (layout.location.x + layout.size.width) as f64 - 1.0,
+                    (layout.location.y + layout.size.height) as f64 - 1.0,
+                ),
+            );
+        }
+    }
+
+    #[inline(always)]
+    fn layout_style(&self) -> StyleNode {
+        self.child.layout_style()
+    }
+
+    fn update(&mut self, layout: &LayoutNode, context: AppContext, info: &AppInfo) -> Update {
+        let pointer =
+            context
+                .pointer()
+                .register(self.pointer, &layout.layout, Propagation::Continue);
+
+        // targets inside this widget may accept the payload first
+        let mut update = context
+            .pointer()
+            .scope(pointer, || self.child.update(layout, context.clone(), info));
+
+        let drag = context.drag();
+        let hovered = context.pointer().is_hovered(pointer)
+            && drag
+                .with_payload(|payload: &T| (self.filter)(payload))
+                .unwrap_or(false);
+
+        if hovered
+            && drag.is_dropped()
+            && let Some(payload) = drag.accept::<T>()
+        {
+            update |= self.on_drop.call(&payload, &context);
+        }
+
+        // the payload is gone after dropping it
+        let hovered = hovered && drag.is_dragging();
+
+        if hovered != self.hovered {
+            self.hovered = hovered;
+
+            update |= Update::DRAW
+                | if hovered {
+                    self.on_drag_enter.call(&(), &context)
+                } else {
+                    self.on_drag_leave.call(&(), &context)
+                };
+        }
+
+        update
+    }
+
+    #[inline(always)]
+    fn widget_id(&self) -> WidgetId {
+        WidgetId::new("maycoon-widgets", "DropTarget")
+    }
+}
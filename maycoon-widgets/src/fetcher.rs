@@ -120,6 +120,7 @@ impl<T: Send + 'static, W: Widget> Widget for WidgetFetcher<T, W> {
             StyleNode {
                 style: LayoutStyle::default(),
                 children: Vec::new(),
+                measure: None,
             }
         }
     }
@@ -0,0 +1,40 @@
+use maycoon_core::layout::Layout;
+use maycoon_core::vgi::kurbo::{Rect, RoundedRect, RoundedRectRadii, Stroke};
+use maycoon_core::vgi::{Brush, Scene};
+use maycoon_theme::id::WidgetId;
+use maycoon_theme::theme::Theme;
+
+/// The distance between the widget bounds and the focus ring.
+const OFFSET: f64 = 3.0;
+
+/// Draws a ring around the given layout to indicate that the widget is focused.
+///
+/// The color is taken from the `color_focus` style property of the widget,
+/// falling back to [DefaultInteractiveStyles::focus](maycoon_theme::style::DefaultInteractiveStyles::focus).
+pub(crate) fn draw_focus_ring(
+    scene: &mut dyn Scene,
+    theme: &dyn Theme,
+    id: WidgetId,
+    layout: &Layout,
+    radius: f64,
+) {
+    let color = theme
+        .of(id)
+        .and_then(|style| style.get_color("color_focus"))
+        .unwrap_or_else(|| theme.defaults().interactive().focus());
+
+    scene.draw_rounded_rect(
+        &Brush::Solid(color),
+        None,
+        Some(&Stroke::new(2.0)),
+        &RoundedRect::from_rect(
+            Rect::new(
+                layout.location.x as f64 - OFFSET,
+                layout.location.y as f64 - OFFSET,
+                (layout.location.x + layout.size.width) as f64 + OFFSET,
+                (layout.location.y + layout.size.height) as f64 + OFFSET,
+            ),
+            RoundedRectRadii::from_single_radius(radius + OFFSET),
+        ),
+    );
+}
@@ -1,22 +1,51 @@
 use maycoon_core::app::context::AppContext;
+use maycoon_core::app::gesture::{
+    GestureRecognizer, Pan, PanRecognizer, Pinch, PinchRecognizer, Rotation, RotationRecognizer,
+};
 use maycoon_core::app::info::AppInfo;
+use maycoon_core::app::pointer::{Pointer, PointerEventKind, PointerId, Propagation};
 use maycoon_core::app::update::Update;
-use maycoon_core::layout;
+use maycoon_core::event::{Click, Handler};
 use maycoon_core::layout::{LayoutNode, StyleNode};
-use maycoon_core::signal::MaybeSignal;
+use maycoon_core::platform::time::Instant;
 use maycoon_core::vgi::Scene;
 use maycoon_core::widget::{BoxedWidget, Widget};
-use maycoon_core::window::{ElementState, MouseButton};
+use maycoon_core::window::{ElementState, MouseButton, MouseScrollDelta, TouchPhase};
 use maycoon_theme::id::WidgetId;
 use maycoon_theme::theme::Theme;
+use nalgebra::Vector2;
+use std::time::Duration;
 
-/// A widget to detect gestures like pressing or releasing the left mouse button.
+/// The distance in pixels the cursor has to move while pressed, before a drag starts.
+///
+/// Clicks further apart than this distance are not counted as double or triple clicks.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+/// A widget to detect gestures like clicking, dragging or scrolling.
 /// It can also contain a child widget.
 ///
 /// The [GestureDetector] has different callbacks that are called on different events:
-/// - `on_press` is called when the left mouse button is pressed.
-/// - `on_release` is called when the left mouse button is released.
-/// - `on_hover` is called when the mouse cursor hovers over the widget.
+/// - `on_press` is called with a [Click], when the left mouse button is pressed.
+/// - `on_release` is called with a [Click], when the left mouse button is released.
+/// - `on_hover` is called with the cursor position, when the mouse cursor hovers over the widget.
+/// - `on_hover_enter` and `on_hover_leave` are called when the mouse cursor enters or leaves the widget.
+/// - `on_double_click` and `on_triple_click` are called when the left mouse button is clicked two or three times
+///   in a row, each click within the click interval of the previous one.
+/// - `on_long_press` is called when the left mouse button is held for the long press duration without dragging.
+/// - `on_secondary_press` and `on_secondary_release` are called for the secondary (usually right) mouse button.
+/// - `on_middle_press` and `on_middle_release` are called for the middle mouse button.
+/// - `on_drag_start`, `on_drag_update` and `on_drag_end` are called with a [Drag], when the cursor is moved
+///   while the left mouse button is pressed. The drag continues outside the widget, until the button is released.
+/// - `on_scroll` is called with the scroll delta, when the mouse wheel or touchpad is scrolled over the widget.
+/// - `on_pan`, `on_pinch` and `on_rotate` are called with a [Pan], [Pinch] or [Rotation], when the fingers touching the widget
+///   move on a touchscreen or the platform recognizes the gesture on a touchpad, while hovering the widget.
+///   The fingers are followed outside the widget, until they are lifted.
+///
+/// Pointer events are routed by the [PointerManager](maycoon_core::app::pointer::PointerManager),
+/// so the detector only receives events that are not stopped by widgets inside it (like buttons).
+/// By default, it lets the events propagate to widgets containing it. Use [GestureDetector::with_propagation] to change this.
+///
+/// See the [gesture_detector](https://github.com/maycoon-ui/maycoon/blob/master/examples/gesture_detector/src/main.rs) example for how to use it in practice.
 ///
 /// ### Theming
 /// The [GestureDetector] should not be themed and does not draw anything on itself.
@@ -25,9 +54,37 @@ use maycoon_theme::theme::Theme;
 /// The [WidgetId] is equal to `maycoon-widgets:GestureDetector`.
 pub struct GestureDetector {
     child: BoxedWidget,
-    on_press: MaybeSignal<Update>,
-    on_release: MaybeSignal<Update>,
-    on_hover: MaybeSignal<Update>,
+    on_press: Handler<Click>,
+    on_release: Handler<Click>,
+    on_hover: Handler<Vector2<f32>>,
+    on_hover_enter: Handler<Vector2<f32>>,
+    on_hover_leave: Handler<()>,
+    on_double_click: Handler<Click>,
+    on_triple_click: Handler<Click>,
+    on_long_press: Handler<Click>,
+    on_secondary_press: Handler<Click>,
+    on_secondary_release: Handler<Click>,
+    on_middle_press: Handler<Click>,
+    on_middle_release: Handler<Click>,
+    on_drag_start: Handler<Drag>,
+    on_drag_update: Handler<Drag>,
+    on_drag_end: Handler<Drag>,
+    on_scroll: Handler<MouseScrollDelta>,
+    on_pan: Handler<Pan>,
+    on_pinch: Handler<Pinch>,
+    on_rotate: Handler<Rotation>,
+    click_interval: Duration,
+    long_press_duration: Duration,
+    propagation: Propagation,
+    hovered: bool,
+    press: Option<Press>,
+    drag: Option<Drag>,
+    clicks: Option<Clicks>,
+    touches: Vec<u64>,
+    pan: PanRecognizer,
+    pinch: PinchRecognizer,
+    rotation: RotationRecognizer,
+    pointer: PointerId,
 }
 
 impl GestureDetector {
@@ -36,9 +93,37 @@ impl GestureDetector {
     pub fn new(child: impl Widget + 'static) -> Self {
         Self {
             child: Box::new(child),
-            on_press: MaybeSignal::value(Update::empty()),
-            on_release: MaybeSignal::value(Update::empty()),
-            on_hover: MaybeSignal::value(Update::empty()),
+            on_press: Handler::none(),
+            on_release: Handler::none(),
+            on_hover: Handler::none(),
+            on_hover_enter: Handler::none(),
+            on_hover_leave: Handler::none(),
+            on_double_click: Handler::none(),
+            on_triple_click: Handler::none(),
+            on_long_press: Handler::none(),
+            on_secondary_press: Handler::none(),
+            on_secondary_release: Handler::none(),
+            on_middle_press: Handler::none(),
+            on_middle_release: Handler::none(),
+            on_drag_start: Handler::none(),
+            on_drag_update: Handler::none(),
+            on_drag_end: Handler::none(),
+            on_scroll: Handler::none(),
+            on_pan: Handler::none(),
+            on_pinch: Handler::none(),
+            on_rotate: Handler::none(),
+            click_interval: Duration::from_millis(500),
+            long_press_duration: Duration::from_millis(500),
+            propagation: Propagation::Continue,
+            hovered: false,
+            press: None,
+            drag: None,
+            clicks: None,
+            touches: Vec::new(),
+            pan: PanRecognizer::new(),
+            pinch: PinchRecognizer::new(),
+            rotation: RotationRecognizer::new(),
+            pointer: PointerId::new(),
         }
     }
 
@@ -51,41 +136,365 @@ impl GestureDetector {
 
     /// Sets the `on_press` callback of the [GestureDetector] and returns self.
     #[inline(always)]
-    pub fn with_on_press(mut self, on_press: impl Into<MaybeSignal<Update>>) -> Self {
-        self.on_press = on_press.into();
+    pub fn with_on_press(
+        mut self,
+        on_press: impl FnMut(&Click, &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_press = Handler::new(on_press);
         self
     }
 
     /// Sets the `on_release` callback of the [GestureDetector] and returns self.
     #[inline(always)]
-    pub fn with_on_release(mut self, on_release: impl Into<MaybeSignal<Update>>) -> Self {
-        self.on_release = on_release.into();
+    pub fn with_on_release(
+        mut self,
+        on_release: impl FnMut(&Click, &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_release = Handler::new(on_release);
         self
     }
 
     /// Sets the `on_hover` callback of the [GestureDetector] and returns self.
     #[inline(always)]
-    pub fn with_on_hover(mut self, on_hover: impl Into<MaybeSignal<Update>>) -> Self {
-        self.on_hover = on_hover.into();
+    pub fn with_on_hover(
+        mut self,
+        on_hover: impl FnMut(&Vector2<f32>, &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_hover = Handler::new(on_hover);
+        self
+    }
+
+    /// Sets the `on_hover_enter` callback of the [GestureDetector] and returns self.
+    #[inline(always)]
+    pub fn with_on_hover_enter(
+        mut self,
+        on_hover_enter: impl FnMut(&Vector2<f32>, &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_hover_enter = Handler::new(on_hover_enter);
+        self
+    }
+
+    /// Sets the `on_hover_leave` callback of the [GestureDetector] and returns self.
+    #[inline(always)]
+    pub fn with_on_hover_leave(
+        mut self,
+        on_hover_leave: impl FnMut(&(), &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_hover_leave = Handler::new(on_hover_leave);
+        self
+    }
+
+    /// Sets the `on_double_click` callback of the [GestureDetector] and returns self.
+    #[inline(always)]
+    pub fn with_on_double_click(
+        mut self,
+        on_double_click: impl FnMut(&Click, &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_double_click = Handler::new(on_double_click);
         self
     }
 
-    /// Call the `on_hover` callback of the [GestureDetector].
+    /// Sets the `on_triple_click` callback of the [GestureDetector] and returns self.
     #[inline(always)]
-    pub fn on_hover(&mut self) -> Update {
-        *self.on_hover.get()
+    pub fn with_on_triple_click(
+        mut self,
+        on_triple_click: impl FnMut(&Click, &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_triple_click = Handler::new(on_triple_click);
+        self
     }
 
-    /// Call the `on_press` callback of the [GestureDetector].
+    /// Sets the maximum time between two clicks of a double or triple click and returns self.
+    /// Defaults to 500 milliseconds.
     #[inline(always)]
-    pub fn on_press(&mut self) -> Update {
-        *self.on_press.get()
+    pub fn with_click_interval(mut self, interval: Duration) -> Self {
+        self.click_interval = interval;
+        self
     }
 
-    /// Call the `on_release` callback of the [GestureDetector].
+    /// Sets the `on_long_press` callback of the [GestureDetector] and returns self.
     #[inline(always)]
-    pub fn on_release(&mut self) -> Update {
-        *self.on_release.get()
+    pub fn with_on_long_press(
+        mut self,
+        on_long_press: impl FnMut(&Click, &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_long_press = Handler::new(on_long_press);
+        self
+    }
+
+    /// Sets the time the left mouse button has to be held for a long press and returns self.
+    /// Defaults to 500 milliseconds.
+    #[inline(always)]
+    pub fn with_long_press_duration(mut self, duration: Duration) -> Self {
+        self.long_press_duration = duration;
+        self
+    }
+
+    /// Sets the `on_secondary_press` callback of the [GestureDetector] and returns self.
+    #[inline(always)]
+    pub fn with_on_secondary_press(
+        mut self,
+        on_secondary_press: impl FnMut(&Click, &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_secondary_press = Handler::new(on_secondary_press);
+        self
+    }
+
+    /// Sets the `on_secondary_release` callback of the [GestureDetector] and returns self.
+    #[inline(always)]
+    pub fn with_on_secondary_release(
+        mut self,
+        on_secondary_release: impl FnMut(&Click, &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_secondary_release = Handler::new(on_secondary_release);
+        self
+    }
+
+    /// Sets the `on_middle_press` callback of the [GestureDetector] and returns self.
+    #[inline(always)]
+    pub fn with_on_middle_press(
+        mut self,
+        on_middle_press: impl FnMut(&Click, &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_middle_press = Handler::new(on_middle_press);
+        self
+    }
+
+    /// Sets the `on_middle_release` callback of the [GestureDetector] and returns self.
+    #[inline(always)]
+    pub fn with_on_middle_release(
+        mut self,
+        on_middle_release: impl FnMut(&Click, &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_middle_release = Handler::new(on_middle_release);
+        self
+    }
+
+    /// Sets the `on_drag_start` callback of the [GestureDetector] and returns self.
+    #[inline(always)]
+    pub fn with_on_drag_start(
+        mut self,
+        on_drag_start: impl FnMut(&Drag, &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_drag_start = Handler::new(on_drag_start);
+        self
+    }
+
+    /// Sets the `on_drag_update` callback of the [GestureDetector] and returns self.
+    #[inline(always)]
+    pub fn with_on_drag_update(
+        mut self,
+        on_drag_update: impl FnMut(&Drag, &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_drag_update = Handler::new(on_drag_update);
+        self
+    }
+
+    /// Sets the `on_drag_end` callback of the [GestureDetector] and returns self.
+    #[inline(always)]
+    pub fn with_on_drag_end(
+        mut self,
+        on_drag_end: impl FnMut(&Drag, &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_drag_end = Handler::new(on_drag_end);
+        self
+    }
+
+    /// Sets the `on_scroll` callback of the [GestureDetector] and returns self.
+    #[inline(always)]
+    pub fn with_on_scroll(
+        mut self,
+        on_scroll: impl FnMut(&MouseScrollDelta, &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_scroll = Handler::new(on_scroll);
+        self
+    }
+
+    /// Sets the `on_pan` callback of the [GestureDetector] and returns self.
+    #[inline(always)]
+    pub fn with_on_pan(
+        mut self,
+        on_pan: impl FnMut(&Pan, &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_pan = Handler::new(on_pan);
+        self
+    }
+
+    /// Sets the `on_pinch` callback of the [GestureDetector] and returns self.
+    #[inline(always)]
+    pub fn with_on_pinch(
+        mut self,
+        on_pinch: impl FnMut(&Pinch, &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_pinch = Handler::new(on_pinch);
+        self
+    }
+
+    /// Sets the `on_rotate` callback of the [GestureDetector] and returns self.
+    #[inline(always)]
+    pub fn with_on_rotate(
+        mut self,
+        on_rotate: impl FnMut(&Rotation, &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_rotate = Handler::new(on_rotate);
+        self
+    }
+
+    /// Sets whether pointer events propagate to the widgets containing the [GestureDetector] and returns self.
+    #[inline(always)]
+    pub fn with_propagation(mut self, propagation: Propagation) -> Self {
+        self.propagation = propagation;
+        self
+    }
+
+    /// Count the given click and call the double or triple click callback.
+    fn click(&mut self, click: &Click, context: &AppContext) -> Update {
+        let position = click.position.unwrap_or_default();
+
+        let count = match self.clicks {
+            Some(clicks)
+                if clicks.count < 3
+                    && clicks.time.elapsed() <= self.click_interval
+                    && (position - clicks.position).norm() <= DRAG_THRESHOLD =>
+            {
+                clicks.count + 1
+            },
+
+            _ => 1,
+        };
+
+        self.clicks = Some(Clicks {
+            time: Instant::now(),
+            position,
+            count,
+        });
+
+        match count {
+            2 => self.on_double_click.call(click, context),
+            3 => self.on_triple_click.call(click, context),
+            _ => Update::empty(),
+        }
+    }
+
+    /// Start, update or end the drag and detect long presses, while the left mouse button is pressed.
+    fn update_press(&mut self, context: &AppContext, info: &AppInfo) -> Update {
+        let mut update = Update::empty();
+
+        let Some(press) = &mut self.press else {
+            return update;
+        };
+
+        // the button might be released outside the widget, so the raw input is used
+        let released = info
+            .buttons
+            .iter()
+            .rfind(|(_, btn, _)| *btn == MouseButton::Left)
+            .is_some_and(|(_, _, el)| !el.is_pressed());
+
+        if let Some(cursor) = info.cursor_pos {
+            match &mut self.drag {
+                Some(drag) if drag.position != cursor => {
+                    drag.delta = cursor - drag.position;
+                    drag.position = cursor;
+
+                    update |= self.on_drag_update.call(drag, context);
+                },
+
+                None if (cursor - press.position).norm() > DRAG_THRESHOLD => {
+                    let drag = Drag {
+                        start: press.position,
+                        position: cursor,
+                        delta: cursor - press.position,
+                    };
+
+                    self.drag = Some(drag);
+
+                    update |= self.on_drag_start.call(&drag, context);
+                },
+
+                _ => (),
+            }
+        }
+
+        if !released && self.drag.is_none() && !press.long {
+            if press.time.elapsed() >= self.long_press_duration {
+                press.long = true;
+
+                update |= self.on_long_press.call(
+                    &Click::new(press.position, MouseButton::Left, info.modifiers),
+                    context,
+                );
+            } else {
+                // keep updating until the long press duration is reached
+                update |= Update::EVAL;
+            }
+        }
+
+        if released {
+            self.press = None;
+
+            if let Some(drag) = self.drag.take() {
+                update |= self.on_drag_end.call(
+                    &Drag {
+                        delta: Vector2::zeros(),
+                        ..drag
+                    },
+                    context,
+                );
+            }
+        }
+
+        update
+    }
+
+    /// Feed the touch events of the fingers touching the widget and the platform gestures to the recognizers.
+    fn update_touches(&mut self, pointer: Pointer, context: &AppContext, info: &AppInfo) -> Update {
+        let mut update = Update::empty();
+
+        for touch in &info.touches {
+            if touch.phase == TouchPhase::Started && context.pointer().hits(pointer, touch.position)
+            {
+                self.touches.push(touch.id);
+            } else if !self.touches.contains(&touch.id) {
+                continue;
+            }
+
+            if matches!(touch.phase, TouchPhase::Ended | TouchPhase::Cancelled) {
+                self.touches.retain(|id| *id != touch.id);
+            }
+
+            for pan in self.pan.touch(touch) {
+                update |= self.on_pan.call(&pan, context);
+            }
+
+            for pinch in self.pinch.touch(touch) {
+                update |= self.on_pinch.call(&pinch, context);
+            }
+
+            for rotation in self.rotation.touch(touch) {
+                update |= self.on_rotate.call(&rotation, context);
+            }
+        }
+
+        if let Some(cursor) = info.cursor_pos
+            && context.pointer().is_hovered(pointer)
+        {
+            for gesture in &info.gestures {
+                if let Some(pan) = self.pan.platform(gesture, cursor) {
+                    update |= self.on_pan.call(&pan, context);
+                }
+
+                if let Some(pinch) = self.pinch.platform(gesture, cursor) {
+                    update |= self.on_pinch.call(&pinch, context);
+                }
+
+                if let Some(rotation) = self.rotation.platform(gesture, cursor) {
+                    update |= self.on_rotate.call(&rotation, context);
+                }
+            }
+        }
+
+        update
     }
 }
 
@@ -109,29 +518,87 @@ impl Widget for GestureDetector {
 
     fn update(&mut self, layout: &LayoutNode, context: AppContext, info: &AppInfo) -> Update {
         let mut update = Update::empty();
+        let pointer = context
+            .pointer()
+            .register(self.pointer, &layout.layout, self.propagation);
+        let hovered = context.pointer().is_hovered(pointer);
+
+        if hovered != self.hovered {
+            self.hovered = hovered;
+
+            update |= match info.cursor_pos {
+                Some(cursor) if hovered => self.on_hover_enter.call(&cursor, &context),
+                _ => self.on_hover_leave.call(&(), &context),
+            };
+        }
 
         if let Some(cursor) = info.cursor_pos
-            && layout::intersects(cursor, &layout.layout)
+            && hovered
         {
-            update |= self.on_hover();
-
-            // check for click
-            for (_, btn, el) in &info.buttons {
-                if *btn == MouseButton::Left {
-                    match el {
-                        ElementState::Pressed => {
-                            update |= self.on_press();
-                        },
-
-                        ElementState::Released => {
-                            update |= self.on_release();
-                        },
+            update |= self.on_hover.call(&cursor, &context);
+        }
+
+        for event in context.pointer().events(pointer) {
+            let click = |button| Click::new(event.position, button, info.modifiers);
+
+            match event.kind {
+                PointerEventKind::Button(MouseButton::Left, ElementState::Pressed) => {
+                    self.press = Some(Press {
+                        position: event.position,
+                        time: Instant::now(),
+                        long: false,
+                    });
+
+                    update |= self.on_press.call(&click(MouseButton::Left), &context);
+                },
+
+                PointerEventKind::Button(MouseButton::Left, ElementState::Released) => {
+                    update |= self.on_release.call(&click(MouseButton::Left), &context);
+
+                    // dragging or long pressing is not clicking
+                    if self
+                        .press
+                        .as_ref()
+                        .is_some_and(|press| !press.long && self.drag.is_none())
+                    {
+                        update |= self.click(&click(MouseButton::Left), &context);
                     }
-                }
+                },
+
+                PointerEventKind::Button(MouseButton::Right, el) => {
+                    let click = click(MouseButton::Right);
+
+                    update |= if el.is_pressed() {
+                        self.on_secondary_press.call(&click, &context)
+                    } else {
+                        self.on_secondary_release.call(&click, &context)
+                    };
+                },
+
+                PointerEventKind::Button(MouseButton::Middle, el) => {
+                    let click = click(MouseButton::Middle);
+
+                    update |= if el.is_pressed() {
+                        self.on_middle_press.call(&click, &context)
+                    } else {
+                        self.on_middle_release.call(&click, &context)
+                    };
+                },
+
+                PointerEventKind::Scroll(delta) => {
+                    update |= self.on_scroll.call(&delta, &context);
+                },
+
+                _ => (),
             }
         }
 
-        update |= self.child.update(layout, context, info);
+        update |= self.update_press(&context, info);
+        update |= self.update_touches(pointer, &context, info);
+
+        update |= context
+            .pointer()
+            .scope(pointer, || self.child.update(layout, context.clone(), info));
 
         update
     }
@@ -141,3 +608,44 @@ impl Widget for GestureDetector {
         WidgetId::new("maycoon-widgets", "GestureDetector")
     }
 }
+
+/// A drag gesture of a [GestureDetector].
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Drag {
+    /// The cursor position, where the left mouse button was pressed.
+    pub start: Vector2<f32>,
+    /// The current cursor position.
+    pub position: Vector2<f32>,
+    /// The movement of the cursor since the last drag event.
+    pub delta: Vector2<f32>,
+}
+
+impl Drag {
+    /// Get the total movement of the cursor since the drag started.
+    #[inline(always)]
+    pub fn offset(&self) -> Vector2<f32> {
+        self.position - self.start
+    }
+}
+
+/// A press of the left mouse button on a [GestureDetector].
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct Press {
+    /// The cursor position, where the button was pressed.
+    position: Vector2<f32>,
+    /// The time the button was pressed.
+    time: Instant,
+    /// If the press already was a long press.
+    long: bool,
+}
+
+/// The last click on a [GestureDetector], to detect double and triple clicks.
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct Clicks {
+    /// The time of the click.
+    time: Instant,
+    /// The cursor position of the click.
+    position: Vector2<f32>,
+    /// The number of clicks in a row.
+    count: usize,
+}
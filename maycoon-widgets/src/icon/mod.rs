@@ -71,6 +71,7 @@ impl Widget for Icon {
         StyleNode {
             style: self.layout_style.get().clone(),
             children: Vec::new(),
+            measure: None,
         }
     }
 
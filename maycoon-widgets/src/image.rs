@@ -1,16 +1,19 @@
 use maycoon_core::app::context::AppContext;
 use maycoon_core::app::info::AppInfo;
 use maycoon_core::app::update::Update;
-use maycoon_core::layout::{LayoutNode, LayoutStyle, StyleNode};
+use maycoon_core::layout::{LayoutNode, LayoutStyle, MeasureFn, StyleNode};
 use maycoon_core::signal::MaybeSignal;
 use maycoon_core::vgi::{ImageBrush, ImageData, Scene};
 use maycoon_core::widget::{Widget, WidgetLayoutExt};
 use maycoon_theme::id::WidgetId;
 use maycoon_theme::theme::Theme;
 use nalgebra::Vector2;
+use std::rc::Rc;
 
 /// An image widget. Pretty self-explanatory.
 ///
+/// Unless the size is set in the layout style, the widget takes the natural size of the image.
+///
 /// See the [image](https://github.com/maycoon-ui/maycoon/blob/master/examples/image/src/main.rs) example for how to use it in practice.
 ///
 /// ### Theming
@@ -20,6 +23,7 @@ use nalgebra::Vector2;
 pub struct Image {
     image: MaybeSignal<ImageData>,
     style: MaybeSignal<LayoutStyle>,
+    measure: Option<(Vector2<f32>, MeasureFn)>,
 }
 
 impl Image {
@@ -29,6 +33,7 @@ impl Image {
         Self {
             image: image.into(),
             style: LayoutStyle::default().into(),
+            measure: None,
         }
     }
 
@@ -72,12 +77,29 @@ impl Widget for Image {
         StyleNode {
             style: self.style.get().clone(),
             children: Vec::new(),
+            measure: self.measure.as_ref().map(|(_, measure)| measure.clone()),
         }
     }
 
-    #[inline(always)]
     fn update(&mut self, _: &LayoutNode, _: AppContext, _: &AppInfo) -> Update {
-        Update::empty()
+        let image = self.image.get();
+        let size = Vector2::new(image.width as f32, image.height as f32);
+
+        if self.measure.as_ref().is_some_and(|(old, _)| *old == size) {
+            return Update::empty();
+        }
+
+        // keep the aspect ratio, if only one dimension is known
+        let measure: MeasureFn = Rc::new(move |known, _| match (known.x, known.y) {
+            (Some(width), Some(height)) => Vector2::new(width, height),
+            (Some(width), None) if size.x > 0.0 => Vector2::new(width, width * size.y / size.x),
+            (None, Some(height)) if size.y > 0.0 => Vector2::new(height * size.x / size.y, height),
+            _ => Vector2::new(known.x.unwrap_or(size.x), known.y.unwrap_or(size.y)),
+        });
+
+        self.measure = Some((size, measure));
+
+        Update::LAYOUT | Update::DRAW
     }
 
     #[inline(always)]
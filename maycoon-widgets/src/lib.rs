@@ -32,7 +32,7 @@ pub mod fetcher;
 #[cfg(feature = "canvas")]
 pub mod canvas;
 
-/// Contains the [gesture_detector::GestureDetector] widget.
+/// Contains the [gesture_detector::GestureDetector] widget and associated structures.
 pub mod gesture_detector;
 
 /// Contains the [icon::Icon] widget.
@@ -41,3 +41,39 @@ pub mod icon;
 
 /// Contains the [animator::Animator] widget and associated structures.
 pub mod animator;
+
+/// Contains the [text_input::TextInput] widget.
+pub mod text_input;
+
+/// Contains the [text_area::TextArea] widget.
+pub mod text_area;
+
+/// Contains the [scroll_view::ScrollView] widget.
+pub mod scroll_view;
+
+/// Contains the [list_view::ListView] widget and associated structures.
+pub mod list_view;
+
+/// Contains the [table::Table] widget and associated structures.
+pub mod table;
+
+/// Contains the [drag_source::DragSource] widget.
+pub mod drag_source;
+
+/// Contains the [drop_target::DropTarget] widget.
+pub mod drop_target;
+
+/// Contains the [shortcuts::Shortcuts] widget.
+pub mod shortcuts;
+
+/// Contains the focus ring drawn around focused widgets.
+mod focus_ring;
+
+/// Contains the editing state shared by text widgets.
+mod text_edit;
+
+/// Contains the scrollbars shared by scrollable widgets.
+mod scrollbar;
+
+/// Contains the row recycling shared by virtualized widgets.
+mod recycler;
@@ -0,0 +1,614 @@
+use maycoon_core::app::context::AppContext;
+use maycoon_core::app::info::AppInfo;
+use maycoon_core::app::update::Update;
+use maycoon_core::layout;
+use maycoon_core::layout::{
+    Dimension, FlexDirection, LayoutNode, LayoutStyle, LengthPercentageAuto, Overflow, Position,
+    StyleNode,
+};
+use maycoon_core::signal::state::StateSignal;
+use maycoon_core::signal::{MaybeSignal, Signal};
+use maycoon_core::vgi::Scene;
+use maycoon_core::vgi::kurbo::Rect;
+use maycoon_core::widget::{BoxedWidget, Widget, WidgetLayoutExt};
+use maycoon_theme::id::WidgetId;
+use maycoon_theme::theme::Theme;
+use nalgebra::Vector2;
+
+use crate::recycler::Recycler;
+use crate::scrollbar::{Scrollbars, Scroller};
+
+/// A scrollable list, which only builds, lays out and renders the visible rows.
+///
+/// Use this instead of a [Container](crate::container::Container) inside a [ScrollView](crate::scroll_view::ScrollView)
+/// for large data sets: The update and render cost only depends on the number of visible rows
+/// (plus a few rows above and below, see [ListView::with_overscan]) and not on the number of items.
+///
+/// Rows are built by the item builder, which gets a signal containing the index of the item to display.
+/// When the list scrolls, rows that are scrolled out of view are recycled by setting their index signal
+/// to the index of a newly visible item, so the builder is only called for the first visible rows.
+/// Row widgets should therefore derive their content from the index signal.
+///
+/// The height of the rows can either be fixed or measured from the row layout (see [RowHeight]).
+///
+/// The list scrolls vertically using the mouse wheel or the scrollbar and fills its parent by default.
+/// The thickness of the scrollbar is the `scrollbar_width` of the [LayoutStyle].
+///
+/// See the [list_view](https://github.com/maycoon-ui/maycoon/blob/master/examples/list_view/src/main.rs) example for how to use it in practice.
+///
+/// ### Theming
+/// You can style the scrollbar of the list using following properties:
+/// - `color_track` - The color of the scrollbar track.
+/// - `color_thumb` - The color of the scrollbar thumb.
+/// - `color_thumb_active` - The color of the scrollbar thumb while it's dragged.
+///
+/// The [WidgetId] is equal to `maycoon-widgets:ListView`.
+pub struct ListView {
+    count: MaybeSignal<usize>,
+    builder: Box<dyn Fn(StateSignal<usize>) -> BoxedWidget>,
+    style: MaybeSignal<LayoutStyle>,
+    row_height: RowHeight,
+    overscan: usize,
+    offset: MaybeSignal<f32>,
+    scroll_step: f32,
+    scroller: Scroller,
+    heights: RowHeights,
+    rows: Recycler<BoxedWidget>,
+}
+
+impl ListView {
+    /// Creates a new list view with the given item count and item builder.
+    #[inline(always)]
+    pub fn new<W: Widget + 'static>(
+        count: impl Into<MaybeSignal<usize>>,
+        builder: impl Fn(StateSignal<usize>) -> W + 'static,
+    ) -> Self {
+        Self {
+            count: count.into(),
+            builder: Box::new(move |index| Box::new(builder(index))),
+            style: LayoutStyle {
+                size: Vector2::new(Dimension::percent(1.0), Dimension::percent(1.0)),
+                scrollbar_width: 10.0,
+                ..Default::default()
+            }
+            .into(),
+            row_height: RowHeight::Measured(30.0),
+            overscan: 3,
+            offset: 0.0.into(),
+            scroll_step: 40.0,
+            scroller: Scroller::default(),
+            heights: RowHeights::default(),
+            rows: Recycler::new(),
+        }
+    }
+
+    /// Sets the item count and returns itself.
+    #[inline(always)]
+    pub fn with_count(mut self, count: impl Into<MaybeSignal<usize>>) -> Self {
+        self.count = count.into();
+        self
+    }
+
+    /// Sets the height of the rows and returns itself. Defaults to [RowHeight::Measured] with an estimate of `30.0`.
+    #[inline(always)]
+    pub fn with_row_height(mut self, row_height: RowHeight) -> Self {
+        self.row_height = row_height;
+        self.heights = RowHeights::default();
+        self
+    }
+
+    /// Sets the number of rows to build above and below the visible rows and returns itself. Defaults to `3`.
+    ///
+    /// A higher overscan reduces the work done while scrolling, but increases the work done per frame.
+    #[inline(always)]
+    pub fn with_overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    /// Sets the vertical scroll offset and returns itself.
+    ///
+    /// If the offset is a signal, it's updated whenever the list scrolls.
+    /// Setting the signal scrolls the list to the given offset, which is clamped to the scrollable area.
+    #[inline(always)]
+    pub fn with_offset(mut self, offset: impl Into<MaybeSignal<f32>>) -> Self {
+        self.offset = offset.into();
+        self
+    }
+
+    /// Sets the distance in pixels to scroll per line of the mouse wheel and returns itself. Defaults to `40.0`.
+    #[inline(always)]
+    pub fn with_scroll_step(mut self, step: f32) -> Self {
+        self.scroll_step = step;
+        self
+    }
+
+    /// Set the scroll offset. The offset is only stored locally, if it's not a signal.
+    #[inline(always)]
+    fn set_offset(&mut self, offset: f32) {
+        if let Some(sig) = self.offset.as_signal() {
+            sig.set(offset);
+        } else {
+            self.offset = offset.into();
+        }
+    }
+
+    /// Get the top position of the row with the given index, relative to the top of the list.
+    #[inline(always)]
+    fn top(&self, index: usize) -> f32 {
+        match self.row_height {
+            RowHeight::Fixed(height) => index as f32 * height,
+            RowHeight::Measured(_) => self.heights.top(index),
+        }
+    }
+
+    /// Get the index of the row at the given position, relative to the top of the list.
+    #[inline(always)]
+    fn index_at(&self, y: f32, count: usize) -> usize {
+        let index = match self.row_height {
+            RowHeight::Fixed(height) if height > 0.0 => (y / height).max(0.0) as usize,
+            RowHeight::Fixed(_) => 0,
+            RowHeight::Measured(_) => self.heights.index_at(y),
+        };
+
+        index.min(count.saturating_sub(1))
+    }
+}
+
+impl WidgetLayoutExt for ListView {
+    #[inline(always)]
+    fn set_layout_style(&mut self, layout_style: impl Into<MaybeSignal<LayoutStyle>>) {
+        self.style = layout_style.into();
+    }
+}
+
+impl Widget for ListView {
+    fn render(
+        &mut self,
+        scene: &mut dyn Scene,
+        theme: &mut dyn Theme,
+        layout_node: &LayoutNode,
+        info: &AppInfo,
+        context: AppContext,
+    ) {
+        let count = *self.count.get();
+        let bars = self.scrollbars(&layout_node.layout, count);
+        let offset = bars.clamp(Vector2::new(0.0, *self.offset.get()));
+        let shift = Vector2::new(0.0, self.top(self.rows.range().start) - offset.y);
+
+        scene.push_clip(None, &bars.viewport);
+
+        for (row, node) in self.rows.rows_mut().iter_mut().zip(&layout_node.children) {
+            row.item.render(
+                scene,
+                theme,
+                &node.children[0].translated(shift),
+                info,
+                context.clone(),
+            );
+        }
+
+        scene.pop_layer();
+
+        self.scroller
+            .render(scene, theme, self.widget_id(), &bars, offset);
+    }
+
+    fn layout_style(&self) -> StyleNode {
+        let style = self.style.get();
+        let first = self.top(self.rows.range().start);
+
+        let height = match self.row_height {
+            RowHeight::Fixed(height) => Dimension::length(height),
+            RowHeight::Measured(_) => Dimension::auto(),
+        };
+
+        // rows are positioned relative to the first built row and moved by the scroll offset when rendering
+        let children = self
+            .rows
+            .rows()
+            .iter()
+            .map(|row| StyleNode {
+                style: LayoutStyle {
+                    position: Position::Absolute,
+                    inset: layout::Rect {
+                        left: LengthPercentageAuto::length(0.0),
+                        right: LengthPercentageAuto::length(style.scrollbar_width),
+                        top: LengthPercentageAuto::length(self.top(*row.index.get()) - first),
+                        bottom: LengthPercentageAuto::auto(),
+                    },
+                    size: Vector2::new(Dimension::auto(), height),
+                    flex_direction: FlexDirection::Column,
+                    ..Default::default()
+                },
+                children: vec![row.item.layout_style()],
+                measure: None,
+            })
+            .collect();
+
+        StyleNode {
+            style: LayoutStyle {
+                overflow: (Overflow::Hidden, Overflow::Hidden),
+                ..style.clone()
+            },
+            children,
+            measure: None,
+        }
+    }
+
+    fn update(&mut self, layout: &LayoutNode, context: AppContext, info: &AppInfo) -> Update {
+        let mut update = Update::empty();
+        let count = *self.count.get();
+        let old_offset = *self.offset.get();
+        let mut offset = old_offset;
+
+        if let RowHeight::Measured(estimate) = self.row_height {
+            self.heights.resize(count, estimate);
+
+            // measure the built rows and keep the visible rows in place, if rows above change their height
+            for (row, node) in self.rows.rows().iter().zip(&layout.children) {
+                let index = *row.index.get();
+
+                if index >= count {
+                    continue;
+                }
+
+                let height = node.layout.size.height;
+                let old_height = self.heights.get(index);
+
+                if !layout::equal(height, old_height) {
+                    if self.heights.top(index) < offset {
+                        offset += height - old_height;
+                    }
+
+                    self.heights.set(index, height);
+                    update.insert(Update::LAYOUT);
+                }
+            }
+        }
+
+        let bars = self.scrollbars(&layout.layout, count);
+
+        // scroll before updating the rows, so they register their hit regions at the new offset
+        let (pointer, new_offset, redraw) = self.scroller.update(
+            &bars,
+            &layout.layout,
+            Vector2::new(0.0, offset),
+            self.scroll_step,
+            &context,
+            info,
+        );
+
+        if redraw {
+            update.insert(Update::DRAW);
+        }
+
+        let shift = Vector2::new(0.0, self.top(self.rows.range().start) - new_offset.y);
+
+        context.pointer().scope(pointer, || {
+            context.pointer().clip(&bars.viewport, || {
+                for (row, node) in self.rows.rows_mut().iter_mut().zip(&layout.children) {
+                    update.insert(row.item.update(
+                        &node.children[0].translated(shift),
+                        context.clone(),
+                        info,
+                    ));
+                }
+            })
+        });
+
+        // build the visible rows
+        let range = if count == 0 {
+            0..0
+        } else {
+            let first = self.index_at(new_offset.y, count);
+            let last = self.index_at(new_offset.y + bars.size().y, count);
+
+            first.saturating_sub(self.overscan)..(last + 1 + self.overscan).min(count)
+        };
+
+        if self.rows.build(range, |index| index, &self.builder) {
+            // recycled rows registered their hit regions at the position of their previous item
+            context.pointer().invalidate();
+            update.insert(Update::EVAL | Update::LAYOUT | Update::DRAW);
+        }
+
+        if new_offset.y != old_offset {
+            self.set_offset(new_offset.y);
+
+            // update again, so the hover state of the rows follows the new offset
+            update.insert(Update::EVAL | Update::DRAW);
+        }
+
+        update
+    }
+
+    #[inline(always)]
+    fn widget_id(&self) -> WidgetId {
+        WidgetId::new("maycoon-widgets", "ListView")
+    }
+}
+
+impl ListView {
+    /// Compute the scrollbar geometry from the layout of the list.
+    fn scrollbars(&self, layout: &layout::Layout, count: usize) -> Scrollbars {
+        let width = self.style.get().scrollbar_width;
+
+        Scrollbars::new(
+            Rect::new(
+                layout.location.x as f64,
+                layout.location.y as f64,
+                (layout.location.x + layout.size.width - width) as f64,
+                (layout.location.y + layout.size.height) as f64,
+            ),
+            Vector2::new(width, 0.0),
+            Vector2::new(0.0, self.top(count)),
+        )
+    }
+}
+
+/// The height of the rows of a [ListView].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum RowHeight {
+    /// All rows have the given height.
+    ///
+    /// This is the fastest option, since rows don't need to be measured.
+    Fixed(f32),
+    /// The rows are measured after they have been laid out.
+    ///
+    /// Rows that have not been measured yet are assumed to have the given estimated height.
+    Measured(f32),
+}
+
+/// The measured row heights of a [ListView].
+///
+/// The heights are stored in a Fenwick tree,
+/// so positions can be computed in logarithmic time, independent of the number of rows.
+#[derive(Default)]
+struct RowHeights {
+    /// The height of every row.
+    heights: Vec<f32>,
+    /// The Fenwick tree. The element `i` contains the sum of the heights in `(i + 1 - lowbit(i + 1), i]`.
+    tree: Vec<f32>,
+}
+
+impl RowHeights {
+    /// Resize to the given number of rows. New rows get the given height.
+    fn resize(&mut self, count: usize, height: f32) {
+        if count < self.heights.len() {
+            // the sums of the remaining elements do not depend on the removed elements
+            self.heights.truncate(count);
+            self.tree.truncate(count);
+        }
+
+        while self.heights.len() < count {
+            let i = self.heights.len() + 1;
+
+            self.tree
+                .push(height + self.top(i - 1) - self.top(i - (i & i.wrapping_neg())));
+            self.heights.push(height);
+        }
+    }
+
+    /// Get the height of the given row.
+    #[inline(always)]
+    fn get(&self, index: usize) -> f32 {
+        self.heights[index]
+    }
+
+    /// Set the height of the given row.
+    fn set(&mut self, index: usize, height: f32) {
+        let delta = height - self.heights[index];
+        let mut i = index + 1;
+
+        self.heights[index] = height;
+
+        while i <= self.tree.len() {
+            self.tree[i - 1] += delta;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Get the top position of the given row, i.e. the sum of the heights of the rows before it.
+    fn top(&self, index: usize) -> f32 {
+        let mut sum = 0.0;
+        let mut i = index.min(self.tree.len());
+
+        while i > 0 {
+            sum += self.tree[i - 1];
+            i &= i - 1;
+        }
+
+        sum
+    }
+
+    /// Get the index of the row at the given position.
+    fn index_at(&self, y: f32) -> usize {
+        let mut index = 0;
+        let mut rest = y;
+        let mut step = if self.tree.is_empty() {
+            0
+        } else {
+            1 << self.tree.len().ilog2()
+        };
+
+        while step > 0 {
+            if index + step <= self.tree.len() && self.tree[index + step - 1] <= rest {
+                index += step;
+                rest -= self.tree[index - 1];
+            }
+
+            step >>= 1;
+        }
+
+        index
+    }
+}
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use crate::button::Button;
+    use crate::container::Container;
+    use crate::list_view::{ListView, RowHeight, RowHeights};
+    use maycoon_core::app::update::Update;
+    use maycoon_core::layout::{Dimension, LayoutStyle};
+    use maycoon_core::signal::Signal;
+    use maycoon_core::testing::Harness;
+    use maycoon_core::widget::WidgetLayoutExt;
+    use maycoon_core::window::MouseScrollDelta;
+    use maycoon_theme::theme::celeste::CelesteTheme;
+    use nalgebra::Vector2;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// A naive implementation of [RowHeights] using prefix sums.
+    fn naive_top(heights: &[f32], index: usize) -> f32 {
+        heights[..index.min(heights.len())].iter().sum()
+    }
+
+    /// A naive implementation of [RowHeights::index_at] using prefix sums.
+    fn naive_index_at(heights: &[f32], y: f32) -> usize {
+        (0..=heights.len())
+            .rev()
+            .find(|index| naive_top(heights, *index) <= y)
+            .unwrap_or_default()
+    }
+
+    /// Check the given row heights against the naive implementation.
+    fn check(rows: &RowHeights, heights: &[f32]) {
+        assert_eq!(rows.heights, heights);
+
+        for index in 0..=heights.len() + 1 {
+            assert_eq!(rows.top(index), naive_top(heights, index), "top({index})");
+        }
+
+        let total = naive_top(heights, heights.len());
+        let mut y = -1.0;
+
+        while y <= total + 2.0 {
+            assert_eq!(
+                rows.index_at(y),
+                naive_index_at(heights, y),
+                "index_at({y})"
+            );
+
+            y += 0.5;
+        }
+    }
+
+    /// Tests resizing, including truncating and growing again.
+    #[test]
+    fn test_row_heights_resize() {
+        let mut rows = RowHeights::default();
+        let mut heights = Vec::new();
+
+        check(&rows, &heights);
+
+        rows.resize(10, 2.0);
+        heights.resize(10, 2.0);
+        check(&rows, &heights);
+
+        rows.set(3, 5.0);
+        heights[3] = 5.0;
+        rows.set(7, 1.0);
+        heights[7] = 1.0;
+        check(&rows, &heights);
+
+        rows.resize(5, 3.0);
+        heights.truncate(5);
+        check(&rows, &heights);
+
+        rows.resize(13, 3.0);
+        heights.resize(13, 3.0);
+        check(&rows, &heights);
+
+        rows.set(12, 4.0);
+        heights[12] = 4.0;
+        check(&rows, &heights);
+
+        rows.resize(0, 1.0);
+        heights.clear();
+        check(&rows, &heights);
+    }
+
+    /// Tests that clicking a row right after scrolling clicks the row displayed under the cursor,
+    /// even if the row widget was recycled for another item.
+    #[test]
+    fn test_list_view_click_after_scroll() {
+        let clicked = Rc::new(RefCell::new(Vec::new()));
+
+        let list = {
+            let clicked = clicked.clone();
+
+            ListView::new(100, move |index| {
+                let clicked = clicked.clone();
+
+                Button::new(Container::new(Vec::new())).with_on_pressed(move |_, _| {
+                    clicked.borrow_mut().push(*index.get());
+                    Update::empty()
+                })
+            })
+            .with_row_height(RowHeight::Fixed(20.0))
+            .with_overscan(0)
+            .with_layout_style(LayoutStyle {
+                size: Vector2::new(Dimension::length(200.0), Dimension::length(100.0)),
+                scrollbar_width: 10.0,
+                ..Default::default()
+            })
+        };
+
+        let mut harness = Harness::new(list, CelesteTheme::default(), Vector2::new(200.0, 100.0));
+
+        harness.settle(5);
+
+        harness.click(Vector2::new(50.0, 90.0));
+        harness.settle(5);
+
+        assert_eq!(*clicked.borrow(), [4]);
+
+        // scrolling by two rows recycles the rows of the items 0 and 1 for the items 6 and 7
+        harness.scroll(MouseScrollDelta::LineDelta(0.0, -1.0));
+        harness.frame();
+
+        harness.click(Vector2::new(50.0, 90.0));
+        harness.settle(5);
+
+        assert_eq!(*clicked.borrow(), [4, 6]);
+    }
+
+    /// Tests random updates against the naive implementation.
+    #[test]
+    fn test_row_heights_random() {
+        let mut rows = RowHeights::default();
+        let mut heights = Vec::new();
+        let mut seed = 7u64;
+
+        let mut random = |max: usize| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+
+            (seed >> 33) as usize % max
+        };
+
+        for _ in 0..200 {
+            if heights.is_empty() || random(4) == 0 {
+                let count = random(40);
+                let height = random(8) as f32;
+
+                rows.resize(count, height);
+                heights.resize(count, height);
+            } else {
+                let index = random(heights.len());
+                let height = random(8) as f32;
+
+                rows.set(index, height);
+                heights[index] = height;
+
+                assert_eq!(rows.get(index), height);
+            }
+
+            check(&rows, &heights);
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use maycoon_core::signal::Signal;
+use maycoon_core::signal::state::StateSignal;
+use std::ops::Range;
+
+/// Keeps the rows of a virtualized widget for a range of positions and recycles rows that leave the range.
+///
+/// Every row owns a signal containing the index of the item it displays,
+/// so recycled rows can update their content without being rebuilt.
+pub(crate) struct Recycler<T> {
+    /// The range of positions of the built rows.
+    range: Range<usize>,
+    /// The built rows, ordered by position.
+    rows: Vec<Slot<T>>,
+    /// Unused rows, which are recycled before new rows are built.
+    pool: Vec<Slot<T>>,
+}
+
+impl<T> Recycler<T> {
+    /// Create an empty recycler.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            range: 0..0,
+            rows: Vec::new(),
+            pool: Vec::new(),
+        }
+    }
+
+    /// Get the range of positions of the built rows.
+    #[inline(always)]
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Get the built rows, ordered by position.
+    #[inline(always)]
+    pub fn rows(&self) -> &[Slot<T>] {
+        &self.rows
+    }
+
+    /// Get the built rows mutably, ordered by position.
+    #[inline(always)]
+    pub fn rows_mut(&mut self) -> &mut [Slot<T>] {
+        &mut self.rows
+    }
+
+    /// Build the rows for the given range of positions.
+    ///
+    /// The item index of each row is computed using `index`.
+    /// Rows still inside the range are kept, rows outside of it are recycled and new rows are built using `build`.
+    ///
+    /// Returns `true` if any row changed its position or item index.
+    pub fn build(
+        &mut self,
+        range: Range<usize>,
+        index: impl Fn(usize) -> usize,
+        mut build: impl FnMut(StateSignal<usize>) -> T,
+    ) -> bool {
+        if range == self.range
+            && self
+                .rows
+                .iter()
+                .zip(range.clone())
+                .all(|(row, pos)| *row.index.get() == index(pos))
+        {
+            return false;
+        }
+
+        let old_range = std::mem::replace(&mut self.range, range.clone());
+        let mut kept = Vec::with_capacity(range.len());
+
+        // recycle rows outside the range first, so they can be re-used on both ends
+        for (row, pos) in std::mem::take(&mut self.rows).into_iter().zip(old_range) {
+            if range.contains(&pos) {
+                kept.push((pos, row));
+            } else {
+                self.pool.push(row);
+            }
+        }
+
+        let mut kept = kept.into_iter().peekable();
+        let mut rows = Vec::with_capacity(range.len());
+
+        for pos in range.clone() {
+            let idx = index(pos);
+
+            // re-use rows which are still inside the range
+            let row = if let Some((_, row)) = kept.next_if(|(old_pos, _)| *old_pos == pos) {
+                row
+            } else if let Some(row) = self.pool.pop() {
+                row
+            } else {
+                let signal = StateSignal::new(idx);
+
+                Slot {
+                    item: build(signal.clone()),
+                    index: signal,
+                }
+            };
+
+            if *row.index.get() != idx {
+                row.index.set(idx);
+            }
+
+            rows.push(row);
+        }
+
+        self.pool.truncate(range.len());
+        self.rows = rows;
+
+        true
+    }
+}
+
+/// A built row of a [Recycler].
+pub(crate) struct Slot<T> {
+    /// The index of the displayed item.
+    pub index: StateSignal<usize>,
+    /// The row content.
+    pub item: T,
+}
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use crate::recycler::Recycler;
+    use maycoon_core::signal::Signal;
+    use maycoon_core::signal::state::StateSignal;
+    use std::cell::Cell;
+    use std::ops::Range;
+
+    /// A test row, containing a unique ID and the index signal it was built with.
+    type Row = (usize, StateSignal<usize>);
+
+    /// Build the given range and return the IDs of the rows by position.
+    fn build(
+        recycler: &mut Recycler<Row>,
+        built: &Cell<usize>,
+        range: Range<usize>,
+        index: impl Fn(usize) -> usize,
+    ) -> Vec<usize> {
+        recycler.build(range, &index, |signal| {
+            built.set(built.get() + 1);
+
+            (built.get(), signal)
+        });
+
+        // the rows must display the same items as freshly built rows
+        for (row, pos) in recycler.rows().iter().zip(recycler.range()) {
+            assert_eq!(*row.index.get(), index(pos));
+            assert_eq!(*row.item.1.get(), index(pos));
+        }
+
+        assert_eq!(recycler.rows().len(), recycler.range().len());
+
+        recycler.rows().iter().map(|row| row.item.0).collect()
+    }
+
+    /// Tests that rows inside the range are kept and rows leaving it are recycled.
+    #[test]
+    fn test_recycler_build() {
+        let mut recycler = Recycler::new();
+        let built = Cell::new(0);
+
+        let ids = build(&mut recycler, &built, 0..5, |pos| pos);
+        assert_eq!(ids, vec![1, 2, 3, 4, 5]);
+
+        // nothing changed
+        assert!(!recycler.build(0..5, |pos| pos, |_| unreachable!()));
+
+        // scroll down: rows 2..5 are kept, rows 0..2 are recycled for 5..7
+        let ids = build(&mut recycler, &built, 2..7, |pos| pos);
+        assert_eq!(&ids[..3], &[3, 4, 5]);
+        assert_eq!(built.get(), 5);
+
+        // scroll up: rows 2..5 are kept again
+        let ids = build(&mut recycler, &built, 0..5, |pos| pos);
+        assert_eq!(&ids[2..], &[3, 4, 5]);
+        assert_eq!(built.get(), 5);
+
+        // the items changed (e.g. sorted), so the rows are re-indexed without rebuilding
+        let ids = build(&mut recycler, &built, 0..5, |pos| 10 - pos);
+        assert_eq!(&ids[2..], &[3, 4, 5]);
+        assert_eq!(built.get(), 5);
+
+        // more rows than built before
+        build(&mut recycler, &built, 0..8, |pos| pos);
+        assert_eq!(built.get(), 8);
+
+        // the pool is limited to the number of visible rows
+        build(&mut recycler, &built, 100..102, |pos| pos);
+        assert!(recycler.pool.len() <= 2);
+
+        build(&mut recycler, &built, 0..0, |pos| pos);
+        assert!(recycler.rows().is_empty());
+        assert!(recycler.pool.is_empty());
+    }
+
+    /// Tests random ranges against freshly built rows.
+    #[test]
+    fn test_recycler_random() {
+        let mut recycler = Recycler::new();
+        let built = Cell::new(0);
+        let mut seed = 42u64;
+
+        let mut random = |max: usize| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+
+            (seed >> 33) as usize % max
+        };
+
+        let mut old = (0..0, Vec::new());
+
+        for _ in 0..500 {
+            let start = random(50);
+            let range = start..start + random(20);
+            let offset = random(3);
+
+            let ids = build(&mut recycler, &built, range.clone(), |pos| pos * 2 + offset);
+
+            // rows at the same position are kept
+            for (pos, id) in range.clone().zip(&ids) {
+                if old.0.contains(&pos) {
+                    assert_eq!(old.1[pos - old.0.start], *id);
+                }
+            }
+
+            old = (range, ids);
+        }
+    }
+}
@@ -0,0 +1,336 @@
+use maycoon_core::app::context::AppContext;
+use maycoon_core::app::info::AppInfo;
+use maycoon_core::app::update::Update;
+use maycoon_core::layout::{FlexDirection, LayoutNode, LayoutStyle, Overflow, StyleNode};
+use maycoon_core::signal::MaybeSignal;
+use maycoon_core::vgi::Scene;
+use maycoon_core::widget::{BoxedWidget, Widget, WidgetLayoutExt};
+use maycoon_theme::id::WidgetId;
+use maycoon_theme::theme::Theme;
+use nalgebra::Vector2;
+
+use crate::scrollbar::{Scrollbars, Scroller};
+
+/// A scrollable viewport for a child widget.
+///
+/// The child is laid out with its full size, clipped to the bounds of the scroll view
+/// and moved by the scroll offset. The offset can be changed by:
+/// - Scrolling the mouse wheel or trackpad, while hovering the scroll view. Hold `Shift` to scroll horizontally.
+/// - Dragging the thumb of a scrollbar.
+/// - Clicking the track of a scrollbar, which scrolls by one page.
+///
+/// The offset can be observed or changed programmatically by passing a signal to [ScrollView::with_offset].
+///
+/// Scrolls vertically by default. Use [ScrollView::with_horizontal] and [ScrollView::with_vertical] to change the scroll axes.
+/// The thickness of the scrollbars is the `scrollbar_width` of the [LayoutStyle].
+///
+/// See the [scroll_view](https://github.com/maycoon-ui/maycoon/blob/master/examples/scroll_view/src/main.rs) example for how to use it in practice.
+///
+/// ### Theming
+/// You can style the scroll view using following properties:
+/// - `color_track` - The color of the scrollbar tracks.
+/// - `color_thumb` - The color of the scrollbar thumbs.
+/// - `color_thumb_active` - The color of a scrollbar thumb while it's dragged.
+///
+/// The [WidgetId] is equal to `maycoon-widgets:ScrollView`.
+pub struct ScrollView {
+    child: BoxedWidget,
+    style: MaybeSignal<LayoutStyle>,
+    offset: MaybeSignal<Vector2<f32>>,
+    horizontal: MaybeSignal<bool>,
+    vertical: MaybeSignal<bool>,
+    scroll_step: f32,
+    scroller: Scroller,
+}
+
+impl ScrollView {
+    /// Creates a new scroll view with the given child widget.
+    #[inline(always)]
+    pub fn new(child: impl Widget + 'static) -> Self {
+        Self {
+            child: Box::new(child),
+            style: LayoutStyle {
+                flex_direction: FlexDirection::Column,
+                scrollbar_width: 10.0,
+                ..Default::default()
+            }
+            .into(),
+            offset: Vector2::zeros().into(),
+            horizontal: false.into(),
+            vertical: true.into(),
+            scroll_step: 40.0,
+            scroller: Scroller::default(),
+        }
+    }
+
+    /// Sets the child widget of the scroll view and returns itself.
+    #[inline(always)]
+    pub fn with_child(mut self, child: impl Widget + 'static) -> Self {
+        self.child = Box::new(child);
+        self
+    }
+
+    /// Sets the scroll offset and returns itself.
+    ///
+    /// If the offset is a signal, it's updated whenever the scroll view scrolls.
+    /// Setting the signal scrolls the view to the given offset, which is clamped to the scrollable area.
+    #[inline(always)]
+    pub fn with_offset(mut self, offset: impl Into<MaybeSignal<Vector2<f32>>>) -> Self {
+        self.offset = offset.into();
+        self
+    }
+
+    /// Sets whether the scroll view can scroll horizontally and returns itself. Defaults to `false`.
+    #[inline(always)]
+    pub fn with_horizontal(mut self, horizontal: impl Into<MaybeSignal<bool>>) -> Self {
+        self.horizontal = horizontal.into();
+        self
+    }
+
+    /// Sets whether the scroll view can scroll vertically and returns itself. Defaults to `true`.
+    #[inline(always)]
+    pub fn with_vertical(mut self, vertical: impl Into<MaybeSignal<bool>>) -> Self {
+        self.vertical = vertical.into();
+        self
+    }
+
+    /// Sets the distance in pixels to scroll per line of the mouse wheel and returns itself. Defaults to `40.0`.
+    #[inline(always)]
+    pub fn with_scroll_step(mut self, step: f32) -> Self {
+        self.scroll_step = step;
+        self
+    }
+
+    /// Set the scroll offset. The offset is only stored locally, if it's not a signal.
+    #[inline(always)]
+    fn set_offset(&mut self, offset: Vector2<f32>) {
+        if let Some(sig) = self.offset.as_signal() {
+            sig.set(offset);
+        } else {
+            self.offset = offset.into();
+        }
+    }
+}
+
+impl WidgetLayoutExt for ScrollView {
+    #[inline(always)]
+    fn set_layout_style(&mut self, layout_style: impl Into<MaybeSignal<LayoutStyle>>) {
+        self.style = layout_style.into();
+    }
+}
+
+impl Widget for ScrollView {
+    fn render(
+        &mut self,
+        scene: &mut dyn Scene,
+        theme: &mut dyn Theme,
+        layout_node: &LayoutNode,
+        info: &AppInfo,
+        context: AppContext,
+    ) {
+        let bars = Scrollbars::from_layout(&layout_node.layout);
+        let offset = bars.clamp(*self.offset.get());
+
+        scene.push_clip(None, &bars.viewport);
+
+        self.child.render(
+            scene,
+            theme,
+            &layout_node.children[0].translated(-offset),
+            info,
+            context,
+        );
+
+        scene.pop_layer();
+
+        self.scroller
+            .render(scene, theme, self.widget_id(), &bars, offset);
+    }
+
+    #[inline(always)]
+    fn layout_style(&self) -> StyleNode {
+        let overflow = |scroll: bool| {
+            if scroll {
+                Overflow::Scroll
+            } else {
+                Overflow::Visible
+            }
+        };
+
+        StyleNode {
+            style: LayoutStyle {
+                overflow: (
+                    overflow(*self.horizontal.get()),
+                    overflow(*self.vertical.get()),
+                ),
+                ..self.style.get().clone()
+            },
+            children: vec![self.child.layout_style()],
+            measure: None,
+        }
+    }
+
+    fn update(&mut self, layout: &LayoutNode, context: AppContext, info: &AppInfo) -> Update {
+        let bars = Scrollbars::from_layout(&layout.layout);
+        let old_offset = *self.offset.get();
+
+        // scroll before updating the child, so it registers its hit regions at the new offset
+        let (pointer, offset, redraw) = self.scroller.update(
+            &bars,
+            &layout.layout,
+            old_offset,
+            self.scroll_step,
+            &context,
+            info,
+        );
+
+        let mut update = context.pointer().scope(pointer, || {
+            context.pointer().clip(&bars.viewport, || {
+                self.child.update(
+                    &layout.children[0].translated(-offset),
+                    context.clone(),
+                    info,
+                )
+            })
+        });
+
+        if redraw {
+            update.insert(Update::DRAW);
+        }
+
+        // update again, so the hover state of the child follows the new offset
+        if offset != old_offset {
+            self.set_offset(offset);
+            update.insert(Update::EVAL | Update::DRAW);
+        }
+
+        update
+    }
+
+    #[inline(always)]
+    fn widget_id(&self) -> WidgetId {
+        WidgetId::new("maycoon-widgets", "ScrollView")
+    }
+}
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use crate::container::Container;
+    use crate::scroll_view::ScrollView;
+    use maycoon_core::layout::{Dimension, FlexDirection, LayoutStyle};
+    use maycoon_core::signal::Signal;
+    use maycoon_core::signal::state::StateSignal;
+    use maycoon_core::testing::Harness;
+    use maycoon_core::widget::{Widget, WidgetLayoutExt};
+    use maycoon_core::window::{ElementState, MouseButton, MouseScrollDelta};
+    use maycoon_theme::theme::celeste::CelesteTheme;
+    use nalgebra::Vector2;
+
+    /// Create a scroll view with the given size and offset signal.
+    fn scroll_view(
+        size: Vector2<f32>,
+        offset: &StateSignal<Vector2<f32>>,
+        child: impl Widget + 'static,
+    ) -> ScrollView {
+        ScrollView::new(child)
+            .with_offset(offset.maybe())
+            .with_layout_style(LayoutStyle {
+                size: size.map(Dimension::length),
+                flex_direction: FlexDirection::Column,
+                flex_shrink: 0.0,
+                scrollbar_width: 10.0,
+                ..Default::default()
+            })
+    }
+
+    /// Create an empty widget with the given height.
+    fn spacer(height: f32) -> Container {
+        Container::new(Vec::new()).with_layout_style(LayoutStyle {
+            size: Vector2::new(Dimension::length(100.0), Dimension::length(height)),
+            flex_shrink: 0.0,
+            ..Default::default()
+        })
+    }
+
+    /// Tests that the mouse wheel only scrolls the innermost scroll view under the cursor.
+    #[test]
+    fn test_scroll_view_nested() {
+        let outer = StateSignal::new(Vector2::zeros());
+        let inner = StateSignal::new(Vector2::zeros());
+
+        let content = Container::new(vec![
+            Box::new(scroll_view(
+                Vector2::new(190.0, 100.0),
+                &inner,
+                spacer(400.0),
+            )),
+            Box::new(spacer(400.0)),
+        ])
+        .with_layout_style(LayoutStyle {
+            flex_direction: FlexDirection::Column,
+            ..Default::default()
+        });
+
+        let mut harness = Harness::new(
+            scroll_view(Vector2::new(200.0, 200.0), &outer, content),
+            CelesteTheme::default(),
+            Vector2::new(200.0, 200.0),
+        );
+
+        harness.settle(5);
+
+        harness.cursor_move(Vector2::new(50.0, 50.0));
+        harness.scroll(MouseScrollDelta::LineDelta(0.0, -1.0));
+        harness.settle(5);
+
+        assert_eq!(*inner.get(), Vector2::new(0.0, 40.0));
+        assert_eq!(*outer.get(), Vector2::zeros());
+
+        harness.cursor_move(Vector2::new(50.0, 150.0));
+        harness.scroll(MouseScrollDelta::LineDelta(0.0, -1.0));
+        harness.settle(5);
+
+        assert_eq!(*inner.get(), Vector2::new(0.0, 40.0));
+        assert_eq!(*outer.get(), Vector2::new(0.0, 40.0));
+    }
+
+    /// Tests dragging the thumb, which ends when releasing the button outside the window.
+    #[test]
+    fn test_scroll_view_thumb() {
+        let offset = StateSignal::new(Vector2::zeros());
+
+        let mut harness = Harness::new(
+            scroll_view(Vector2::new(200.0, 200.0), &offset, spacer(800.0)),
+            CelesteTheme::default(),
+            Vector2::new(200.0, 200.0),
+        );
+
+        harness.settle(5);
+
+        // the thumb is 50 pixels long, so every pixel scrolls by 600 / 150 pixels
+        harness.cursor_move(Vector2::new(195.0, 10.0));
+        harness.mouse_input(MouseButton::Left, ElementState::Pressed);
+        harness.settle(5);
+
+        harness.cursor_move(Vector2::new(195.0, 60.0));
+        harness.settle(5);
+
+        assert_eq!(*offset.get(), Vector2::new(0.0, 200.0));
+
+        harness.cursor_leave();
+        harness.mouse_input(MouseButton::Left, ElementState::Released);
+        harness.settle(5);
+
+        // the drag ended, so moving the cursor doesn't scroll anymore
+        harness.cursor_move(Vector2::new(195.0, 110.0));
+        harness.settle(5);
+
+        assert_eq!(*offset.get(), Vector2::new(0.0, 200.0));
+
+        // clicking the track below the thumb scrolls by one page
+        harness.click(Vector2::new(195.0, 150.0));
+        harness.settle(5);
+
+        assert_eq!(*offset.get(), Vector2::new(0.0, 400.0));
+    }
+}
@@ -0,0 +1,395 @@
+use maycoon_core::app::context::AppContext;
+use maycoon_core::app::info::AppInfo;
+use maycoon_core::app::pointer::{Pointer, PointerEventKind, PointerId, Propagation};
+use maycoon_core::layout::Layout;
+use maycoon_core::vgi::kurbo::{Point, Rect, RoundedRect, RoundedRectRadii};
+use maycoon_core::vgi::{Brush, Color, Scene};
+use maycoon_core::window::{ElementState, MouseButton, MouseScrollDelta};
+use maycoon_theme::id::WidgetId;
+use maycoon_theme::theme::Theme;
+use nalgebra::Vector2;
+
+/// The minimal length of a scrollbar thumb.
+const MIN_THUMB_LENGTH: f32 = 20.0;
+
+/// A scroll axis.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum Axis {
+    Horizontal,
+    Vertical,
+}
+
+impl Axis {
+    /// Get the component of the vector along this axis.
+    #[inline(always)]
+    fn get(self, vec: Vector2<f32>) -> f32 {
+        match self {
+            Axis::Horizontal => vec.x,
+            Axis::Vertical => vec.y,
+        }
+    }
+
+    /// Get the start of the rectangle along this axis.
+    #[inline(always)]
+    fn start(self, rect: &Rect) -> f32 {
+        match self {
+            Axis::Horizontal => rect.x0 as f32,
+            Axis::Vertical => rect.y0 as f32,
+        }
+    }
+
+    /// Get a mutable reference to the component of the vector along this axis.
+    #[inline(always)]
+    fn get_mut(self, vec: &mut Vector2<f32>) -> &mut f32 {
+        match self {
+            Axis::Horizontal => &mut vec.x,
+            Axis::Vertical => &mut vec.y,
+        }
+    }
+}
+
+/// The geometry of the viewport and scrollbars of a scrollable widget.
+pub(crate) struct Scrollbars {
+    /// The visible area of the content.
+    pub viewport: Rect,
+    /// The thickness of the vertical and horizontal scrollbars. Zero if the axis does not scroll.
+    thickness: Vector2<f32>,
+    /// The maximum scroll offset.
+    max: Vector2<f32>,
+}
+
+impl Scrollbars {
+    /// Create the scrollbar geometry for the given viewport, scrollbar thickness and content size.
+    pub fn new(viewport: Rect, thickness: Vector2<f32>, content: Vector2<f32>) -> Self {
+        Self {
+            viewport,
+            thickness,
+            max: Vector2::new(
+                (content.x - viewport.width() as f32).max(0.0),
+                (content.y - viewport.height() as f32).max(0.0),
+            ),
+        }
+    }
+
+    /// Compute the scrollbar geometry from the layout of a node with [Overflow::Scroll](layout::Overflow::Scroll).
+    pub fn from_layout(layout: &Layout) -> Self {
+        let thickness = Vector2::new(layout.scrollbar_size.width, layout.scrollbar_size.height);
+
+        let viewport = Rect::new(
+            (layout.location.x + layout.border.left) as f64,
+            (layout.location.y + layout.border.top) as f64,
+            (layout.location.x + layout.size.width - layout.border.right - thickness.x) as f64,
+            (layout.location.y + layout.size.height - layout.border.bottom - thickness.y) as f64,
+        );
+
+        // the content size is relative to the border box
+        let content = Vector2::new(
+            layout.content_size.width - layout.border.left,
+            layout.content_size.height - layout.border.top,
+        );
+
+        Self::new(viewport, thickness, content)
+    }
+
+    /// The size of the viewport.
+    #[inline(always)]
+    pub fn size(&self) -> Vector2<f32> {
+        Vector2::new(self.viewport.width() as f32, self.viewport.height() as f32)
+    }
+
+    /// Clamp the given offset to the scrollable area.
+    #[inline(always)]
+    pub fn clamp(&self, offset: Vector2<f32>) -> Vector2<f32> {
+        Vector2::new(
+            offset.x.clamp(0.0, self.max.x),
+            offset.y.clamp(0.0, self.max.y),
+        )
+    }
+
+    /// The track of the scrollbar along the given axis. Returns [None] if the axis does not scroll.
+    fn track(&self, axis: Axis) -> Option<Rect> {
+        let vp = self.viewport;
+
+        match axis {
+            Axis::Horizontal if self.thickness.y > 0.0 => Some(Rect::new(
+                vp.x0,
+                vp.y1,
+                vp.x1,
+                vp.y1 + self.thickness.y as f64,
+            )),
+            Axis::Vertical if self.thickness.x > 0.0 => Some(Rect::new(
+                vp.x1,
+                vp.y0,
+                vp.x1 + self.thickness.x as f64,
+                vp.y1,
+            )),
+            _ => None,
+        }
+    }
+
+    /// The length of the thumb of the scrollbar along the given axis.
+    #[inline(always)]
+    fn thumb_length(&self, axis: Axis) -> f32 {
+        let visible = axis.get(self.size());
+
+        (visible * visible / (visible + axis.get(self.max)))
+            .max(MIN_THUMB_LENGTH)
+            .min(visible)
+    }
+
+    /// The scroll distance per pixel the thumb along the given axis is moved.
+    #[inline(always)]
+    fn scale(&self, axis: Axis) -> f32 {
+        let free = axis.get(self.size()) - self.thumb_length(axis);
+
+        if free > 0.0 {
+            axis.get(self.max) / free
+        } else {
+            0.0
+        }
+    }
+
+    /// The thumb of the scrollbar along the given axis at the given offset.
+    ///
+    /// Returns [None] if the axis does not scroll or the content fits into the viewport.
+    fn thumb(&self, axis: Axis, offset: Vector2<f32>) -> Option<Rect> {
+        let track = self.track(axis)?;
+        let max = axis.get(self.max);
+
+        if max <= 0.0 {
+            return None;
+        }
+
+        let length = self.thumb_length(axis) as f64;
+        let start =
+            ((axis.get(self.size()) - self.thumb_length(axis)) * axis.get(offset) / max) as f64;
+
+        Some(match axis {
+            Axis::Horizontal => Rect::new(
+                track.x0 + start,
+                track.y0,
+                track.x0 + start + length,
+                track.y1,
+            ),
+            Axis::Vertical => Rect::new(
+                track.x0,
+                track.y0 + start,
+                track.x1,
+                track.y0 + start + length,
+            ),
+        })
+    }
+}
+
+/// Handles scrolling with the mouse wheel and the scrollbars and draws the scrollbars.
+#[derive(Default)]
+pub(crate) struct Scroller {
+    drag: Option<Drag>,
+    pointer: PointerId,
+    tracks: [PointerId; 2],
+}
+
+impl Scroller {
+    /// Register the scrollable area and the scrollbars with the [PointerManager](maycoon_core::app::pointer::PointerManager)
+    /// and apply the routed pointer events to the given offset.
+    ///
+    /// The mouse wheel scrolls, while the cursor hovers over the given bounds and no scrollable widget inside them handles the event.
+    /// Returns the pointer handle of the bounds, the new (clamped) offset and if the scrollbars need to be redrawn.
+    ///
+    /// The content should be updated with the new offset inside [PointerManager::scope](maycoon_core::app::pointer::PointerManager::scope)
+    /// of the returned handle, so events bubble from the content to the scrollable widget.
+    pub fn update(
+        &mut self,
+        bars: &Scrollbars,
+        bounds: &Layout,
+        offset: Vector2<f32>,
+        step: f32,
+        context: &AppContext,
+        info: &AppInfo,
+    ) -> (Pointer, Vector2<f32>, bool) {
+        let pointer = context.pointer();
+        let mut offset = bars.clamp(offset);
+        let mut redraw = false;
+
+        let area = pointer.register(self.pointer, bounds, Propagation::StopScroll);
+
+        for event in pointer.events(area) {
+            if let PointerEventKind::Scroll(delta) = event.kind {
+                let mut delta = match delta {
+                    MouseScrollDelta::LineDelta(x, y) => Vector2::new(x, y) * step,
+                    MouseScrollDelta::PixelDelta(pos) => Vector2::new(pos.x as f32, pos.y as f32),
+                };
+
+                // scroll horizontally with a vertical mouse wheel
+                if info.modifiers.shift_key() && delta.x == 0.0 {
+                    delta = Vector2::new(delta.y, 0.0);
+                }
+
+                offset -= delta;
+            }
+        }
+
+        // the tracks are outside the viewport, so the clipped content can't cover them
+        for (axis, id) in [Axis::Horizontal, Axis::Vertical]
+            .into_iter()
+            .zip(self.tracks)
+        {
+            let Some(track) = bars.track(axis) else {
+                continue;
+            };
+
+            let handle = pointer.scope(area, || {
+                pointer.register(id, &layout_of(&track), Propagation::StopButtons)
+            });
+
+            for event in pointer.events(handle) {
+                match event.kind {
+                    PointerEventKind::Button(MouseButton::Left, ElementState::Pressed) => {
+                        let cursor = event.position;
+
+                        if let Some(thumb) = bars.thumb(axis, offset)
+                            && contains(&thumb, cursor)
+                        {
+                            self.drag = Some(Drag {
+                                axis,
+                                cursor: axis.get(cursor),
+                                offset: axis.get(offset),
+                            });
+                            redraw = true;
+                        } else {
+                            // scroll by one page towards the cursor
+                            let page = axis.get(bars.size());
+                            let thumb = bars.thumb(axis, offset).unwrap_or(track);
+
+                            if axis.get(cursor) < axis.start(&thumb) {
+                                *axis.get_mut(&mut offset) -= page;
+                            } else {
+                                *axis.get_mut(&mut offset) += page;
+                            }
+                        }
+                    },
+
+                    PointerEventKind::Button(MouseButton::Left, ElementState::Released)
+                        if self.drag.is_some_and(|drag| drag.axis == axis) =>
+                    {
+                        self.drag = None;
+                        redraw = true;
+                    },
+
+                    _ => (),
+                }
+            }
+
+            if let Some(drag) = self.drag.filter(|drag| drag.axis == axis) {
+                // the button might be released outside the scrollbar or the window
+                pointer.capture(handle);
+
+                if let Some(cursor) = info.cursor_pos {
+                    *axis.get_mut(&mut offset) =
+                        drag.offset + (axis.get(cursor) - drag.cursor) * bars.scale(axis);
+                }
+            }
+        }
+
+        (area, bars.clamp(offset), redraw)
+    }
+
+    /// Draw the scrollbars at the given offset.
+    ///
+    /// The colors are taken from the `color_track`, `color_thumb` and `color_thumb_active` style properties of the widget.
+    pub fn render(
+        &self,
+        scene: &mut dyn Scene,
+        theme: &dyn Theme,
+        id: WidgetId,
+        bars: &Scrollbars,
+        offset: Vector2<f32>,
+    ) {
+        let (track, thumb, thumb_active) = if let Some(style) = theme.of(id) {
+            (
+                style.get_color("color_track").unwrap(),
+                style.get_color("color_thumb").unwrap(),
+                style.get_color("color_thumb_active").unwrap(),
+            )
+        } else {
+            (
+                theme.defaults().container().background(),
+                theme.defaults().interactive().inactive(),
+                theme.defaults().interactive().active(),
+            )
+        };
+
+        for axis in [Axis::Horizontal, Axis::Vertical] {
+            let Some(track_rect) = bars.track(axis) else {
+                continue;
+            };
+
+            draw_bar(scene, track, &track_rect);
+
+            if let Some(thumb_rect) = bars.thumb(axis, offset) {
+                let color = if self.drag.is_some_and(|drag| drag.axis == axis) {
+                    thumb_active
+                } else {
+                    thumb
+                };
+
+                draw_bar(scene, color, &thumb_rect);
+            }
+        }
+    }
+}
+
+/// The state of a dragged scrollbar thumb.
+#[derive(Copy, Clone, Debug)]
+struct Drag {
+    /// The axis of the dragged scrollbar.
+    axis: Axis,
+    /// The cursor position along the axis, when the drag started.
+    cursor: f32,
+    /// The scroll offset along the axis, when the drag started.
+    offset: f32,
+}
+
+/// Get the layout of the given rectangle, e.g. to register it with the pointer manager.
+#[inline(always)]
+pub(crate) fn layout_of(rect: &Rect) -> Layout {
+    let mut layout = Layout::new();
+
+    layout.location.x = rect.x0 as f32;
+    layout.location.y = rect.y0 as f32;
+    layout.size.width = rect.width() as f32;
+    layout.size.height = rect.height() as f32;
+
+    layout
+}
+
+/// Get the rectangle of the given layout.
+#[inline(always)]
+pub(crate) fn rect_of(layout: &Layout) -> Rect {
+    Rect::new(
+        layout.location.x as f64,
+        layout.location.y as f64,
+        (layout.location.x + layout.size.width) as f64,
+        (layout.location.y + layout.size.height) as f64,
+    )
+}
+
+/// Checks if the given rectangle contains the given point.
+#[inline(always)]
+pub(crate) fn contains(rect: &Rect, point: Vector2<f32>) -> bool {
+    rect.contains(Point::new(point.x as f64, point.y as f64))
+}
+
+/// Draws a scrollbar track or thumb.
+#[inline(always)]
+fn draw_bar(scene: &mut dyn Scene, color: Color, rect: &Rect) {
+    scene.draw_rounded_rect(
+        &Brush::Solid(color),
+        None,
+        None,
+        &RoundedRect::from_rect(
+            *rect,
+            RoundedRectRadii::from_single_radius(rect.width().min(rect.height()) / 2.0),
+        ),
+    );
+}
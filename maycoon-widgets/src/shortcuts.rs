@@ -0,0 +1,118 @@
+use maycoon_core::app::context::AppContext;
+use maycoon_core::app::info::AppInfo;
+use maycoon_core::app::shortcut::Shortcut;
+use maycoon_core::app::update::Update;
+use maycoon_core::event::Handler;
+use maycoon_core::layout::{LayoutNode, StyleNode};
+use maycoon_core::vgi::Scene;
+use maycoon_core::widget::{BoxedWidget, Widget};
+use maycoon_theme::id::WidgetId;
+use maycoon_theme::theme::Theme;
+
+/// A widget to run actions when keyboard shortcuts are pressed.
+/// It contains a child widget.
+///
+/// Shortcuts are registered with the [ShortcutManager](maycoon_core::app::shortcut::ShortcutManager) on every update.
+/// By default, they are only active while a widget inside the child is focused.
+/// Use [Shortcuts::with_global] to make them active regardless of the focus.
+///
+/// If multiple active shortcuts have the same key chord, the innermost [Shortcuts] widget handles it.
+/// Keys not handled by a shortcut are delivered to the focused widget as usual.
+///
+/// See the [shortcuts](https://github.com/maycoon-ui/maycoon/blob/master/examples/shortcuts/src/main.rs) example for how to use it in practice.
+///
+/// ### Theming
+/// The [Shortcuts] widget should not be themed and does not draw anything on itself.
+/// It just contains the given child widget.
+///
+/// The [WidgetId] is equal to `maycoon-widgets:Shortcuts`.
+pub struct Shortcuts {
+    child: BoxedWidget,
+    shortcuts: Vec<(Shortcut, Handler<Shortcut>)>,
+    global: bool,
+}
+
+impl Shortcuts {
+    /// Creates a new [Shortcuts] widget with the given child widget and no shortcuts.
+    #[inline(always)]
+    pub fn new(child: impl Widget + 'static) -> Self {
+        Self {
+            child: Box::new(child),
+            shortcuts: Vec::new(),
+            global: false,
+        }
+    }
+
+    /// Sets the child widget of the [Shortcuts] widget and returns self.
+    #[inline(always)]
+    pub fn with_child(mut self, child: impl Widget + 'static) -> Self {
+        self.child = Box::new(child);
+        self
+    }
+
+    /// Adds a shortcut with the given action to the [Shortcuts] widget and returns self.
+    ///
+    /// The action is called with the shortcut, when it is pressed.
+    #[inline(always)]
+    pub fn with_shortcut(
+        mut self,
+        shortcut: Shortcut,
+        action: impl FnMut(&Shortcut, &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.shortcuts.push((shortcut, Handler::new(action)));
+        self
+    }
+
+    /// Sets if the shortcuts are active regardless of the focus and returns self.
+    #[inline(always)]
+    pub fn with_global(mut self, global: bool) -> Self {
+        self.global = global;
+        self
+    }
+}
+
+impl Widget for Shortcuts {
+    #[inline(always)]
+    fn render(
+        &mut self,
+        scene: &mut dyn Scene,
+        theme: &mut dyn Theme,
+        layout_node: &LayoutNode,
+        info: &AppInfo,
+        context: AppContext,
+    ) {
+        self.child.render(scene, theme, layout_node, info, context);
+    }
+
+    #[inline(always)]
+    fn layout_style(&self) -> StyleNode {
+        self.child.layout_style()
+    }
+
+    fn update(&mut self, layout: &LayoutNode, context: AppContext, info: &AppInfo) -> Update {
+        let (mut update, scope) = context
+            .focus()
+            .scope(|| self.child.update(layout, context.clone(), info));
+
+        let manager = context.shortcuts();
+
+        for (shortcut, action) in &mut self.shortcuts {
+            let handle = if self.global {
+                manager.register(shortcut.clone())
+            } else {
+                manager.register_scoped(shortcut.clone(), scope.clone())
+            };
+
+            if manager.is_triggered(handle) {
+                update |= action.call(shortcut, &context);
+            }
+        }
+
+        update
+    }
+
+    #[inline(always)]
+    fn widget_id(&self) -> WidgetId {
+        WidgetId::new("maycoon-widgets", "Shortcuts")
+    }
+}
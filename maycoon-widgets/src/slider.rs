@@ -1,30 +1,47 @@
 use maycoon_core::app::context::AppContext;
+use maycoon_core::app::focus::Focus;
 use maycoon_core::app::info::AppInfo;
+use maycoon_core::app::pointer::{PointerEventKind, PointerId, Propagation};
 use maycoon_core::app::update::Update;
+use maycoon_core::event::Handler;
 use maycoon_core::layout;
 use maycoon_core::layout::{Dimension, LayoutNode, LayoutStyle, LengthPercentageAuto, StyleNode};
 use maycoon_core::signal::MaybeSignal;
 use maycoon_core::vgi::kurbo::{Circle, Point, Rect, RoundedRect, RoundedRectRadii};
 use maycoon_core::vgi::{Brush, Scene};
 use maycoon_core::widget::{Widget, WidgetLayoutExt};
-use maycoon_core::window::MouseButton;
+use maycoon_core::window::{CursorIcon, ElementState, Key, MouseButton, NamedKey};
 use maycoon_theme::id::WidgetId;
 use maycoon_theme::theme::Theme;
 use nalgebra::Vector2;
 
+use crate::focus_ring::draw_focus_ring;
+
 /// A slider widget to control a floating point value between `0.0` and `1.0`.
 ///
+/// The slider is focusable and can also be controlled using the keyboard while focused:
+/// - `Right`/`Up` increases the value by the step (`0.1` by default).
+/// - `Left`/`Down` decreases the value by the step.
+/// - `Home`/`End` sets the value to `0.0`/`1.0`.
+///
+/// The cursor changes to a pointing hand while hovering the slider.
+/// Once pressed, the slider follows the cursor until the button is released, even outside the slider.
+///
 /// ### Theming
 /// You can style the slider using following properties:
 /// - `color` - The color of the slider bar.
 /// - `color_ball` - The color of the slider ball.
+/// - `color_focus` - The color of the focus ring, when the slider is focused.
 ///
 /// The [WidgetId] is equal to `maycoon-widgets:Slider`.
 pub struct Slider {
     layout_style: MaybeSignal<LayoutStyle>,
     value: MaybeSignal<f32>,
-    on_change: MaybeSignal<Update>,
+    on_change: Handler<f32>,
+    step: f32,
     dragging: bool,
+    focus: Option<Focus>,
+    pointer: PointerId,
 }
 
 impl Slider {
@@ -44,8 +61,11 @@ impl Slider {
             }
             .into(),
             value: value.into(),
-            on_change: MaybeSignal::value(Update::empty()),
+            on_change: Handler::none(),
+            step: 0.1,
             dragging: false,
+            focus: None,
+            pointer: PointerId::new(),
         }
     }
 
@@ -56,12 +76,40 @@ impl Slider {
         self
     }
 
-    /// Sets the function to be called when the slider is clicked/changed.
+    /// Sets the handler to call with the new value, when the slider is clicked/changed.
+    #[inline(always)]
+    pub fn with_on_change(
+        mut self,
+        on_change: impl FnMut(&f32, &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_change = Handler::new(on_change);
+        self
+    }
+
+    /// Sets the step to change the value by, when using the arrow keys, and returns itself.
     #[inline(always)]
-    pub fn with_on_change(mut self, on_change: impl Into<MaybeSignal<Update>>) -> Self {
-        self.on_change = on_change.into();
+    pub fn with_step(mut self, step: f32) -> Self {
+        self.step = step;
         self
     }
+
+    /// Set the value and call the `on_change` handler, if the value changed.
+    /// The value is only stored locally, if it's not a signal.
+    fn set_value(&mut self, value: f32, context: &AppContext) -> Update {
+        let value = value.clamp(0.0, 1.0);
+
+        if value == *self.value.get() {
+            return Update::empty();
+        }
+
+        if let Some(sig) = self.value.as_signal() {
+            sig.set(value);
+        } else {
+            self.value = value.into();
+        }
+
+        self.on_change.call(&value, context) | Update::DRAW
+    }
 }
 
 impl WidgetLayoutExt for Slider {
@@ -78,7 +126,7 @@ impl Widget for Slider {
         theme: &mut dyn Theme,
         layout_node: &LayoutNode,
         _: &AppInfo,
-        _: AppContext,
+        context: AppContext,
     ) {
         let value = *self.value.get();
 
@@ -123,6 +171,13 @@ impl Widget for Slider {
                 circle_radius,
             ),
         );
+
+        if self
+            .focus
+            .is_some_and(|focus| context.focus().is_focused(focus))
+        {
+            draw_focus_ring(scene, theme, self.widget_id(), &layout_node.layout, 20.0);
+        }
     }
 
     #[inline(always)]
@@ -130,33 +185,72 @@ impl Widget for Slider {
         StyleNode {
             style: self.layout_style.get().clone(),
             children: Vec::new(),
+            measure: None,
         }
     }
 
-    fn update(&mut self, layout: &LayoutNode, _: AppContext, info: &AppInfo) -> Update {
+    fn update(&mut self, layout: &LayoutNode, context: AppContext, info: &AppInfo) -> Update {
         let mut update = Update::empty();
+        let focus = context.focus().register();
 
-        if let Some(cursor) = info.cursor_pos
-            && layout::intersects(cursor, &layout.layout)
-        {
-            for (_, btn, el_state) in &info.buttons {
-                if btn == &MouseButton::Left && el_state.is_pressed() {
-                    self.dragging = el_state.is_pressed();
-                }
+        self.focus = Some(focus);
+
+        let pointer =
+            context
+                .pointer()
+                .register(self.pointer, &layout.layout, Propagation::Continue);
+
+        context
+            .pointer()
+            .set_cursor_icon(pointer, CursorIcon::Pointer);
+
+        // the value at the given horizontal cursor position
+        let value_at = |x: f32| (x - layout.layout.location.x) / layout.layout.size.width;
+
+        for event in context.pointer().events(pointer) {
+            match event.kind {
+                PointerEventKind::Button(MouseButton::Left, ElementState::Pressed) => {
+                    self.dragging = true;
+                    context.focus().request(focus);
+                    update |= self.set_value(value_at(event.position.x), &context);
+                },
+
+                PointerEventKind::Button(MouseButton::Left, ElementState::Released)
+                    if self.dragging =>
+                {
+                    self.dragging = false;
+                    update |= self.set_value(value_at(event.position.x), &context);
+                },
+
+                _ => (),
             }
+        }
 
-            if self.dragging {
-                let new_value = (cursor.x - layout.layout.location.x) / layout.layout.size.width;
+        if self.dragging {
+            // the button might be released outside the slider or the window
+            context.pointer().capture(pointer);
 
-                if let Some(sig) = self.value.as_signal() {
-                    sig.set(new_value);
-                }
+            if let Some(cursor) = info.cursor_pos {
+                update |= self.set_value(value_at(cursor.x), &context);
+            }
+        }
 
-                update.insert(*self.on_change.get());
-                update.insert(Update::DRAW);
+        for key in context.focus().keys(focus, info) {
+            if !key.is_pressed() {
+                continue;
             }
-        } else {
-            self.dragging = false;
+
+            let value = *self.value.get();
+
+            let new_value = match key.logical_key {
+                Key::Named(NamedKey::ArrowRight | NamedKey::ArrowUp) => value + self.step,
+                Key::Named(NamedKey::ArrowLeft | NamedKey::ArrowDown) => value - self.step,
+                Key::Named(NamedKey::Home) => 0.0,
+                Key::Named(NamedKey::End) => 1.0,
+                _ => continue,
+            };
+
+            update |= self.set_value(new_value, &context);
         }
 
         update
@@ -167,3 +261,68 @@ impl Widget for Slider {
         WidgetId::new("maycoon-widgets", "Slider")
     }
 }
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use crate::slider::Slider;
+    use maycoon_core::app::update::Update;
+    use maycoon_core::signal::Signal;
+    use maycoon_core::signal::state::StateSignal;
+    use maycoon_core::testing::Harness;
+    use maycoon_core::window::{ElementState, MouseButton};
+    use maycoon_theme::theme::celeste::CelesteTheme;
+    use nalgebra::Vector2;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    /// Tests dragging outside the slider, which ends when releasing the button outside the window,
+    /// and that `on_change` is only called when the value changes.
+    #[test]
+    fn test_slider_drag() {
+        let value = StateSignal::new(0.0);
+        let changes = Rc::new(Cell::new(0));
+
+        let slider = Slider::new(value.maybe()).with_on_change({
+            let changes = changes.clone();
+
+            move |_, _| {
+                changes.set(changes.get() + 1);
+                Update::empty()
+            }
+        });
+
+        let mut harness = Harness::new(slider, CelesteTheme::default(), Vector2::new(200.0, 50.0));
+
+        harness.settle(5);
+
+        // the slider is 100 pixels wide and starts at `x = 10`
+        harness.cursor_move(Vector2::new(60.0, 15.0));
+        harness.mouse_input(MouseButton::Left, ElementState::Pressed);
+        harness.settle(5);
+
+        assert_eq!(*value.get(), 0.5);
+        assert_eq!(changes.get(), 1);
+
+        harness.cursor_move(Vector2::new(160.0, 40.0));
+        harness.settle(5);
+
+        assert_eq!(*value.get(), 1.0);
+        assert_eq!(changes.get(), 2);
+
+        harness.cursor_move(Vector2::new(190.0, 40.0));
+        harness.settle(5);
+
+        assert_eq!(changes.get(), 2);
+
+        harness.cursor_leave();
+        harness.mouse_input(MouseButton::Left, ElementState::Released);
+        harness.settle(5);
+
+        // the drag ended, so moving the cursor doesn't change the value anymore
+        harness.cursor_move(Vector2::new(35.0, 15.0));
+        harness.settle(5);
+
+        assert_eq!(*value.get(), 1.0);
+        assert_eq!(changes.get(), 2);
+    }
+}
@@ -1,33 +1,44 @@
 use maycoon_core::app::context::AppContext;
+use maycoon_core::app::focus::Focus;
 use maycoon_core::app::info::AppInfo;
+use maycoon_core::app::pointer::{PointerId, Propagation};
 use maycoon_core::app::update::Update;
+use maycoon_core::event::Handler;
 use maycoon_core::layout;
 use maycoon_core::layout::{Dimension, LayoutNode, LayoutStyle, LengthPercentageAuto, StyleNode};
 use maycoon_core::signal::MaybeSignal;
 use maycoon_core::vgi::kurbo::{Circle, Point, Rect, RoundedRect, RoundedRectRadii, Stroke};
 use maycoon_core::vgi::{Brush, Scene};
 use maycoon_core::widget::{Widget, WidgetLayoutExt};
-use maycoon_core::window::{ElementState, MouseButton};
+use maycoon_core::window::{CursorIcon, ElementState, Key, MouseButton, NamedKey};
 use maycoon_theme::id::WidgetId;
 use maycoon_theme::theme::Theme;
 use nalgebra::Vector2;
 
+use crate::focus_ring::draw_focus_ring;
+
 /// A switch widget to toggle between two states.
 ///
 /// Similar to a checkbox, but with slightly different visuals.
 ///
+/// The switch is focusable and can also be toggled by pressing `Space` or `Enter` while focused.
+/// The cursor changes to a pointing hand while hovering the switch.
+///
 /// See the [switch](https://github.com/maycoon-ui/maycoon/blob/master/examples/switch/src/main.rs) example for how to use it in practice.
 ///
 /// ### Theming
 /// Styling the checkbox requires following properties:
 /// - `color_unchecked` -  The color of the switch, when it's not checked (inner value is false).
 /// - `color_checked` - The color of the switch, when it's checked (inner value is true).
+/// - `color_focus` - The color of the focus ring, when the switch is focused.
 ///
 /// The [WidgetId] is equal to `maycoon-widgets:Switch`.
 pub struct Switch {
     layout: MaybeSignal<LayoutStyle>,
     value: MaybeSignal<bool>,
-    on_change: MaybeSignal<Update>,
+    on_change: Handler<bool>,
+    focus: Option<Focus>,
+    pointer: PointerId,
 }
 
 impl Switch {
@@ -49,7 +60,9 @@ impl Switch {
             }
             .into(),
             value: value.into(),
-            on_change: Update::empty().into(),
+            on_change: Handler::none(),
+            focus: None,
+            pointer: PointerId::new(),
         }
     }
 
@@ -60,12 +73,27 @@ impl Switch {
         self
     }
 
-    /// Sets the update value to apply on changes and returns self.
+    /// Sets the handler to call with the new value on changes and returns self.
     #[inline(always)]
-    pub fn with_on_change(mut self, update: impl Into<MaybeSignal<Update>>) -> Self {
-        self.on_change = update.into();
+    pub fn with_on_change(
+        mut self,
+        on_change: impl FnMut(&bool, &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_change = Handler::new(on_change);
         self
     }
+
+    /// Toggle the value of the switch and return the update to apply.
+    #[inline(always)]
+    fn toggle(&mut self, context: &AppContext) -> Update {
+        let checked = !*self.value.get();
+
+        if let Some(sig) = self.value.as_signal() {
+            sig.set(checked);
+        }
+
+        self.on_change.call(&checked, context) | Update::DRAW
+    }
 }
 
 impl WidgetLayoutExt for Switch {
@@ -82,7 +110,7 @@ impl Widget for Switch {
         theme: &mut dyn Theme,
         layout_node: &LayoutNode,
         _: &AppInfo,
-        _: AppContext,
+        context: AppContext,
     ) {
         let checked = *self.value.get();
 
@@ -128,6 +156,13 @@ impl Widget for Switch {
                 10.0,
             ),
         );
+
+        if self
+            .focus
+            .is_some_and(|focus| context.focus().is_focused(focus))
+        {
+            draw_focus_ring(scene, theme, self.widget_id(), &layout_node.layout, 60.0);
+        }
     }
 
     #[inline(always)]
@@ -135,28 +170,50 @@ impl Widget for Switch {
         StyleNode {
             style: self.layout.get().clone(),
             children: Vec::new(),
+            measure: None,
         }
     }
 
-    fn update(&mut self, layout: &LayoutNode, _: AppContext, info: &AppInfo) -> Update {
+    fn update(&mut self, layout: &LayoutNode, context: AppContext, info: &AppInfo) -> Update {
         let mut update = Update::empty();
+        let focus = context.focus().register();
+
+        self.focus = Some(focus);
+
+        let pointer =
+            context
+                .pointer()
+                .register(self.pointer, &layout.layout, Propagation::Continue);
+
+        context
+            .pointer()
+            .set_cursor_icon(pointer, CursorIcon::Pointer);
 
         if let Some(cursor) = info.cursor_pos
             && layout::intersects(cursor, &layout.layout)
         {
             for (_, btn, el) in &info.buttons {
-                if btn == &MouseButton::Left && *el == ElementState::Released {
-                    update |= *self.on_change.get();
-                    update |= Update::DRAW;
-
-                    if let Some(sig) = self.value.as_signal() {
-                        let checked = *sig.get();
-                        sig.set(!checked);
+                if btn == &MouseButton::Left {
+                    match el {
+                        ElementState::Pressed => context.focus().request(focus),
+                        ElementState::Released => update |= self.toggle(&context),
                     }
                 }
             }
         }
 
+        for key in context.focus().keys(focus, info) {
+            if key.is_pressed()
+                && !key.repeat
+                && matches!(
+                    key.logical_key,
+                    Key::Named(NamedKey::Space | NamedKey::Enter)
+                )
+            {
+                update |= self.toggle(&context);
+            }
+        }
+
         update
     }
 
@@ -0,0 +1,1260 @@
+use maycoon_core::app::context::AppContext;
+use maycoon_core::app::info::AppInfo;
+use maycoon_core::app::pointer::{PointerEventKind, PointerId, Propagation};
+use maycoon_core::app::update::Update;
+use maycoon_core::layout;
+use maycoon_core::layout::{
+    AlignItems, Dimension, Display, GridAutoFlow, JustifyContent, Layout, LayoutNode, LayoutStyle,
+    LengthPercentage, LengthPercentageAuto, Overflow, Position, StyleNode,
+};
+use maycoon_core::signal::state::StateSignal;
+use maycoon_core::signal::{MaybeSignal, Signal};
+use maycoon_core::vgi::kurbo::{Point, Rect, Triangle};
+use maycoon_core::vgi::{Brush, Color, Scene};
+use maycoon_core::widget::{BoxedWidget, Widget, WidgetLayoutExt};
+use maycoon_core::window::{CursorIcon, ElementState, MouseButton};
+use maycoon_theme::id::WidgetId;
+use maycoon_theme::theme::Theme;
+use nalgebra::Vector2;
+use std::cmp::Ordering;
+
+use crate::recycler::Recycler;
+use crate::scrollbar::{Scrollbars, Scroller, layout_of, rect_of};
+
+/// The horizontal padding of the table cells.
+const CELL_PADDING: f32 = 6.0;
+
+/// The distance from a column edge in which the column can be resized.
+const RESIZE_HANDLE: f32 = 4.0;
+
+/// A table displaying a large number of rows in columns.
+///
+/// The table does not own any data: Each [Column] builds its cells using a closure,
+/// which gets a signal containing the index of the row to display.
+/// Like the [ListView](crate::list_view::ListView), only the visible rows are built, laid out and rendered
+/// and rows scrolled out of view are recycled by setting their index signal.
+///
+/// The table supports following interactions:
+/// - Clicking a header of a sortable column (see [Column::with_sort]) sorts the rows by that column.
+///   Clicking it again reverses the order and clicking it a third time removes the sorting.
+/// - Dragging the right edge of a header resizes the column.
+/// - Clicking a row selects it. If the [SelectionMode] is [SelectionMode::Multiple],
+///   `Ctrl` toggles the clicked row and `Shift` selects the range from the last clicked row.
+/// - Scrolling with the mouse wheel or the scrollbars. Hold `Shift` to scroll horizontally.
+///
+/// Clicks are routed by the [PointerManager](maycoon_core::app::pointer::PointerManager),
+/// so widgets inside the headers and cells stopping the propagation (like a [Button](crate::button::Button))
+/// handle their clicks without sorting or selecting.
+///
+/// The sort state and the selection can be observed and changed by passing signals to [Table::with_sort] and [Table::with_selection].
+/// The rows are only sorted again when the sort state or the row count changes.
+/// If the data changes otherwise, set the sort signal to its current value to sort the rows again.
+///
+/// The cells are laid out as a grid per row, with one column track per [Column].
+/// The thickness of the scrollbars is the `scrollbar_width` of the [LayoutStyle].
+///
+/// See the [table](https://github.com/maycoon-ui/maycoon/blob/master/examples/table/src/main.rs) example for how to use it in practice.
+///
+/// ### Theming
+/// You can style the table using following properties:
+/// - `color_header` - The background color of the header.
+/// - `color_border` - The color of the lines between rows and columns.
+/// - `color_hover` - The background color of the hovered row.
+/// - `color_selection` - The background color of selected rows.
+/// - `color_sort` - The color of the sort indicator.
+/// - `color_track` - The color of the scrollbar tracks.
+/// - `color_thumb` - The color of the scrollbar thumbs.
+/// - `color_thumb_active` - The color of a scrollbar thumb while it's dragged.
+///
+/// The [WidgetId] is equal to `maycoon-widgets:Table`.
+pub struct Table {
+    columns: Vec<Column>,
+    widths: Vec<f32>,
+    count: MaybeSignal<usize>,
+    style: MaybeSignal<LayoutStyle>,
+    row_height: f32,
+    overscan: usize,
+    offset: MaybeSignal<Vector2<f32>>,
+    scroll_step: f32,
+    sort: MaybeSignal<Option<Sort>>,
+    selection: MaybeSignal<Vec<usize>>,
+    selection_mode: SelectionMode,
+    sorted: Option<(Sort, usize)>,
+    order: Vec<usize>,
+    anchor: Option<usize>,
+    hovered: Option<usize>,
+    resize: Option<Resize>,
+    scroller: Scroller,
+    rows: Recycler<Vec<BoxedWidget>>,
+    header_pointer: PointerId,
+    viewport_pointer: PointerId,
+}
+
+impl Table {
+    /// Creates a new table with the given row count and columns.
+    #[inline(always)]
+    pub fn new(count: impl Into<MaybeSignal<usize>>, columns: Vec<Column>) -> Self {
+        Self {
+            widths: columns.iter().map(|column| column.width).collect(),
+            columns,
+            count: count.into(),
+            style: LayoutStyle {
+                size: Vector2::new(Dimension::percent(1.0), Dimension::percent(1.0)),
+                scrollbar_width: 10.0,
+                ..Default::default()
+            }
+            .into(),
+            row_height: 28.0,
+            overscan: 3,
+            offset: Vector2::zeros().into(),
+            scroll_step: 40.0,
+            sort: None.into(),
+            selection: Vec::new().into(),
+            selection_mode: SelectionMode::Single,
+            sorted: None,
+            order: Vec::new(),
+            anchor: None,
+            hovered: None,
+            resize: None,
+            scroller: Scroller::default(),
+            rows: Recycler::new(),
+            header_pointer: PointerId::new(),
+            viewport_pointer: PointerId::new(),
+        }
+    }
+
+    /// Sets the row count and returns itself.
+    #[inline(always)]
+    pub fn with_count(mut self, count: impl Into<MaybeSignal<usize>>) -> Self {
+        self.count = count.into();
+        self
+    }
+
+    /// Sets the height of the header and the rows and returns itself. Defaults to `28.0`.
+    #[inline(always)]
+    pub fn with_row_height(mut self, row_height: f32) -> Self {
+        self.row_height = row_height;
+        self
+    }
+
+    /// Sets the number of rows to build above and below the visible rows and returns itself. Defaults to `3`.
+    #[inline(always)]
+    pub fn with_overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    /// Sets the scroll offset and returns itself.
+    ///
+    /// If the offset is a signal, it's updated whenever the table scrolls.
+    /// Setting the signal scrolls the table to the given offset, which is clamped to the scrollable area.
+    #[inline(always)]
+    pub fn with_offset(mut self, offset: impl Into<MaybeSignal<Vector2<f32>>>) -> Self {
+        self.offset = offset.into();
+        self
+    }
+
+    /// Sets the distance in pixels to scroll per line of the mouse wheel and returns itself. Defaults to `40.0`.
+    #[inline(always)]
+    pub fn with_scroll_step(mut self, step: f32) -> Self {
+        self.scroll_step = step;
+        self
+    }
+
+    /// Sets the sort state and returns itself. Defaults to [None], which displays the rows in their original order.
+    ///
+    /// If the sort state is a signal, it's updated whenever a header is clicked.
+    /// Sorting by a column without a comparison function has no effect.
+    #[inline(always)]
+    pub fn with_sort(mut self, sort: impl Into<MaybeSignal<Option<Sort>>>) -> Self {
+        self.sort = sort.into();
+        self
+    }
+
+    /// Sets the selected rows and returns itself.
+    ///
+    /// The selection contains the indices of the selected rows in ascending order, independent of the sorting.
+    /// If the selection is a signal, it's updated whenever the selection changes.
+    #[inline(always)]
+    pub fn with_selection(mut self, selection: impl Into<MaybeSignal<Vec<usize>>>) -> Self {
+        self.selection = selection.into();
+        self
+    }
+
+    /// Sets the selection mode and returns itself. Defaults to [SelectionMode::Single].
+    #[inline(always)]
+    pub fn with_selection_mode(mut self, mode: SelectionMode) -> Self {
+        self.selection_mode = mode;
+        self
+    }
+
+    /// Set the scroll offset. The offset is only stored locally, if it's not a signal.
+    #[inline(always)]
+    fn set_offset(&mut self, offset: Vector2<f32>) {
+        if let Some(sig) = self.offset.as_signal() {
+            sig.set(offset);
+        } else {
+            self.offset = offset.into();
+        }
+    }
+
+    /// Set the sort state. The sort state is only stored locally, if it's not a signal.
+    #[inline(always)]
+    fn set_sort(&mut self, sort: Option<Sort>) {
+        if let Some(sig) = self.sort.as_signal() {
+            sig.set(sort);
+        } else {
+            self.sort = sort.into();
+        }
+    }
+
+    /// Set the selection. The selection is only stored locally, if it's not a signal.
+    #[inline(always)]
+    fn set_selection(&mut self, selection: Vec<usize>) {
+        if let Some(sig) = self.selection.as_signal() {
+            sig.set(selection);
+        } else {
+            self.selection = selection.into();
+        }
+    }
+
+    /// Get the index of the row displayed at the given position.
+    #[inline(always)]
+    fn index(&self, position: usize) -> usize {
+        self.order.get(position).copied().unwrap_or(position)
+    }
+
+    /// Sort the rows, if the sort state or the row count changed.
+    ///
+    /// Returns `true` if the order of the rows changed.
+    fn sort_rows(&mut self, count: usize) -> bool {
+        let sort = *self.sort.get();
+        let key = sort.map(|sort| (sort, count));
+
+        if key == self.sorted {
+            return false;
+        }
+
+        self.sorted = key;
+        self.anchor = None;
+        self.order.clear();
+
+        if let Some(sort) = sort
+            && let Some(compare) = self
+                .columns
+                .get(sort.column)
+                .and_then(|column| column.compare.as_ref())
+        {
+            self.order.extend(0..count);
+
+            self.order.sort_by(|a, b| match sort.order {
+                SortOrder::Ascending => compare(*a, *b),
+                SortOrder::Descending => compare(*b, *a),
+            });
+        }
+
+        true
+    }
+
+    /// Compute the header area and the scrollbar geometry from the layout of the table.
+    fn geometry(&self, layout: &Layout, count: usize) -> (Rect, Scrollbars) {
+        let width = self.style.get().scrollbar_width;
+        let right = (layout.location.x + layout.size.width - width) as f64;
+
+        let header = Rect::new(
+            layout.location.x as f64,
+            layout.location.y as f64,
+            right,
+            (layout.location.y + self.row_height) as f64,
+        );
+
+        let bars = Scrollbars::new(
+            Rect::new(
+                header.x0,
+                header.y1,
+                right,
+                (layout.location.y + layout.size.height - width).max(header.y1 as f32) as f64,
+            ),
+            Vector2::new(width, width),
+            Vector2::new(self.widths.iter().sum(), count as f32 * self.row_height),
+        );
+
+        (header, bars)
+    }
+
+    /// Get the column at the given horizontal position, relative to the start of the first column.
+    fn column_at(&self, x: f32) -> Option<usize> {
+        let mut end = 0.0;
+
+        self.widths.iter().position(|width| {
+            end += width;
+            x < end
+        })
+    }
+
+    /// Select the row at the given position, according to the selection mode and the pressed modifiers.
+    fn select(&mut self, position: usize, info: &AppInfo) {
+        let index = self.index(position);
+        let ctrl = info.modifiers.control_key() || info.modifiers.super_key();
+        let mut selection = self.selection.get().to_vec();
+
+        match self.selection_mode {
+            SelectionMode::None => return,
+            SelectionMode::Single => selection = vec![index],
+            SelectionMode::Multiple => {
+                if info.modifiers.shift_key()
+                    && let Some(anchor) = self.anchor
+                {
+                    if !ctrl {
+                        selection.clear();
+                    }
+
+                    selection.extend(
+                        (anchor.min(position)..=anchor.max(position)).map(|pos| self.index(pos)),
+                    );
+                    selection.sort_unstable();
+                    selection.dedup();
+
+                    // keep the anchor to extend the range from the same row
+                    self.set_selection(selection);
+                    return;
+                } else if ctrl {
+                    match selection.binary_search(&index) {
+                        Ok(i) => {
+                            selection.remove(i);
+                        },
+                        Err(i) => selection.insert(i, index),
+                    }
+                } else {
+                    selection = vec![index];
+                }
+            },
+        }
+
+        self.anchor = Some(position);
+        self.set_selection(selection);
+    }
+
+    /// Sort by the given column or change the sort order, if the column is already sorted.
+    fn toggle_sort(&mut self, column: usize) {
+        if self.columns[column].compare.is_none() {
+            return;
+        }
+
+        let sort = match *self.sort.get() {
+            Some(Sort {
+                column: sorted,
+                order: SortOrder::Ascending,
+            }) if sorted == column => Some(Sort {
+                column,
+                order: SortOrder::Descending,
+            }),
+            Some(Sort {
+                column: sorted,
+                order: SortOrder::Descending,
+            }) if sorted == column => None,
+            _ => Some(Sort {
+                column,
+                order: SortOrder::Ascending,
+            }),
+        };
+
+        self.set_sort(sort);
+    }
+
+    /// Get the layout style of a row with the given cells, positioned at the given top.
+    fn row_style(&self, top: f32, cells: Vec<StyleNode>) -> StyleNode {
+        StyleNode {
+            style: LayoutStyle {
+                display: Display::Grid,
+                grid_auto_flow: GridAutoFlow::Column,
+                position: Position::Absolute,
+                inset: layout::Rect {
+                    left: LengthPercentageAuto::length(0.0),
+                    right: LengthPercentageAuto::auto(),
+                    top: LengthPercentageAuto::length(top),
+                    bottom: LengthPercentageAuto::auto(),
+                },
+                size: Vector2::new(
+                    Dimension::length(self.widths.iter().sum()),
+                    Dimension::length(self.row_height),
+                ),
+                ..Default::default()
+            },
+            children: cells
+                .into_iter()
+                .enumerate()
+                .map(|(i, cell)| StyleNode {
+                    style: LayoutStyle {
+                        size: Vector2::new(
+                            Dimension::length(self.widths[i]),
+                            Dimension::percent(1.0),
+                        ),
+                        padding: layout::Rect {
+                            left: LengthPercentage::length(CELL_PADDING),
+                            right: LengthPercentage::length(CELL_PADDING),
+                            top: LengthPercentage::length(0.0),
+                            bottom: LengthPercentage::length(0.0),
+                        },
+                        overflow: (Overflow::Hidden, Overflow::Hidden),
+                        align_items: Some(AlignItems::Center),
+                        justify_content: Some(self.columns[i].align),
+                        ..Default::default()
+                    },
+                    children: vec![cell],
+                    measure: None,
+                })
+                .collect(),
+            measure: None,
+        }
+    }
+}
+
+impl WidgetLayoutExt for Table {
+    #[inline(always)]
+    fn set_layout_style(&mut self, layout_style: impl Into<MaybeSignal<LayoutStyle>>) {
+        self.style = layout_style.into();
+    }
+}
+
+impl Widget for Table {
+    fn render(
+        &mut self,
+        scene: &mut dyn Scene,
+        theme: &mut dyn Theme,
+        layout_node: &LayoutNode,
+        info: &AppInfo,
+        context: AppContext,
+    ) {
+        let count = *self.count.get();
+        let (header, bars) = self.geometry(&layout_node.layout, count);
+        let offset = bars.clamp(*self.offset.get());
+        let viewport = bars.viewport;
+
+        let (color_header, color_border, color_hover, color_selection, color_sort) =
+            if let Some(style) = theme.of(self.widget_id()) {
+                (
+                    style.get_color("color_header").unwrap(),
+                    style.get_color("color_border").unwrap(),
+                    style.get_color("color_hover").unwrap(),
+                    style.get_color("color_selection").unwrap(),
+                    style.get_color("color_sort").unwrap(),
+                )
+            } else {
+                (
+                    theme.defaults().container().background(),
+                    theme.defaults().interactive().disabled(),
+                    theme.defaults().interactive().hover(),
+                    theme.defaults().interactive().active(),
+                    theme.defaults().text().foreground(),
+                )
+            };
+
+        let Some((header_node, row_nodes)) = layout_node.children.split_first() else {
+            return;
+        };
+
+        // header
+        scene.draw_rect(&Brush::Solid(color_header), None, None, &header);
+        scene.push_clip(None, &header);
+
+        let shift = Vector2::new(-offset.x, 0.0);
+
+        for (column, node) in self.columns.iter_mut().zip(&header_node.children) {
+            let node = node.translated(shift);
+
+            scene.push_clip(None, &rect_of(&node.layout));
+            column
+                .header
+                .render(scene, theme, &node.children[0], info, context.clone());
+            scene.pop_layer();
+        }
+
+        let mut edge = header.x0 - offset.x as f64;
+        let sort = *self.sort.get();
+
+        for (i, width) in self.widths.iter().enumerate() {
+            edge += *width as f64;
+
+            draw_line(
+                scene,
+                color_border,
+                Rect::new(edge - 1.0, header.y0, edge, header.y1),
+            );
+
+            if let Some(sort) = sort
+                && sort.column == i
+            {
+                let center = Point::new(edge - CELL_PADDING as f64 - 5.0, header.center().y);
+
+                let triangle = match sort.order {
+                    SortOrder::Ascending => Triangle::new(
+                        (center.x - 4.0, center.y + 2.0),
+                        (center.x + 4.0, center.y + 2.0),
+                        (center.x, center.y - 3.0),
+                    ),
+                    SortOrder::Descending => Triangle::new(
+                        (center.x - 4.0, center.y - 2.0),
+                        (center.x + 4.0, center.y - 2.0),
+                        (center.x, center.y + 3.0),
+                    ),
+                };
+
+                scene.draw_triangle(&Brush::Solid(color_sort), None, None, &triangle);
+            }
+        }
+
+        draw_line(
+            scene,
+            color_border,
+            Rect::new(header.x0, header.y1 - 1.0, header.x1, header.y1),
+        );
+
+        scene.pop_layer();
+
+        // rows
+        let range = self.rows.range();
+        let shift = Vector2::new(-offset.x, range.start as f32 * self.row_height - offset.y);
+        let right = viewport
+            .x1
+            .min(viewport.x0 + (self.widths.iter().sum::<f32>() - offset.x) as f64);
+        let selection = self.selection.get().to_vec();
+
+        scene.push_clip(None, &viewport);
+
+        for ((position, row), node) in range.zip(self.rows.rows_mut().iter_mut()).zip(row_nodes) {
+            let top = viewport.y0 + (position as f32 * self.row_height - offset.y) as f64;
+            let rect = Rect::new(viewport.x0, top, right, top + self.row_height as f64);
+
+            if selection.binary_search(&*row.index.get()).is_ok() {
+                scene.draw_rect(&Brush::Solid(color_selection), None, None, &rect);
+            } else if self.hovered == Some(position) {
+                scene.draw_rect(&Brush::Solid(color_hover), None, None, &rect);
+            }
+
+            for (cell, node) in row.item.iter_mut().zip(&node.children) {
+                let node = node.translated(shift);
+
+                scene.push_clip(None, &rect_of(&node.layout));
+                cell.render(scene, theme, &node.children[0], info, context.clone());
+                scene.pop_layer();
+            }
+
+            draw_line(
+                scene,
+                color_border,
+                Rect::new(rect.x0, rect.y1 - 1.0, rect.x1, rect.y1),
+            );
+        }
+
+        scene.pop_layer();
+
+        self.scroller
+            .render(scene, theme, self.widget_id(), &bars, offset);
+    }
+
+    fn layout_style(&self) -> StyleNode {
+        let style = self.style.get();
+        let first = self.rows.range().start;
+
+        let mut children = Vec::with_capacity(self.rows.rows().len() + 1);
+
+        children.push(
+            self.row_style(
+                0.0,
+                self.columns
+                    .iter()
+                    .map(|column| column.header.layout_style())
+                    .collect(),
+            ),
+        );
+
+        // rows are positioned relative to the first built row and moved by the scroll offset when rendering
+        for (position, row) in self.rows.range().zip(self.rows.rows()) {
+            children.push(self.row_style(
+                (position - first + 1) as f32 * self.row_height,
+                row.item.iter().map(|cell| cell.layout_style()).collect(),
+            ));
+        }
+
+        StyleNode {
+            style: LayoutStyle {
+                overflow: (Overflow::Hidden, Overflow::Hidden),
+                ..style.clone()
+            },
+            children,
+            measure: None,
+        }
+    }
+
+    fn update(&mut self, layout: &LayoutNode, context: AppContext, info: &AppInfo) -> Update {
+        let mut update = Update::empty();
+        let count = *self.count.get();
+
+        if self.sort_rows(count) {
+            update.insert(Update::LAYOUT | Update::DRAW);
+        }
+
+        let (header, bars) = self.geometry(&layout.layout, count);
+        let old_offset = *self.offset.get();
+
+        // the routed pointer events refer to the offset the table was displayed at
+        let displayed = bars.clamp(old_offset);
+
+        // scroll before updating the cells, so they register their hit regions at the new offset
+        let (area, offset, redraw) = self.scroller.update(
+            &bars,
+            &layout.layout,
+            old_offset,
+            self.scroll_step,
+            &context,
+            info,
+        );
+
+        if redraw {
+            update.insert(Update::DRAW);
+        }
+
+        let pointer = context.pointer();
+
+        let (header_pointer, viewport_pointer) = pointer.scope(area, || {
+            // the regions are registered before updating the cells, so the cells are above them
+            let header_pointer = pointer.register(
+                self.header_pointer,
+                &layout_of(&header),
+                Propagation::Continue,
+            );
+            let viewport_pointer = pointer.register(
+                self.viewport_pointer,
+                &layout_of(&bars.viewport),
+                Propagation::Continue,
+            );
+
+            if let Some((header_node, row_nodes)) = layout.children.split_first() {
+                let shift = Vector2::new(-offset.x, 0.0);
+
+                pointer.clip(&header, || {
+                    pointer.scope(header_pointer, || {
+                        for (column, node) in self.columns.iter_mut().zip(&header_node.children) {
+                            let node = node.translated(shift);
+
+                            pointer.clip(&rect_of(&node.layout), || {
+                                update.insert(column.header.update(
+                                    &node.children[0],
+                                    context.clone(),
+                                    info,
+                                ));
+                            });
+                        }
+                    });
+
+                    // the resize handles are registered last, so they are above the headers
+                    let mut edge = header.x0 as f32 - offset.x;
+
+                    for (column, (width, id)) in self
+                        .widths
+                        .iter()
+                        .zip(self.columns.iter().map(|column| column.handle))
+                        .enumerate()
+                    {
+                        edge += width;
+
+                        let handle = pointer.register(
+                            id,
+                            &layout_of(&Rect::new(
+                                (edge - RESIZE_HANDLE) as f64,
+                                header.y0,
+                                (edge + RESIZE_HANDLE) as f64,
+                                header.y1,
+                            )),
+                            Propagation::StopButtons,
+                        );
+
+                        pointer.set_cursor_icon(handle, CursorIcon::ColResize);
+
+                        for event in pointer.events(handle) {
+                            match event.kind {
+                                PointerEventKind::Button(
+                                    MouseButton::Left,
+                                    ElementState::Pressed,
+                                ) => {
+                                    self.resize = Some(Resize {
+                                        column,
+                                        cursor: event.position.x,
+                                        width: *width,
+                                    });
+                                },
+
+                                PointerEventKind::Button(
+                                    MouseButton::Left,
+                                    ElementState::Released,
+                                ) if self.resize.is_some_and(|resize| resize.column == column) => {
+                                    self.resize = None;
+                                },
+
+                                _ => (),
+                            }
+                        }
+
+                        if self.resize.is_some_and(|resize| resize.column == column) {
+                            // the button might be released outside the header or the window
+                            pointer.capture(handle);
+                        }
+                    }
+                });
+
+                let shift = Vector2::new(
+                    -offset.x,
+                    self.rows.range().start as f32 * self.row_height - offset.y,
+                );
+
+                pointer.clip(&bars.viewport, || {
+                    pointer.scope(viewport_pointer, || {
+                        for (row, node) in self.rows.rows_mut().iter_mut().zip(row_nodes) {
+                            for (cell, node) in row.item.iter_mut().zip(&node.children) {
+                                let node = node.translated(shift);
+
+                                pointer.clip(&rect_of(&node.layout), || {
+                                    update.insert(cell.update(
+                                        &node.children[0],
+                                        context.clone(),
+                                        info,
+                                    ));
+                                });
+                            }
+                        }
+                    });
+                });
+            }
+
+            (header_pointer, viewport_pointer)
+        });
+
+        // resize columns
+        if let Some(resize) = self.resize
+            && let Some(cursor) = info.cursor_pos
+        {
+            let width = (resize.width + cursor.x - resize.cursor)
+                .max(self.columns[resize.column].min_width);
+
+            if !layout::equal(width, self.widths[resize.column]) {
+                self.widths[resize.column] = width;
+                update.insert(Update::LAYOUT | Update::DRAW);
+            }
+        }
+
+        // the position of the row at the given vertical position and offset
+        let row_height = self.row_height;
+        let position_at = |y: f32, offset: Vector2<f32>| {
+            Some(((y - bars.viewport.y0 as f32 + offset.y) / row_height) as usize)
+                .filter(|position| *position < count)
+        };
+
+        let hovered = info
+            .cursor_pos
+            .filter(|_| pointer.is_hovered(viewport_pointer))
+            .and_then(|cursor| position_at(cursor.y, offset));
+
+        if hovered != self.hovered {
+            self.hovered = hovered;
+            update.insert(Update::DRAW);
+        }
+
+        for event in pointer.events(header_pointer) {
+            if event.kind == PointerEventKind::Button(MouseButton::Left, ElementState::Pressed)
+                && let Some(column) =
+                    self.column_at(event.position.x - header.x0 as f32 + displayed.x)
+            {
+                self.toggle_sort(column);
+
+                if self.sort_rows(count) {
+                    update.insert(Update::LAYOUT | Update::DRAW);
+                }
+            }
+        }
+
+        for event in pointer.events(viewport_pointer) {
+            if event.kind == PointerEventKind::Button(MouseButton::Left, ElementState::Pressed)
+                && let Some(position) = position_at(event.position.y, displayed)
+            {
+                self.select(position, info);
+                update.insert(Update::DRAW);
+            }
+        }
+
+        // build the visible rows
+        let range = if count == 0 || self.row_height <= 0.0 {
+            0..0
+        } else {
+            let first = ((offset.y / self.row_height) as usize).min(count - 1);
+            let last = (((offset.y + bars.size().y) / self.row_height) as usize).min(count - 1);
+
+            first.saturating_sub(self.overscan)..(last + 1 + self.overscan).min(count)
+        };
+
+        let columns = &self.columns;
+        let order = &self.order;
+
+        if self.rows.build(
+            range,
+            |position| order.get(position).copied().unwrap_or(position),
+            |index| {
+                columns
+                    .iter()
+                    .map(|column| (column.cell)(index.clone()))
+                    .collect()
+            },
+        ) {
+            // recycled rows registered their hit regions at the position of their previous row
+            pointer.invalidate();
+            update.insert(Update::EVAL | Update::LAYOUT | Update::DRAW);
+        }
+
+        if offset != old_offset {
+            self.set_offset(offset);
+
+            // update again, so the hover state of the cells follows the new offset
+            update.insert(Update::EVAL | Update::DRAW);
+        }
+
+        update
+    }
+
+    #[inline(always)]
+    fn widget_id(&self) -> WidgetId {
+        WidgetId::new("maycoon-widgets", "Table")
+    }
+}
+
+/// A column of a [Table].
+pub struct Column {
+    header: BoxedWidget,
+    cell: Box<dyn Fn(StateSignal<usize>) -> BoxedWidget>,
+    width: f32,
+    min_width: f32,
+    align: JustifyContent,
+    compare: Option<Box<dyn Fn(usize, usize) -> Ordering>>,
+    handle: PointerId,
+}
+
+impl Column {
+    /// Creates a new column with the given header widget and cell builder.
+    ///
+    /// The cell builder gets a signal containing the index of the row to display.
+    #[inline(always)]
+    pub fn new<W: Widget + 'static>(
+        header: impl Widget + 'static,
+        cell: impl Fn(StateSignal<usize>) -> W + 'static,
+    ) -> Self {
+        Self {
+            header: Box::new(header),
+            cell: Box::new(move |index| Box::new(cell(index))),
+            width: 120.0,
+            min_width: 30.0,
+            align: JustifyContent::Start,
+            compare: None,
+            handle: PointerId::new(),
+        }
+    }
+
+    /// Sets the initial width of the column and returns itself. Defaults to `120.0`.
+    #[inline(always)]
+    pub fn with_width(mut self, width: f32) -> Self {
+        self.width = width.max(self.min_width);
+        self
+    }
+
+    /// Sets the minimum width the column can be resized to and returns itself. Defaults to `30.0`.
+    #[inline(always)]
+    pub fn with_min_width(mut self, min_width: f32) -> Self {
+        self.min_width = min_width;
+        self.width = self.width.max(min_width);
+        self
+    }
+
+    /// Sets the horizontal alignment of the header and the cells and returns itself. Defaults to [JustifyContent::Start].
+    #[inline(always)]
+    pub fn with_align(mut self, align: JustifyContent) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Makes the column sortable with the given function and returns itself.
+    ///
+    /// The function compares the rows with the given indices in ascending order.
+    #[inline(always)]
+    pub fn with_sort(mut self, compare: impl Fn(usize, usize) -> Ordering + 'static) -> Self {
+        self.compare = Some(Box::new(compare));
+        self
+    }
+}
+
+/// The sort state of a [Table].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Sort {
+    /// The index of the sorted column.
+    pub column: usize,
+    /// The sort order.
+    pub order: SortOrder,
+}
+
+/// The order of a sorted [Table] column.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum SortOrder {
+    /// The rows are sorted in ascending order.
+    Ascending,
+    /// The rows are sorted in descending order.
+    Descending,
+}
+
+/// How the rows of a [Table] can be selected.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum SelectionMode {
+    /// Rows cannot be selected.
+    None,
+    /// A single row can be selected.
+    Single,
+    /// Multiple rows can be selected using `Ctrl` and `Shift`.
+    Multiple,
+}
+
+/// The state of a resized column.
+#[derive(Copy, Clone, Debug)]
+struct Resize {
+    /// The index of the resized column.
+    column: usize,
+    /// The horizontal cursor position, when the resize started.
+    cursor: f32,
+    /// The width of the column, when the resize started.
+    width: f32,
+}
+
+/// Draws a border line.
+#[inline(always)]
+fn draw_line(scene: &mut dyn Scene, color: Color, rect: Rect) {
+    scene.draw_rect(&Brush::Solid(color), None, None, &rect);
+}
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use crate::button::Button;
+    use crate::table::{Column, SelectionMode, Sort, SortOrder, Table};
+    use crate::text::Text;
+    use maycoon_core::app::update::Update;
+    use maycoon_core::signal::Signal;
+    use maycoon_core::signal::state::StateSignal;
+    use maycoon_core::testing::Harness;
+    use maycoon_core::window::{ElementState, ModifiersState, MouseButton, MouseScrollDelta};
+    use maycoon_theme::theme::celeste::CelesteTheme;
+    use nalgebra::Vector2;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    /// Tests that header clicks, column resizing and row selection use the routed pointer events.
+    #[test]
+    fn test_table_pointer() {
+        let sort = StateSignal::new(None);
+        let selection = StateSignal::new(Vec::new());
+        let pressed = Rc::new(Cell::new(0));
+
+        let table = Table::new(
+            10,
+            vec![
+                Column::new(Text::new("Name".to_string()), |_| {
+                    Text::new("Cell".to_string())
+                })
+                .with_sort(|a, b| a.cmp(&b)),
+                Column::new(Text::new("Action".to_string()), {
+                    let pressed = pressed.clone();
+
+                    move |_| {
+                        let pressed = pressed.clone();
+
+                        Button::new(Text::new("x".to_string())).with_on_pressed(move |_, _| {
+                            pressed.set(pressed.get() + 1);
+
+                            Update::empty()
+                        })
+                    }
+                }),
+            ],
+        )
+        .with_row_height(60.0)
+        .with_sort(sort.maybe())
+        .with_selection(selection.maybe());
+
+        let mut harness = Harness::new(table, CelesteTheme::default(), Vector2::new(400.0, 300.0));
+
+        harness.settle(5);
+
+        // sort by the first column
+        harness.click(Vector2::new(50.0, 30.0));
+        harness.settle(5);
+
+        assert_eq!(
+            *sort.get(),
+            Some(Sort {
+                column: 0,
+                order: SortOrder::Ascending,
+            })
+        );
+
+        // select the second row
+        harness.click(Vector2::new(50.0, 150.0));
+        harness.settle(5);
+
+        assert_eq!(*selection.get(), vec![1]);
+
+        // the button inside the third row stops the click, so the row is not selected
+        let button = harness.layout().unwrap().children[3].children[1].children[0]
+            .layout
+            .location;
+
+        harness.click(Vector2::new(button.x + 2.0, button.y + 2.0));
+        harness.settle(5);
+
+        assert_eq!(pressed.get(), 1);
+        assert_eq!(*selection.get(), vec![1]);
+
+        // resize the first column by dragging its right edge without sorting
+        harness.cursor_move(Vector2::new(121.0, 30.0));
+        harness.mouse_input(MouseButton::Left, ElementState::Pressed);
+        harness.settle(5);
+
+        harness.cursor_move(Vector2::new(171.0, 30.0));
+        harness.settle(5);
+
+        harness.mouse_input(MouseButton::Left, ElementState::Released);
+        harness.settle(5);
+
+        assert_eq!(harness.widget().widths[0], 170.0);
+        assert_eq!(
+            *sort.get(),
+            Some(Sort {
+                column: 0,
+                order: SortOrder::Ascending,
+            })
+        );
+
+        // the column keeps its width after releasing the button
+        harness.cursor_move(Vector2::new(250.0, 30.0));
+        harness.settle(5);
+
+        assert_eq!(harness.widget().widths[0], 170.0);
+    }
+
+    /// Create a column with the given header.
+    fn column(name: &str) -> Column {
+        Column::new(Text::new(name.to_string()), |_| {
+            Text::new("Cell".to_string())
+        })
+    }
+
+    /// Get the center of the row at the given position in a table with rows of height `30.0`.
+    fn row(position: usize) -> Vector2<f32> {
+        Vector2::new(50.0, 30.0 * position as f32 + 45.0)
+    }
+
+    /// Get the item indices of the built rows.
+    fn built(table: &Table) -> Vec<usize> {
+        table
+            .rows
+            .rows()
+            .iter()
+            .map(|row| *row.index.get())
+            .collect()
+    }
+
+    /// Tests that clicking a header cycles through the sort orders and only sorts by sortable columns.
+    #[test]
+    fn test_table_sort() {
+        const VALUES: [u32; 6] = [5, 3, 8, 1, 9, 2];
+
+        let count = StateSignal::new(VALUES.len());
+        let sort = StateSignal::new(None);
+
+        let table = Table::new(
+            count.maybe(),
+            vec![
+                column("Value")
+                    .with_sort(|a, b| VALUES.get(a).unwrap_or(&0).cmp(VALUES.get(b).unwrap_or(&0))),
+                column("Index"),
+            ],
+        )
+        .with_row_height(30.0)
+        .with_sort(sort.maybe());
+
+        let mut harness = Harness::new(table, CelesteTheme::default(), Vector2::new(400.0, 300.0));
+
+        harness.settle(5);
+
+        // the second column has no comparison function
+        harness.click(Vector2::new(150.0, 15.0));
+        harness.settle(5);
+
+        assert_eq!(*sort.get(), None);
+        assert_eq!(built(harness.widget()), [0, 1, 2, 3, 4, 5]);
+
+        harness.click(Vector2::new(50.0, 15.0));
+        harness.settle(5);
+
+        assert_eq!(
+            *sort.get(),
+            Some(Sort {
+                column: 0,
+                order: SortOrder::Ascending,
+            })
+        );
+        assert_eq!(built(harness.widget()), [3, 5, 1, 0, 2, 4]);
+
+        harness.click(Vector2::new(50.0, 15.0));
+        harness.settle(5);
+
+        assert_eq!(
+            *sort.get(),
+            Some(Sort {
+                column: 0,
+                order: SortOrder::Descending,
+            })
+        );
+        assert_eq!(built(harness.widget()), [4, 2, 0, 1, 5, 3]);
+
+        // new rows are sorted in, when the row count changes
+        count.set(8);
+        harness.force_update();
+        harness.settle(5);
+
+        assert_eq!(built(harness.widget()), [4, 2, 0, 1, 5, 3, 6, 7]);
+
+        harness.click(Vector2::new(50.0, 15.0));
+        harness.settle(5);
+
+        assert_eq!(*sort.get(), None);
+        assert_eq!(built(harness.widget()), [0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    /// Tests multi-selection with `Ctrl` and `Shift`, anchored at the position of the last clicked row.
+    #[test]
+    fn test_table_selection() {
+        let selection = StateSignal::new(Vec::new());
+
+        // the rows are displayed in reverse order, so the position `p` displays the index `9 - p`
+        let table = Table::new(10, vec![column("Index").with_sort(|a, b| a.cmp(&b))])
+            .with_row_height(30.0)
+            .with_sort(Some(Sort {
+                column: 0,
+                order: SortOrder::Descending,
+            }))
+            .with_selection(selection.maybe())
+            .with_selection_mode(SelectionMode::Multiple);
+
+        let mut harness = Harness::new(table, CelesteTheme::default(), Vector2::new(400.0, 400.0));
+
+        harness.settle(5);
+
+        let mut click = |modifiers: ModifiersState, position: usize| {
+            harness.set_modifiers(modifiers);
+            harness.click(row(position));
+            harness.settle(5);
+
+            selection.get().to_vec()
+        };
+
+        assert_eq!(click(ModifiersState::empty(), 1), [8]);
+        assert_eq!(click(ModifiersState::SHIFT, 3), [6, 7, 8]);
+
+        // the anchor stays at the first clicked row
+        assert_eq!(click(ModifiersState::SHIFT, 0), [8, 9]);
+
+        // `Ctrl` toggles a row and moves the anchor
+        assert_eq!(click(ModifiersState::CONTROL, 4), [5, 8, 9]);
+        assert_eq!(
+            click(ModifiersState::CONTROL | ModifiersState::SHIFT, 6),
+            [3, 4, 5, 8, 9]
+        );
+        assert_eq!(click(ModifiersState::CONTROL, 0), [3, 4, 5, 8]);
+
+        // a click without modifiers selects a single row
+        assert_eq!(click(ModifiersState::empty(), 2), [7]);
+    }
+
+    /// Tests that resizing is clamped to the minimum width and ends when releasing the button outside the window.
+    #[test]
+    fn test_table_resize() {
+        let table = Table::new(
+            10,
+            vec![
+                column("First").with_width(100.0).with_min_width(40.0),
+                column("Second"),
+            ],
+        )
+        .with_row_height(30.0);
+
+        let mut harness = Harness::new(table, CelesteTheme::default(), Vector2::new(400.0, 300.0));
+
+        harness.settle(5);
+
+        harness.cursor_move(Vector2::new(101.0, 15.0));
+        harness.mouse_input(MouseButton::Left, ElementState::Pressed);
+        harness.settle(5);
+
+        // the cursor may leave the header while resizing
+        harness.cursor_move(Vector2::new(151.0, 200.0));
+        harness.settle(5);
+
+        assert_eq!(harness.widget().widths, [150.0, 120.0]);
+
+        harness.cursor_move(Vector2::new(1.0, 15.0));
+        harness.settle(5);
+
+        assert_eq!(harness.widget().widths, [40.0, 120.0]);
+
+        harness.cursor_leave();
+        harness.mouse_input(MouseButton::Left, ElementState::Released);
+        harness.settle(5);
+
+        harness.cursor_move(Vector2::new(200.0, 15.0));
+        harness.settle(5);
+
+        assert_eq!(harness.widget().widths, [40.0, 120.0]);
+    }
+
+    /// Tests that only the rows around a non-zero offset are built and clicks select the rows displayed under the cursor.
+    #[test]
+    fn test_table_offset() {
+        let offset = StateSignal::new(Vector2::new(0.0, 3000.0));
+        let selection = StateSignal::new(Vec::new());
+
+        let table = Table::new(1000, vec![column("Index")])
+            .with_row_height(30.0)
+            .with_overscan(2)
+            .with_offset(offset.maybe())
+            .with_selection(selection.maybe());
+
+        let mut harness = Harness::new(table, CelesteTheme::default(), Vector2::new(400.0, 300.0));
+
+        harness.settle(5);
+
+        // the viewport displays the rows 100 to 108
+        assert_eq!(harness.widget().rows.range(), 98..111);
+        assert_eq!(built(harness.widget()), (98..111).collect::<Vec<_>>());
+
+        harness.click(row(0));
+        harness.settle(5);
+
+        assert_eq!(*selection.get(), [100]);
+        assert_eq!(harness.widget().hovered, Some(100));
+
+        // click right after scrolling by 40 pixels
+        harness.scroll(MouseScrollDelta::LineDelta(0.0, -1.0));
+        harness.frame();
+
+        harness.click(row(0));
+        harness.settle(5);
+
+        assert_eq!(*offset.get(), Vector2::new(0.0, 3040.0));
+        assert_eq!(harness.widget().rows.range(), 99..113);
+        assert_eq!(*selection.get(), [101]);
+        assert_eq!(harness.widget().hovered, Some(101));
+    }
+}
@@ -2,17 +2,24 @@ use maycoon_core::app::context::AppContext;
 use maycoon_core::app::info::AppInfo;
 use maycoon_core::app::update::Update;
 use maycoon_core::layout;
-use maycoon_core::layout::{Dimension, LayoutNode, LayoutStyle, StyleNode};
+use maycoon_core::layout::{AvailableSpace, LayoutNode, LayoutStyle, MeasureFn, StyleNode};
 use maycoon_core::signal::MaybeSignal;
-use maycoon_core::vgi::{Brush, Scene};
+use maycoon_core::text::layout::TextLayout;
+use maycoon_core::vgi::{Brush, FontData, Scene};
 use maycoon_core::widget::{Widget, WidgetLayoutExt};
 use maycoon_theme::id::WidgetId;
 use maycoon_theme::theme::Theme;
 use nalgebra::Vector2;
+use std::cell::RefCell;
 use std::ops::Deref;
+use std::rc::Rc;
 
 /// Displays the given text with optional font, size and hinting.
 ///
+/// The text is shaped and measured using a [TextLayout] with the fallback fonts of the application.
+/// If wrapping is enabled, the text is wrapped when it is wider than the available space
+/// and the height of the widget grows with the number of lines.
+///
 /// See the [hello-world](https://github.com/maycoon-ui/maycoon/blob/master/examples/hello-world/src/main.rs) example for how to use it in practice.
 ///
 /// ### Theming
@@ -31,7 +38,8 @@ pub struct Text {
     line_gap: MaybeSignal<f32>,
     wrap: MaybeSignal<bool>,
     hinting: MaybeSignal<bool>,
-    max_width: f32,
+    layouts: Rc<RefCell<TextLayouts>>,
+    measure: Option<MeasureFn>,
 }
 
 impl Text {
@@ -46,7 +54,8 @@ impl Text {
             line_gap: 7.5.into(),
             wrap: true.into(),
             hinting: true.into(),
-            max_width: 0.0,
+            layouts: Rc::new(RefCell::new(TextLayouts::default())),
+            measure: None,
         }
     }
 
@@ -89,6 +98,18 @@ impl Text {
         self.line_gap = gap.into();
         self
     }
+
+    /// Get the font of the text followed by the fallback fonts.
+    #[inline(always)]
+    fn fonts(&self, info: &AppInfo) -> Vec<FontData> {
+        let font = if let Some(name) = self.font.get().deref() {
+            info.font_context.get(name).expect("Font not found")
+        } else {
+            info.font_context.default_font().clone()
+        };
+
+        info.font_context.font_stack(&font)
+    }
 }
 
 impl WidgetLayoutExt for Text {
@@ -104,19 +125,9 @@ impl Widget for Text {
         scene: &mut dyn Scene,
         theme: &mut dyn Theme,
         layout_node: &LayoutNode,
-        info: &AppInfo,
+        _: &AppInfo,
         _: AppContext,
     ) {
-        let font_name = self.font.get();
-
-        let font = if font_name.is_some() {
-            info.font_context
-                .get(font_name.deref().clone().unwrap())
-                .expect("Font not found")
-        } else {
-            info.font_context.default_font().clone()
-        };
-
         let color = if let Some(style) = theme.of(Self::widget_id(self)) {
             if theme.globals().invert_text_color {
                 style.get_color("color_invert").unwrap()
@@ -127,49 +138,76 @@ impl Widget for Text {
             theme.defaults().text().foreground()
         };
 
-        if *self.wrap.get() {
-            self.max_width = layout_node.layout.size.width;
-        } else {
-            self.max_width = f32::INFINITY;
+        if let Some(text_layout) = self.layouts.borrow_mut().get(layout_node.layout.size.width) {
+            scene.draw_layout(
+                &Brush::Solid(color),
+                None,
+                Vector2::new(layout_node.layout.location.x, layout_node.layout.location.y),
+                text_layout,
+                *self.hinting.get(),
+            );
         }
-
-        scene.draw_text(
-            &Brush::Solid(color),
-            None,
-            Vector2::new(layout_node.layout.location.x, layout_node.layout.location.y),
-            self.text.get().as_str(),
-            *self.hinting.get(),
-            &font,
-            *self.font_size.get(),
-            *self.line_gap.get(),
-            self.max_width,
-        );
     }
 
+    #[inline(always)]
     fn layout_style(&self) -> StyleNode {
-        let text = self.text.get();
-
-        let font_size = *self.font_size.get();
-
-        let style = self.style.get().deref().clone();
-
         StyleNode {
-            style: LayoutStyle {
-                size: Vector2::new(
-                    Dimension::length(font_size * text.len() as f32),
-                    Dimension::length(font_size),
-                ),
-                ..style
-            },
+            style: self.style.get().clone(),
             children: Vec::new(),
+            // the text is not measured before the first update
+            measure: self.measure.clone(),
         }
     }
 
-    #[inline(always)]
-    fn update(&mut self, layout: &LayoutNode, _: AppContext, _: &AppInfo) -> Update {
-        // Re-layout if the maximum width of the text changes.
-        if *self.wrap.get() && !layout::equal(layout.layout.size.width, self.max_width) {
-            Update::LAYOUT
+    fn update(&mut self, _: &LayoutNode, _: AppContext, info: &AppInfo) -> Update {
+        let fonts = self.fonts(info);
+        let text = self.text.get();
+        let font_size = *self.font_size.get();
+        let line_gap = *self.line_gap.get();
+        let wrap = *self.wrap.get();
+
+        let mut layouts = self.layouts.borrow_mut();
+
+        // measure the text again, if it changed
+        if layouts.fonts != fonts
+            || layouts.wrap != wrap
+            || layouts.unwrapped.as_ref().is_none_or(|unwrapped| {
+                unwrapped.text() != text.as_str()
+                    || unwrapped.font_size() != font_size
+                    || unwrapped.line_gap() != line_gap
+            })
+        {
+            layouts.unwrapped = Some(TextLayout::new(
+                text.as_str(),
+                &fonts,
+                font_size,
+                line_gap,
+                f32::INFINITY,
+            ));
+            layouts.wrapped = None;
+            layouts.fonts = fonts;
+            layouts.wrap = wrap;
+
+            // a new measure function marks the layout node as changed
+            let shared = self.layouts.clone();
+
+            self.measure = Some(Rc::new(move |known, available| {
+                let max_width = known.x.unwrap_or(match available.x {
+                    AvailableSpace::Definite(width) => width,
+                    AvailableSpace::MinContent => 0.0,
+                    AvailableSpace::MaxContent => f32::INFINITY,
+                });
+
+                let size = shared
+                    .borrow_mut()
+                    .get(max_width)
+                    .map(|layout| layout.size())
+                    .unwrap_or_default();
+
+                Vector2::new(known.x.unwrap_or(size.x), known.y.unwrap_or(size.y))
+            }));
+
+            Update::LAYOUT | Update::DRAW
         } else {
             Update::empty()
         }
@@ -180,3 +218,41 @@ impl Widget for Text {
         WidgetId::new("maycoon-widgets", "Text")
     }
 }
+
+/// The text layouts of a [Text], shared with its measure function.
+#[derive(Default)]
+struct TextLayouts {
+    fonts: Vec<FontData>,
+    wrap: bool,
+    unwrapped: Option<TextLayout>,
+    wrapped: Option<TextLayout>,
+}
+
+impl TextLayouts {
+    /// Get the layout for the given maximum width. The text is only wrapped, if it does not fit.
+    ///
+    /// Returns [None] if the text was not measured yet.
+    fn get(&mut self, max_width: f32) -> Option<&TextLayout> {
+        let unwrapped = self.unwrapped.as_ref()?;
+
+        if !self.wrap || unwrapped.width() <= max_width + layout::LAYOUT_EPSILON {
+            return Some(unwrapped);
+        }
+
+        if self
+            .wrapped
+            .as_ref()
+            .is_none_or(|wrapped| !layout::equal(wrapped.max_width(), max_width))
+        {
+            self.wrapped = Some(TextLayout::new(
+                unwrapped.text(),
+                &self.fonts,
+                unwrapped.font_size(),
+                unwrapped.line_gap(),
+                max_width,
+            ));
+        }
+
+        self.wrapped.as_ref()
+    }
+}
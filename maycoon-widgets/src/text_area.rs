@@ -0,0 +1,1023 @@
+use maycoon_core::app::context::AppContext;
+use maycoon_core::app::focus::Focus;
+use maycoon_core::app::info::AppInfo;
+use maycoon_core::app::pointer::{PointerId, Propagation};
+use maycoon_core::app::update::Update;
+use maycoon_core::event::Handler;
+use maycoon_core::layout;
+use maycoon_core::layout::{Dimension, Layout, LayoutNode, LayoutStyle, StyleNode};
+use maycoon_core::signal::MaybeSignal;
+use maycoon_core::text::layout::TextLine;
+use maycoon_core::text::{line_height, text_width, wrap_lines};
+use maycoon_core::vgi::kurbo::{Rect, RoundedRect, RoundedRectRadii, Stroke};
+use maycoon_core::vgi::{Brush, FontData, Scene};
+use maycoon_core::widget::{Widget, WidgetLayoutExt};
+use maycoon_core::window::{
+    CursorIcon, ElementState, Ime, Key, ModifiersState, MouseButton, MouseScrollDelta, NamedKey,
+};
+use maycoon_theme::id::WidgetId;
+use maycoon_theme::theme::Theme;
+use nalgebra::Vector2;
+use std::ops::Deref;
+
+use crate::focus_ring::draw_focus_ring;
+use crate::text_edit::TextEditor;
+
+/// The space between the border and the text.
+const PADDING: f32 = 8.0;
+
+/// The indentation inserted by pressing `Tab`.
+const INDENT: &str = "    ";
+
+/// The number of lines to scroll per mouse wheel step.
+const SCROLL_LINES: f32 = 3.0;
+
+/// The position of the caret inside a [TextArea].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CaretPosition {
+    /// The zero-based line index. Lines are separated by `\n` and not affected by wrapping.
+    pub line: usize,
+    /// The zero-based column (character index) inside the line.
+    pub column: usize,
+}
+
+/// A multi-line text area for editing longer text.
+///
+/// The text is wrapped at the width of the text area, just like [Scene::draw_text] wraps text,
+/// and scrolls vertically using the mouse wheel or by moving the caret.
+///
+/// Once the text area is focused by clicking on it or using `Tab`, the text is edited via keyboard and mouse:
+/// - `Left`/`Right`/`Up`/`Down` move the caret, `Home`/`End` move it to the start/end of the line
+///   and `PageUp`/`PageDown` move it by a page.
+/// - Holding `Ctrl` (`Alt` on macOS) moves by words and deletes words using `Backspace`/`Delete`.
+///   `Ctrl+Home`/`Ctrl+End` move the caret to the start/end of the text.
+/// - Holding `Shift` or dragging the mouse selects text.
+/// - `Ctrl+A` selects everything, `Ctrl+C`/`Ctrl+X`/`Ctrl+V` copy, cut and paste the selection
+///   and `Ctrl+Z`/`Ctrl+Y` undo and redo edits (using `Cmd` on macOS).
+/// - `Tab`/`Shift+Tab` indent/outdent the selected lines. Use `Ctrl+Tab` to move the focus instead.
+///
+/// Text committed by an input method (IME) is inserted at the caret.
+///
+/// The cursor changes to a text cursor while hovering the text area.
+///
+/// The value should be a signal, so it's mutable and other widgets can react to edits.
+///
+/// See the [text_area](https://github.com/maycoon-ui/maycoon/blob/master/examples/text_area/src/main.rs) example for how to use it in practice.
+///
+/// ### Theming
+/// Styling the text area requires following properties:
+/// - `color_background` - The background color of the text area.
+/// - `color_border` - The color of the border.
+/// - `color_text` - The color of the text and the caret.
+/// - `color_line_number` - The color of the line numbers.
+/// - `color_selection` - The background color of selected text.
+/// - `color_focus` - The color of the focus ring, when the text area is focused.
+///
+/// The [WidgetId] is equal to `maycoon-widgets:TextArea`.
+pub struct TextArea {
+    layout_style: MaybeSignal<LayoutStyle>,
+    value: MaybeSignal<String>,
+    caret: MaybeSignal<CaretPosition>,
+    font: MaybeSignal<Option<String>>,
+    font_size: MaybeSignal<f32>,
+    line_gap: MaybeSignal<f32>,
+    line_numbers: MaybeSignal<bool>,
+    on_change: Handler<String>,
+    editor: TextEditor,
+    focus: Option<Focus>,
+    focused: bool,
+    dragging: bool,
+    preedit: bool,
+    scroll: f32,
+    goal_x: Option<f32>,
+    pointer: PointerId,
+}
+
+impl TextArea {
+    /// Create a new text area with the given value.
+    ///
+    /// The value should be a signal, so it's mutable.
+    #[inline(always)]
+    pub fn new(value: impl Into<MaybeSignal<String>>) -> Self {
+        Self {
+            layout_style: LayoutStyle {
+                size: Vector2::new(Dimension::length(400.0), Dimension::length(200.0)),
+                ..Default::default()
+            }
+            .into(),
+            value: value.into(),
+            caret: CaretPosition::default().into(),
+            font: None.into(),
+            font_size: 18.0.into(),
+            line_gap: 0.0.into(),
+            line_numbers: false.into(),
+            on_change: Handler::none(),
+            editor: TextEditor::default(),
+            focus: None,
+            focused: false,
+            dragging: false,
+            preedit: false,
+            scroll: 0.0,
+            goal_x: None,
+            pointer: PointerId::new(),
+        }
+    }
+
+    /// Sets the value of the text area and returns self.
+    #[inline(always)]
+    pub fn with_value(mut self, value: impl Into<MaybeSignal<String>>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    /// Sets the signal to store the [CaretPosition] in and returns self.
+    ///
+    /// The position is updated whenever the caret moves.
+    #[inline(always)]
+    pub fn with_caret(mut self, caret: impl Into<MaybeSignal<CaretPosition>>) -> Self {
+        self.caret = caret.into();
+        self
+    }
+
+    /// Sets the name of the font to use and returns self. Uses the default font if [None].
+    #[inline(always)]
+    pub fn with_font(mut self, font: impl Into<MaybeSignal<Option<String>>>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the font size and returns self.
+    #[inline(always)]
+    pub fn with_font_size(mut self, size: impl Into<MaybeSignal<f32>>) -> Self {
+        self.font_size = size.into();
+        self
+    }
+
+    /// Sets the gap between lines and returns self.
+    #[inline(always)]
+    pub fn with_line_gap(mut self, gap: impl Into<MaybeSignal<f32>>) -> Self {
+        self.line_gap = gap.into();
+        self
+    }
+
+    /// Sets if a gutter with line numbers should be shown and returns self.
+    #[inline(always)]
+    pub fn with_line_numbers(mut self, line_numbers: impl Into<MaybeSignal<bool>>) -> Self {
+        self.line_numbers = line_numbers.into();
+        self
+    }
+
+    /// Sets the handler to call with the new text, when the text changes, and returns self.
+    #[inline(always)]
+    pub fn with_on_change(
+        mut self,
+        on_change: impl FnMut(&String, &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_change = Handler::new(on_change);
+        self
+    }
+
+    /// Get the font of the text area.
+    #[inline(always)]
+    fn font(&self, info: &AppInfo) -> FontData {
+        if let Some(name) = self.font.get().deref() {
+            info.font_context.get(name).expect("Font not found")
+        } else {
+            info.font_context.default_font().clone()
+        }
+    }
+
+    /// Lay out the given text inside the given layout.
+    fn text_layout(&self, text: &str, layout: &Layout, font: &FontData) -> TextLayout {
+        let font_size = *self.font_size.get();
+
+        let gutter = if *self.line_numbers.get() {
+            let digits = (text.matches('\n').count() + 1).max(10).ilog10() as usize + 1;
+
+            text_width(font, &"0".repeat(digits), font_size) + PADDING * 2.0
+        } else {
+            0.0
+        };
+
+        let rect = Rect::new(
+            (layout.location.x + PADDING + gutter) as f64,
+            (layout.location.y + PADDING) as f64,
+            (layout.location.x + layout.size.width - PADDING) as f64,
+            (layout.location.y + layout.size.height - PADDING) as f64,
+        );
+
+        TextLayout {
+            lines: wrap_lines(font, text, font_size, rect.width() as f32),
+            rect,
+            gutter,
+            advance: line_height(font, font_size) + *self.line_gap.get(),
+        }
+    }
+
+    /// Scroll by the given amount, clamped to the text.
+    #[inline(always)]
+    fn scroll_by(&mut self, text_layout: &TextLayout, delta: f32) {
+        self.scroll = (self.scroll + delta).clamp(0.0, text_layout.max_scroll());
+    }
+
+    /// Scroll so the caret is visible.
+    #[inline(always)]
+    fn reveal(&mut self, text: &str, text_layout: &TextLayout) {
+        let top = text_layout.line_of(self.editor.cursor()) as f32 * text_layout.advance;
+        let height = text_layout.rect.height() as f32;
+
+        if top < self.scroll {
+            self.scroll = top;
+        } else if top + text_layout.advance > self.scroll + height {
+            self.scroll = top + text_layout.advance - height;
+        }
+
+        self.scroll = self.scroll.clamp(0.0, text_layout.max_scroll());
+
+        debug_assert!(self.editor.cursor() <= text.len());
+    }
+
+    /// Move the caret vertically by the given amount of lines, keeping the horizontal position.
+    fn move_vertical(&mut self, text: &str, text_layout: &TextLayout, lines: isize, select: bool) {
+        let cursor = self.editor.cursor();
+        let line = text_layout.line_of(cursor);
+        let x = *self
+            .goal_x
+            .get_or_insert_with(|| text_layout.x_of(text, cursor));
+
+        let target = line.saturating_add_signed(lines);
+
+        let pos = if lines < 0 && line == 0 {
+            0
+        } else if target >= text_layout.lines.len() {
+            text.len()
+        } else {
+            text_layout.hit_line(text, target, x)
+        };
+
+        self.editor.set_cursor(text, pos, select);
+    }
+
+    /// Apply the given key to the text.
+    ///
+    /// Returns if the text changed.
+    fn handle_key(
+        &mut self,
+        text: &mut String,
+        text_layout: &TextLayout,
+        key: &Key,
+        key_text: Option<&str>,
+        modifiers: ModifiersState,
+        context: &AppContext,
+    ) -> bool {
+        let (primary, word) = if cfg!(target_os = "macos") {
+            (modifiers.super_key(), modifiers.alt_key())
+        } else {
+            (modifiers.control_key(), modifiers.control_key())
+        };
+
+        let select = modifiers.shift_key();
+        let page = ((text_layout.rect.height() as f32 / text_layout.advance) as isize).max(1);
+
+        // keep the horizontal position while moving vertically
+        if !matches!(
+            key,
+            Key::Named(
+                NamedKey::ArrowUp | NamedKey::ArrowDown | NamedKey::PageUp | NamedKey::PageDown
+            )
+        ) {
+            self.goal_x = None;
+        }
+
+        match key {
+            Key::Named(NamedKey::ArrowLeft) => self.editor.move_left(text, word, select),
+
+            Key::Named(NamedKey::ArrowRight) => self.editor.move_right(text, word, select),
+
+            Key::Named(NamedKey::ArrowUp) => self.move_vertical(text, text_layout, -1, select),
+
+            Key::Named(NamedKey::ArrowDown) => self.move_vertical(text, text_layout, 1, select),
+
+            Key::Named(NamedKey::PageUp) => self.move_vertical(text, text_layout, -page, select),
+
+            Key::Named(NamedKey::PageDown) => self.move_vertical(text, text_layout, page, select),
+
+            Key::Named(NamedKey::Home) if primary => self.editor.set_cursor(text, 0, select),
+
+            Key::Named(NamedKey::End) if primary => {
+                self.editor.set_cursor(text, text.len(), select)
+            },
+
+            Key::Named(NamedKey::Home) => {
+                let line = &text_layout.lines[text_layout.line_of(self.editor.cursor())];
+
+                self.editor.set_cursor(text, line.range.start, select);
+            },
+
+            Key::Named(NamedKey::End) => {
+                let line = &text_layout.lines[text_layout.line_of(self.editor.cursor())];
+
+                self.editor.set_cursor(text, line.range.end, select);
+            },
+
+            Key::Named(NamedKey::Backspace) => return self.editor.delete_backward(text, word),
+
+            Key::Named(NamedKey::Delete) => return self.editor.delete_forward(text, word),
+
+            Key::Named(NamedKey::Enter) => return self.editor.insert(text, "\n"),
+
+            Key::Named(NamedKey::Tab) if select || self.editor.selected(text).contains('\n') => {
+                return self.editor.indent(text, INDENT, select);
+            },
+
+            Key::Named(NamedKey::Tab) => return self.editor.insert(text, INDENT),
+
+            Key::Character(c) if primary => match c.to_lowercase().as_str() {
+                "a" => self.editor.select_all(text),
+
+                "c" if self.editor.has_selection() => {
+                    context.clipboard().set_text(self.editor.selected(text))
+                },
+
+                "x" => {
+                    if self.editor.has_selection() {
+                        context.clipboard().set_text(self.editor.selected(text));
+                    }
+
+                    return self.editor.delete_selection(text);
+                },
+
+                "v" => {
+                    return self.editor.insert(
+                        text,
+                        &context
+                            .clipboard()
+                            .get_text()
+                            .unwrap_or_default()
+                            .replace('\r', ""),
+                    );
+                },
+
+                "z" if select => return self.editor.redo(text),
+
+                "z" => return self.editor.undo(text),
+
+                "y" => return self.editor.redo(text),
+
+                _ => (),
+            },
+
+            _ => {
+                if let Some(insert) = key_text
+                    && !insert.chars().any(char::is_control)
+                {
+                    return self.editor.insert(text, insert);
+                }
+            },
+        }
+
+        false
+    }
+}
+
+impl WidgetLayoutExt for TextArea {
+    #[inline(always)]
+    fn set_layout_style(&mut self, layout_style: impl Into<MaybeSignal<LayoutStyle>>) {
+        self.layout_style = layout_style.into();
+    }
+}
+
+impl Widget for TextArea {
+    fn render(
+        &mut self,
+        scene: &mut dyn Scene,
+        theme: &mut dyn Theme,
+        layout_node: &LayoutNode,
+        info: &AppInfo,
+        context: AppContext,
+    ) {
+        let (background, border, text_color, line_number_color, selection_color) =
+            if let Some(style) = theme.of(self.widget_id()) {
+                (
+                    style.get_color("color_background").unwrap(),
+                    style.get_color("color_border").unwrap(),
+                    style.get_color("color_text").unwrap(),
+                    style.get_color("color_line_number").unwrap(),
+                    style.get_color("color_selection").unwrap(),
+                )
+            } else {
+                (
+                    theme.defaults().container().background(),
+                    theme.defaults().interactive().inactive(),
+                    theme.defaults().text().foreground(),
+                    theme.defaults().interactive().disabled(),
+                    theme.defaults().interactive().hover(),
+                )
+            };
+
+        let layout = &layout_node.layout;
+        let font = self.font(info);
+        let font_size = *self.font_size.get();
+        let text = self.value.get().clone();
+        let text_layout = self.text_layout(&text, layout, &font);
+        let rect = text_layout.rect;
+
+        // the value might have been changed since the last update
+        self.editor.clamp(&text);
+
+        self.scroll = self.scroll.clamp(0.0, text_layout.max_scroll());
+
+        let bounds = RoundedRect::from_rect(
+            Rect::new(
+                layout.location.x as f64,
+                layout.location.y as f64,
+                (layout.location.x + layout.size.width) as f64,
+                (layout.location.y + layout.size.height) as f64,
+            ),
+            RoundedRectRadii::from_single_radius(5.0),
+        );
+
+        scene.draw_rounded_rect(&Brush::Solid(background), None, None, &bounds);
+        scene.draw_rounded_rect(
+            &Brush::Solid(border),
+            None,
+            Some(&Stroke::new(1.0)),
+            &bounds,
+        );
+
+        if text_layout.gutter > 0.0 {
+            let x = rect.x0 - PADDING as f64;
+
+            scene.draw_rect(
+                &Brush::Solid(border),
+                None,
+                None,
+                &Rect::new(
+                    x - 0.5,
+                    layout.location.y as f64,
+                    x + 0.5,
+                    rect.y1 + PADDING as f64,
+                ),
+            );
+        }
+
+        let selection = self.editor.selection();
+        let mut line_number = 0;
+
+        for (i, line) in text_layout.lines.iter().enumerate() {
+            // only wrapped lines continue the previous line
+            let continued = line.range.start > 0 && !text[..line.range.start].ends_with('\n');
+
+            if !continued {
+                line_number += 1;
+            }
+
+            let top = rect.y0 as f32 + i as f32 * text_layout.advance - self.scroll;
+
+            // only draw completely visible lines, since the text is not clipped
+            if top < rect.y0 as f32 - 0.5 || top + text_layout.advance > rect.y1 as f32 + 0.5 {
+                continue;
+            }
+
+            if self.focused
+                && selection.start <= line.range.end
+                && selection.end > line.range.start
+                && !selection.is_empty()
+            {
+                let start = text_layout.x_of(text.as_str(), selection.start.max(line.range.start));
+                let mut end = text_layout.x_of(text.as_str(), selection.end.min(line.range.end));
+
+                // indicate selected line breaks
+                if selection.end > line.range.end {
+                    end += font_size / 4.0;
+                }
+
+                scene.draw_rect(
+                    &Brush::Solid(selection_color),
+                    None,
+                    None,
+                    &Rect::new(
+                        rect.x0 + start as f64,
+                        top as f64,
+                        rect.x0 + end as f64,
+                        (top + text_layout.advance) as f64,
+                    ),
+                );
+            }
+
+            if !line.range.is_empty() {
+                scene.draw_text(
+                    &Brush::Solid(text_color),
+                    None,
+                    Vector2::new(rect.x0 as f32, top),
+                    &text[line.range.clone()],
+                    true,
+                    &font,
+                    font_size,
+                    0.0,
+                    f32::INFINITY,
+                );
+            }
+
+            if text_layout.gutter > 0.0 && !continued {
+                let number = line_number.to_string();
+                let width = text_width(&font, &number, font_size);
+
+                scene.draw_text(
+                    &Brush::Solid(line_number_color),
+                    None,
+                    Vector2::new(rect.x0 as f32 - PADDING * 2.0 - width, top),
+                    &number,
+                    true,
+                    &font,
+                    font_size,
+                    0.0,
+                    f32::INFINITY,
+                );
+            }
+        }
+
+        if self.focused {
+            let cursor = self.editor.cursor();
+            let top = rect.y0 as f32 + text_layout.line_of(cursor) as f32 * text_layout.advance
+                - self.scroll;
+
+            if top >= rect.y0 as f32 - 0.5 && top + text_layout.advance <= rect.y1 as f32 + 0.5 {
+                let x = rect.x0 + text_layout.x_of(text.as_str(), cursor) as f64;
+
+                scene.draw_rect(
+                    &Brush::Solid(text_color),
+                    None,
+                    None,
+                    &Rect::new(
+                        x - 0.75,
+                        top as f64,
+                        x + 0.75,
+                        (top + text_layout.advance) as f64,
+                    ),
+                );
+
+                context.set_ime_cursor_area(
+                    Vector2::new(x, top as f64),
+                    Vector2::new(1.0, text_layout.advance as f64),
+                );
+            }
+
+            draw_focus_ring(scene, theme, self.widget_id(), layout, 5.0);
+        }
+    }
+
+    #[inline(always)]
+    fn layout_style(&self) -> StyleNode {
+        StyleNode {
+            style: self.layout_style.get().clone(),
+            children: Vec::new(),
+            measure: None,
+        }
+    }
+
+    fn update(&mut self, layout: &LayoutNode, context: AppContext, info: &AppInfo) -> Update {
+        let mut update = Update::empty();
+        let focus = context.focus().register();
+
+        self.focus = Some(focus);
+
+        let pointer =
+            context
+                .pointer()
+                .register(self.pointer, &layout.layout, Propagation::Continue);
+
+        context.pointer().set_cursor_icon(pointer, CursorIcon::Text);
+
+        context.focus().capture_tab(focus);
+
+        let mut text = self.value.get().clone();
+        let old_text = text.clone();
+        let old_selection = (self.editor.cursor(), self.editor.selection());
+        let old_scroll = self.scroll;
+
+        // the value might have been changed from outside
+        self.editor.clamp(&text);
+
+        let font = self.font(info);
+        let mut text_layout = self.text_layout(&text, &layout.layout, &font);
+        let mut reveal = false;
+
+        // mouse selection
+        if let Some(cursor) = info.cursor_pos {
+            let hovered = layout::intersects(cursor, &layout.layout);
+
+            for (_, btn, el) in &info.buttons {
+                if *btn != MouseButton::Left {
+                    continue;
+                }
+
+                match el {
+                    ElementState::Pressed if hovered => {
+                        context.focus().request(focus);
+
+                        let pos = text_layout.hit(&text, cursor, self.scroll);
+
+                        self.editor
+                            .set_cursor(&text, pos, info.modifiers.shift_key());
+                        self.goal_x = None;
+                        self.dragging = true;
+                    },
+
+                    ElementState::Pressed => (),
+
+                    ElementState::Released => self.dragging = false,
+                }
+            }
+
+            if self.dragging {
+                let pos = text_layout.hit(&text, cursor, self.scroll);
+
+                self.editor.set_cursor(&text, pos, true);
+                reveal = true;
+            }
+
+            if hovered && let Some(delta) = info.mouse_scroll_delta {
+                let delta = match delta {
+                    MouseScrollDelta::LineDelta(_, y) => -y * SCROLL_LINES * text_layout.advance,
+                    MouseScrollDelta::PixelDelta(pos) => -pos.y as f32,
+                };
+
+                self.scroll_by(&text_layout, delta);
+            }
+        }
+
+        // allow the input method while focused
+        let focused = context.focus().is_focused(focus);
+
+        if focused != self.focused {
+            self.focused = focused;
+            self.preedit = false;
+
+            context.set_ime_allowed(focused);
+
+            update |= Update::DRAW;
+        }
+
+        for ime in context.focus().ime(focus, info) {
+            match ime {
+                Ime::Preedit(preedit, _) => self.preedit = !preedit.is_empty(),
+
+                Ime::Commit(commit) => {
+                    self.preedit = false;
+
+                    if self.editor.insert(&mut text, commit) {
+                        text_layout = self.text_layout(&text, &layout.layout, &font);
+                    }
+                },
+
+                Ime::Enabled | Ime::Disabled => self.preedit = false,
+            }
+
+            reveal = true;
+        }
+
+        for key in context.focus().keys(focus, info) {
+            // keys are handled by the input method while composing
+            if key.is_pressed() && !self.preedit {
+                if self.handle_key(
+                    &mut text,
+                    &text_layout,
+                    &key.logical_key,
+                    key.text.as_deref(),
+                    info.modifiers,
+                    &context,
+                ) {
+                    text_layout = self.text_layout(&text, &layout.layout, &font);
+                }
+
+                reveal = true;
+            }
+        }
+
+        let changed = text != old_text;
+
+        if changed {
+            if let Some(sig) = self.value.as_signal() {
+                sig.set(text.clone());
+
+                update |= self.on_change.call(&text, &context);
+
+                // the handler might have changed the value (e.g. to clear the text area)
+                if *self.value.get() != text {
+                    text = self.value.get().clone();
+                    text_layout = self.text_layout(&text, &layout.layout, &font);
+
+                    self.editor.clamp(&text);
+                }
+            } else {
+                // the value is not mutable, so reset the caret
+                text = old_text;
+                text_layout = self.text_layout(&text, &layout.layout, &font);
+
+                self.editor.clamp(&text);
+            }
+        }
+
+        if reveal {
+            self.reveal(&text, &text_layout);
+        }
+
+        if old_selection != (self.editor.cursor(), self.editor.selection())
+            || old_scroll != self.scroll
+            || changed
+        {
+            update |= Update::DRAW;
+        }
+
+        // update the caret position
+        if let Some(sig) = self.caret.as_signal() {
+            let before = &text[..self.editor.cursor()];
+
+            let caret = CaretPosition {
+                line: before.matches('\n').count(),
+                column: before
+                    .rsplit('\n')
+                    .next()
+                    .map(|line| line.chars().count())
+                    .unwrap_or_default(),
+            };
+
+            if *sig.get() != caret {
+                sig.set(caret);
+            }
+        }
+
+        update
+    }
+
+    #[inline(always)]
+    fn widget_id(&self) -> WidgetId {
+        WidgetId::new("maycoon-widgets", "TextArea")
+    }
+}
+
+/// The wrapped text of a [TextArea].
+struct TextLayout {
+    lines: Vec<TextLine>,
+    rect: Rect,
+    gutter: f32,
+    advance: f32,
+}
+
+impl TextLayout {
+    /// Returns the maximum scroll offset.
+    #[inline(always)]
+    fn max_scroll(&self) -> f32 {
+        (self.lines.len() as f32 * self.advance - self.rect.height() as f32).max(0.0)
+    }
+
+    /// Returns the index of the line containing the given text position.
+    #[inline(always)]
+    fn line_of(&self, pos: usize) -> usize {
+        self.lines
+            .partition_point(|line| line.range.start <= pos)
+            .saturating_sub(1)
+    }
+
+    /// Returns the horizontal offset of the given text position, relative to the text start.
+    #[inline(always)]
+    fn x_of(&self, text: &str, pos: usize) -> f32 {
+        let line = &self.lines[self.line_of(pos)];
+        let pos = pos.min(line.range.end);
+
+        line.offsets[text[line.range.start..pos].chars().count()]
+    }
+
+    /// Returns the text position in the given line, that is closest to the given horizontal offset.
+    fn hit_line(&self, text: &str, line: usize, x: f32) -> usize {
+        let line = &self.lines[line];
+
+        let idx = line
+            .offsets
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| (*a - x).abs().total_cmp(&(*b - x).abs()))
+            .map(|(i, _)| i)
+            .unwrap_or_default();
+
+        text[line.range.clone()]
+            .char_indices()
+            .nth(idx)
+            .map(|(i, _)| line.range.start + i)
+            .unwrap_or(line.range.end)
+    }
+
+    /// Returns the text position closest to the given point in window coordinates.
+    #[inline(always)]
+    fn hit(&self, text: &str, point: Vector2<f32>, scroll: f32) -> usize {
+        let y = point.y - self.rect.y0 as f32 + scroll;
+        let line = ((y / self.advance).max(0.0) as usize).min(self.lines.len() - 1);
+
+        self.hit_line(text, line, point.x - self.rect.x0 as f32)
+    }
+}
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use crate::text_area::{CaretPosition, TextArea};
+    use maycoon_core::app::update::Update;
+    use maycoon_core::signal::Signal;
+    use maycoon_core::signal::state::StateSignal;
+    use maycoon_core::testing::Harness;
+    use maycoon_core::window::{Ime, Key, ModifiersState, NamedKey};
+    use maycoon_theme::theme::celeste::CelesteTheme;
+    use nalgebra::Vector2;
+
+    /// The modifier used for shortcuts like copy and paste.
+    fn primary() -> ModifiersState {
+        if cfg!(target_os = "macos") {
+            ModifiersState::SUPER
+        } else {
+            ModifiersState::CONTROL
+        }
+    }
+
+    /// Create a harness with the given text area and focus it.
+    fn focused(area: TextArea) -> Harness<TextArea, CelesteTheme> {
+        let mut harness = Harness::new(area, CelesteTheme::default(), Vector2::new(400.0, 300.0));
+
+        harness.frame();
+        harness.click(Vector2::new(10.0, 10.0));
+        harness.frame();
+
+        harness
+    }
+
+    /// Type the given text by pressing a key for each character.
+    fn type_text(harness: &mut Harness<TextArea, CelesteTheme>, text: &str) {
+        for c in text.chars() {
+            if c == '\n' {
+                harness.press_key(Key::Named(NamedKey::Enter));
+            } else {
+                harness.press_key(Key::Character(c.to_string().into()));
+            }
+        }
+
+        harness.frame();
+    }
+
+    /// Press the given key with the given modifiers.
+    fn press(harness: &mut Harness<TextArea, CelesteTheme>, modifiers: ModifiersState, key: Key) {
+        harness.set_modifiers(modifiers);
+        harness.press_key(key);
+        harness.frame();
+        harness.set_modifiers(ModifiersState::empty());
+    }
+
+    /// Tests typing multiple lines and indenting them.
+    #[test]
+    fn test_text_area_typing() {
+        let value = StateSignal::new(String::new());
+        let caret = StateSignal::new(CaretPosition::default());
+        let mut harness = focused(TextArea::new(value.maybe()).with_caret(caret.maybe()));
+
+        type_text(&mut harness, "a\nbc");
+        assert_eq!(value.get().as_str(), "a\nbc");
+        assert_eq!(*caret.get(), CaretPosition { line: 1, column: 2 });
+
+        press(
+            &mut harness,
+            ModifiersState::empty(),
+            Key::Named(NamedKey::Home),
+        );
+        press(
+            &mut harness,
+            ModifiersState::empty(),
+            Key::Named(NamedKey::Tab),
+        );
+        assert_eq!(value.get().as_str(), "a\n    bc");
+        assert_eq!(*caret.get(), CaretPosition { line: 1, column: 4 });
+
+        // indent all selected lines
+        press(&mut harness, primary(), Key::Character("a".into()));
+        press(
+            &mut harness,
+            ModifiersState::empty(),
+            Key::Named(NamedKey::Tab),
+        );
+        assert_eq!(value.get().as_str(), "    a\n        bc");
+
+        press(
+            &mut harness,
+            ModifiersState::SHIFT,
+            Key::Named(NamedKey::Tab),
+        );
+        assert_eq!(value.get().as_str(), "a\n    bc");
+
+        press(&mut harness, primary(), Key::Character("z".into()));
+        assert_eq!(value.get().as_str(), "    a\n        bc");
+
+        press(&mut harness, primary(), Key::Character("y".into()));
+        assert_eq!(value.get().as_str(), "a\n    bc");
+    }
+
+    /// Tests replacing the selection and deleting words.
+    #[test]
+    fn test_text_area_selection() {
+        let value = StateSignal::new(String::from("one two\nthree"));
+        let mut harness = focused(TextArea::new(value.maybe()));
+
+        press(&mut harness, primary(), Key::Named(NamedKey::End));
+        press(
+            &mut harness,
+            ModifiersState::SHIFT,
+            Key::Named(NamedKey::ArrowUp),
+        );
+        press(
+            &mut harness,
+            ModifiersState::SHIFT,
+            Key::Named(NamedKey::End),
+        );
+
+        type_text(&mut harness, "!");
+        assert_eq!(value.get().as_str(), "one two!");
+
+        press(&mut harness, primary(), Key::Named(NamedKey::Home));
+        press(&mut harness, primary(), Key::Named(NamedKey::Delete));
+        assert_eq!(value.get().as_str(), "two!");
+    }
+
+    /// Tests copying, cutting and pasting multiple lines with the clipboard.
+    #[test]
+    fn test_text_area_clipboard() {
+        let value = StateSignal::new(String::new());
+        let mut harness = focused(TextArea::new(value.maybe()));
+        let clipboard = harness.context().clipboard();
+
+        type_text(&mut harness, "one\ntwo");
+
+        press(&mut harness, primary(), Key::Character("a".into()));
+        press(&mut harness, primary(), Key::Character("c".into()));
+        assert_eq!(clipboard.get_text().as_deref(), Some("one\ntwo"));
+
+        // carriage returns are removed
+        clipboard.set_text("x\r\ny");
+
+        press(&mut harness, primary(), Key::Named(NamedKey::End));
+        press(&mut harness, primary(), Key::Character("v".into()));
+        assert_eq!(value.get().as_str(), "one\ntwox\ny");
+
+        press(&mut harness, primary(), Key::Character("a".into()));
+        press(&mut harness, primary(), Key::Character("x".into()));
+        assert_eq!(value.get().as_str(), "");
+        assert_eq!(clipboard.get_text().as_deref(), Some("one\ntwox\ny"));
+    }
+
+    /// Tests that keys are ignored while the input method is composing.
+    #[test]
+    fn test_text_area_ime_preedit() {
+        let value = StateSignal::new(String::new());
+        let mut harness = focused(TextArea::new(value.maybe()));
+
+        harness.ime(Ime::Enabled);
+        harness.ime(Ime::Preedit(String::from("ni"), Some((2, 2))));
+        harness.frame();
+
+        type_text(&mut harness, "x\n");
+        assert_eq!(value.get().as_str(), "");
+
+        harness.ime(Ime::Preedit(String::new(), None));
+        harness.ime(Ime::Commit(String::from("你")));
+        harness.frame();
+        assert_eq!(value.get().as_str(), "你");
+
+        type_text(&mut harness, "\nx");
+        assert_eq!(value.get().as_str(), "你\nx");
+    }
+
+    /// Tests that the value can be cleared by the change handler.
+    #[test]
+    fn test_text_area_clear_on_change() {
+        let value = StateSignal::new(String::new());
+        let caret = StateSignal::new(CaretPosition::default());
+
+        let area = TextArea::new(value.maybe())
+            .with_caret(caret.maybe())
+            .with_on_change({
+                let value = value.clone();
+
+                move |text, _| {
+                    if text.ends_with("\n\n") {
+                        value.set(String::new());
+                    }
+
+                    Update::DRAW
+                }
+            });
+
+        let mut harness = focused(area);
+
+        type_text(&mut harness, "abc\n\n");
+        assert_eq!(value.get().as_str(), "");
+        assert_eq!(*caret.get(), CaretPosition::default());
+
+        type_text(&mut harness, "d");
+        assert_eq!(value.get().as_str(), "d");
+    }
+}
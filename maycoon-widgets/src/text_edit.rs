@@ -0,0 +1,653 @@
+use std::ops::Range;
+
+/// The maximum number of undo steps to keep.
+const MAX_UNDO: usize = 100;
+
+/// The kind of edit, used to merge consecutive edits of the same kind into a single undo step.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum EditKind {
+    Insert,
+    Delete,
+}
+
+/// A snapshot of the text and selection for undo/redo.
+#[derive(Clone, Debug)]
+struct Snapshot {
+    text: String,
+    cursor: usize,
+    anchor: usize,
+}
+
+/// The editing state of a text buffer with caret, selection and undo history.
+///
+/// The text itself is owned by the widget (usually inside a signal) and passed to every operation.
+/// All positions are byte offsets into the text and always lie on character boundaries.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct TextEditor {
+    cursor: usize,
+    anchor: usize,
+    undo: Vec<Snapshot>,
+    redo: Vec<Snapshot>,
+    last_edit: Option<EditKind>,
+}
+
+impl TextEditor {
+    /// Get the caret position.
+    #[inline(always)]
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Get the selected range. The range is empty if nothing is selected.
+    #[inline(always)]
+    pub fn selection(&self) -> Range<usize> {
+        self.cursor.min(self.anchor)..self.cursor.max(self.anchor)
+    }
+
+    /// Returns if any text is selected.
+    #[inline(always)]
+    pub fn has_selection(&self) -> bool {
+        self.cursor != self.anchor
+    }
+
+    /// Get the selected text.
+    #[inline(always)]
+    pub fn selected<'a>(&self, text: &'a str) -> &'a str {
+        &text[self.selection()]
+    }
+
+    /// Make sure the caret and selection are inside the text, after it was changed from outside.
+    #[inline(always)]
+    pub fn clamp(&mut self, text: &str) {
+        self.cursor = floor_char_boundary(text, self.cursor);
+        self.anchor = floor_char_boundary(text, self.anchor);
+    }
+
+    /// Move the caret to the given position and extend the selection if `select` is true.
+    #[inline(always)]
+    pub fn set_cursor(&mut self, text: &str, pos: usize, select: bool) {
+        self.cursor = floor_char_boundary(text, pos);
+
+        if !select {
+            self.anchor = self.cursor;
+        }
+
+        self.last_edit = None;
+    }
+
+    /// Select the whole text.
+    #[inline(always)]
+    pub fn select_all(&mut self, text: &str) {
+        self.anchor = 0;
+        self.cursor = text.len();
+        self.last_edit = None;
+    }
+
+    /// Move the caret one character or word to the left.
+    #[inline(always)]
+    pub fn move_left(&mut self, text: &str, word: bool, select: bool) {
+        let pos = if word {
+            prev_word(text, self.cursor)
+        } else if self.has_selection() && !select {
+            self.selection().start
+        } else {
+            prev_char(text, self.cursor)
+        };
+
+        self.set_cursor(text, pos, select);
+    }
+
+    /// Move the caret one character or word to the right.
+    #[inline(always)]
+    pub fn move_right(&mut self, text: &str, word: bool, select: bool) {
+        let pos = if word {
+            next_word(text, self.cursor)
+        } else if self.has_selection() && !select {
+            self.selection().end
+        } else {
+            next_char(text, self.cursor)
+        };
+
+        self.set_cursor(text, pos, select);
+    }
+
+    /// Replace the selection with the given string and move the caret behind it.
+    ///
+    /// Returns if the text changed.
+    pub fn insert(&mut self, text: &mut String, insert: &str) -> bool {
+        if insert.is_empty() && !self.has_selection() {
+            return false;
+        }
+
+        self.record(text, EditKind::Insert);
+
+        let range = self.selection();
+
+        text.replace_range(range.clone(), insert);
+
+        self.cursor = range.start + insert.len();
+        self.anchor = self.cursor;
+
+        // start a new undo step after each word
+        self.last_edit = if insert.contains(char::is_whitespace) {
+            None
+        } else {
+            Some(EditKind::Insert)
+        };
+
+        true
+    }
+
+    /// Indent or outdent all lines touched by the selection by the given indentation.
+    ///
+    /// Outdenting removes up to the length of the indentation in leading spaces.
+    ///
+    /// Returns if the text changed.
+    pub fn indent(&mut self, text: &mut String, indent: &str, outdent: bool) -> bool {
+        let range = self.selection();
+        let first = text[..range.start].rfind('\n').map(|i| i + 1).unwrap_or(0);
+
+        let mut starts = vec![first];
+
+        starts.extend(
+            text[first..range.end]
+                .match_indices('\n')
+                .map(|(i, _)| first + i + 1)
+                .filter(|start| *start < range.end),
+        );
+
+        let edits = starts
+            .into_iter()
+            .map(|start| {
+                if outdent {
+                    let spaces = text[start..]
+                        .chars()
+                        .take(indent.len())
+                        .take_while(|c| *c == ' ')
+                        .count();
+
+                    (start, start + spaces, "")
+                } else {
+                    (start, start, indent)
+                }
+            })
+            .filter(|(start, end, insert)| start != end || !insert.is_empty())
+            .collect::<Vec<_>>();
+
+        if edits.is_empty() {
+            return false;
+        }
+
+        self.record(text, EditKind::Insert);
+
+        // apply edits from back to front, so the positions stay valid
+        for (start, end, insert) in edits.into_iter().rev() {
+            text.replace_range(start..end, insert);
+
+            let shift = |pos: usize| {
+                if pos >= end {
+                    pos - (end - start) + insert.len()
+                } else if pos > start {
+                    start
+                } else {
+                    pos
+                }
+            };
+
+            self.cursor = shift(self.cursor);
+            self.anchor = shift(self.anchor);
+        }
+
+        self.last_edit = None;
+
+        true
+    }
+
+    /// Delete the selection or the character/word before the caret.
+    ///
+    /// Returns if the text changed.
+    pub fn delete_backward(&mut self, text: &mut String, word: bool) -> bool {
+        if self.has_selection() {
+            return self.delete_selection(text);
+        }
+
+        let start = if word {
+            prev_word(text, self.cursor)
+        } else {
+            prev_char(text, self.cursor)
+        };
+
+        self.delete_range(text, start..self.cursor)
+    }
+
+    /// Delete the selection or the character/word after the caret.
+    ///
+    /// Returns if the text changed.
+    pub fn delete_forward(&mut self, text: &mut String, word: bool) -> bool {
+        if self.has_selection() {
+            return self.delete_selection(text);
+        }
+
+        let end = if word {
+            next_word(text, self.cursor)
+        } else {
+            next_char(text, self.cursor)
+        };
+
+        self.delete_range(text, self.cursor..end)
+    }
+
+    /// Delete the selected text.
+    ///
+    /// Returns if the text changed.
+    #[inline(always)]
+    pub fn delete_selection(&mut self, text: &mut String) -> bool {
+        self.delete_range(text, self.selection())
+    }
+
+    /// Delete the given range and move the caret to its start.
+    ///
+    /// Returns if the text changed.
+    fn delete_range(&mut self, text: &mut String, range: Range<usize>) -> bool {
+        if range.is_empty() {
+            return false;
+        }
+
+        self.record(text, EditKind::Delete);
+
+        text.replace_range(range.clone(), "");
+
+        self.cursor = range.start;
+        self.anchor = range.start;
+        self.last_edit = Some(EditKind::Delete);
+
+        true
+    }
+
+    /// Undo the last edit.
+    ///
+    /// Returns if the text changed.
+    pub fn undo(&mut self, text: &mut String) -> bool {
+        let Some(snapshot) = self.undo.pop() else {
+            return false;
+        };
+
+        self.redo.push(self.snapshot(text));
+        self.restore(text, snapshot);
+
+        true
+    }
+
+    /// Redo the last undone edit.
+    ///
+    /// Returns if the text changed.
+    pub fn redo(&mut self, text: &mut String) -> bool {
+        let Some(snapshot) = self.redo.pop() else {
+            return false;
+        };
+
+        self.undo.push(self.snapshot(text));
+        self.restore(text, snapshot);
+
+        true
+    }
+
+    /// Record an undo step before an edit of the given kind, unless it can be merged with the last edit.
+    #[inline(always)]
+    fn record(&mut self, text: &str, kind: EditKind) {
+        if self.last_edit != Some(kind) || self.has_selection() {
+            if self.undo.len() == MAX_UNDO {
+                self.undo.remove(0);
+            }
+
+            self.undo.push(self.snapshot(text));
+        }
+
+        self.redo.clear();
+    }
+
+    #[inline(always)]
+    fn snapshot(&self, text: &str) -> Snapshot {
+        Snapshot {
+            text: text.to_string(),
+            cursor: self.cursor,
+            anchor: self.anchor,
+        }
+    }
+
+    #[inline(always)]
+    fn restore(&mut self, text: &mut String, snapshot: Snapshot) {
+        *text = snapshot.text;
+        self.cursor = snapshot.cursor;
+        self.anchor = snapshot.anchor;
+        self.last_edit = None;
+    }
+}
+
+/// Returns the largest character boundary in the text, that is not greater than the given position.
+#[inline(always)]
+pub(crate) fn floor_char_boundary(text: &str, pos: usize) -> usize {
+    let mut pos = pos.min(text.len());
+
+    while !text.is_char_boundary(pos) {
+        pos -= 1;
+    }
+
+    pos
+}
+
+/// Returns the position of the character before the given position.
+#[inline(always)]
+pub(crate) fn prev_char(text: &str, pos: usize) -> usize {
+    text[..pos]
+        .char_indices()
+        .next_back()
+        .map(|(i, _)| i)
+        .unwrap_or(0)
+}
+
+/// Returns the position of the character after the given position.
+#[inline(always)]
+pub(crate) fn next_char(text: &str, pos: usize) -> usize {
+    text[pos..]
+        .chars()
+        .next()
+        .map(|c| pos + c.len_utf8())
+        .unwrap_or(pos)
+}
+
+/// The class of a character for word boundary detection.
+#[inline(always)]
+fn char_class(c: char) -> u8 {
+    if c.is_whitespace() {
+        0
+    } else if c.is_alphanumeric() || c == '_' {
+        1
+    } else {
+        2
+    }
+}
+
+/// Returns the start of the word before the given position, skipping whitespace.
+pub(crate) fn prev_word(text: &str, pos: usize) -> usize {
+    let mut chars = text[..pos].char_indices().rev().peekable();
+
+    while chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+
+    let Some(&(mut start, c)) = chars.peek() else {
+        return 0;
+    };
+
+    let class = char_class(c);
+
+    while let Some((i, _)) = chars.next_if(|(_, c)| char_class(*c) == class) {
+        start = i;
+    }
+
+    start
+}
+
+/// Returns the start of the word after the given position, skipping the current word and whitespace.
+pub(crate) fn next_word(text: &str, pos: usize) -> usize {
+    let mut chars = text[pos..].char_indices().peekable();
+
+    if let Some(&(_, c)) = chars.peek()
+        && !c.is_whitespace()
+    {
+        let class = char_class(c);
+
+        while chars.next_if(|(_, c)| char_class(*c) == class).is_some() {}
+    }
+
+    while chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+
+    chars.peek().map(|(i, _)| pos + i).unwrap_or(text.len())
+}
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use crate::text_edit::{MAX_UNDO, TextEditor, floor_char_boundary, next_word, prev_word};
+
+    /// Tests that consecutive inserts are merged into one undo step per word.
+    #[test]
+    fn test_undo_redo_insert() {
+        let mut editor = TextEditor::default();
+        let mut text = String::new();
+
+        for c in ["H", "e", "y", " ", "y", "o"] {
+            assert!(editor.insert(&mut text, c));
+        }
+
+        assert_eq!(text, "Hey yo");
+
+        assert!(editor.undo(&mut text));
+        assert_eq!(text, "Hey ");
+        assert_eq!(editor.cursor(), 4);
+
+        assert!(editor.undo(&mut text));
+        assert_eq!(text, "");
+        assert_eq!(editor.cursor(), 0);
+
+        assert!(!editor.undo(&mut text));
+
+        assert!(editor.redo(&mut text));
+        assert_eq!(text, "Hey ");
+
+        assert!(editor.redo(&mut text));
+        assert_eq!(text, "Hey yo");
+        assert_eq!(editor.cursor(), 6);
+
+        assert!(!editor.redo(&mut text));
+    }
+
+    /// Tests that consecutive deletes are merged and that a new edit clears the redo history.
+    #[test]
+    fn test_undo_redo_delete() {
+        let mut editor = TextEditor::default();
+        let mut text = String::from("Hello");
+
+        editor.set_cursor(&text, text.len(), false);
+
+        assert!(editor.delete_backward(&mut text, false));
+        assert!(editor.delete_backward(&mut text, false));
+        assert_eq!(text, "Hel");
+
+        // insert after delete starts a new undo step
+        assert!(editor.insert(&mut text, "p"));
+        assert_eq!(text, "Help");
+
+        assert!(editor.undo(&mut text));
+        assert_eq!(text, "Hel");
+
+        assert!(editor.undo(&mut text));
+        assert_eq!(text, "Hello");
+
+        assert!(editor.redo(&mut text));
+        assert_eq!(text, "Hel");
+
+        assert!(editor.insert(&mut text, "l"));
+        assert!(!editor.redo(&mut text));
+        assert_eq!(text, "Hell");
+    }
+
+    /// Tests that the undo history is limited to [MAX_UNDO] steps.
+    #[test]
+    fn test_undo_limit() {
+        let mut editor = TextEditor::default();
+        let mut text = String::new();
+
+        for _ in 0..MAX_UNDO + 50 {
+            editor.insert(&mut text, "a ");
+        }
+
+        let mut undone = 0;
+
+        while editor.undo(&mut text) {
+            undone += 1;
+        }
+
+        assert_eq!(undone, MAX_UNDO);
+        assert_eq!(text, "a ".repeat(50));
+    }
+
+    /// Tests that inserting and deleting replaces the selection and that undo restores it.
+    #[test]
+    fn test_selection_replace() {
+        let mut editor = TextEditor::default();
+        let mut text = String::from("Hello World");
+
+        editor.set_cursor(&text, 0, false);
+        editor.set_cursor(&text, 5, true);
+
+        assert!(editor.has_selection());
+        assert_eq!(editor.selected(&text), "Hello");
+
+        assert!(editor.insert(&mut text, "Bye"));
+        assert_eq!(text, "Bye World");
+        assert_eq!(editor.cursor(), 3);
+        assert!(!editor.has_selection());
+
+        // select backwards
+        editor.set_cursor(&text, text.len(), false);
+        editor.set_cursor(&text, 4, true);
+
+        assert_eq!(editor.selection(), 4..9);
+        assert_eq!(editor.selected(&text), "World");
+
+        assert!(editor.delete_forward(&mut text, false));
+        assert_eq!(text, "Bye ");
+
+        assert!(editor.undo(&mut text));
+        assert_eq!(text, "Bye World");
+        assert_eq!(editor.selection(), 4..9);
+
+        assert!(editor.undo(&mut text));
+        assert_eq!(text, "Hello World");
+        assert_eq!(editor.selection(), 0..5);
+    }
+
+    /// Tests moving the caret with and without selecting.
+    #[test]
+    fn test_move() {
+        let mut editor = TextEditor::default();
+        let text = "foo bär";
+
+        editor.set_cursor(text, text.len(), false);
+        editor.move_left(text, false, false);
+        assert_eq!(editor.cursor(), 7);
+
+        // 'ä' is two bytes long
+        editor.move_left(text, false, false);
+        assert_eq!(editor.cursor(), 5);
+
+        editor.move_right(text, true, true);
+        assert_eq!(editor.selected(text), "är");
+
+        // moving without selecting collapses the selection
+        editor.move_left(text, false, false);
+        assert_eq!(editor.cursor(), 5);
+        assert!(!editor.has_selection());
+
+        editor.move_left(text, true, true);
+        assert_eq!(editor.selected(text), "b");
+
+        editor.select_all(text);
+        assert_eq!(editor.selected(text), text);
+
+        editor.move_left(text, false, false);
+        assert_eq!(editor.cursor(), 0);
+    }
+
+    /// Tests indenting and outdenting all lines touched by the selection.
+    #[test]
+    fn test_indent() {
+        let mut editor = TextEditor::default();
+        let mut text = String::from("a\nb\nc");
+
+        // only the line of the caret
+        editor.set_cursor(&text, 2, false);
+        assert!(editor.indent(&mut text, "    ", false));
+        assert_eq!(text, "a\n    b\nc");
+        assert_eq!(editor.cursor(), 6);
+
+        editor.select_all(&text);
+        assert!(editor.indent(&mut text, "    ", false));
+        assert_eq!(text, "    a\n        b\n    c");
+        assert_eq!(editor.cursor(), text.len());
+
+        assert!(editor.indent(&mut text, "    ", true));
+        assert_eq!(text, "a\n    b\nc");
+
+        // lines with less indentation are outdented as far as possible
+        assert!(editor.indent(&mut text, "    ", true));
+        assert_eq!(text, "a\nb\nc");
+
+        assert!(!editor.indent(&mut text, "    ", true));
+
+        assert!(editor.undo(&mut text));
+        assert_eq!(text, "a\n    b\nc");
+    }
+
+    /// Tests that a selection ending at the start of a line does not indent that line.
+    #[test]
+    fn test_indent_line_end() {
+        let mut editor = TextEditor::default();
+        let mut text = String::from("a\nb");
+
+        editor.set_cursor(&text, 0, false);
+        editor.set_cursor(&text, 2, true);
+
+        assert!(editor.indent(&mut text, "  ", false));
+        assert_eq!(text, "  a\nb");
+    }
+
+    /// Tests that the caret is clamped into a shorter text.
+    #[test]
+    fn test_clamp() {
+        let mut editor = TextEditor::default();
+
+        editor.select_all("Hello");
+        editor.clamp("Hi");
+
+        assert_eq!(editor.cursor(), 2);
+        assert_eq!(editor.selection(), 0..2);
+
+        editor.set_cursor("aé", 2, false);
+        assert_eq!(editor.cursor(), 1);
+    }
+
+    /// Tests character boundaries of multibyte characters.
+    #[test]
+    fn test_floor_char_boundary() {
+        assert_eq!(floor_char_boundary("aé", 0), 0);
+        assert_eq!(floor_char_boundary("aé", 1), 1);
+        assert_eq!(floor_char_boundary("aé", 2), 1);
+        assert_eq!(floor_char_boundary("aé", 3), 3);
+        assert_eq!(floor_char_boundary("aé", 10), 3);
+    }
+
+    /// Tests word boundaries.
+    #[test]
+    fn test_words() {
+        let text = "hello  world";
+
+        assert_eq!(prev_word(text, text.len()), 7);
+        assert_eq!(prev_word(text, 7), 0);
+        assert_eq!(prev_word(text, 0), 0);
+
+        assert_eq!(next_word(text, 0), 7);
+        assert_eq!(next_word(text, 5), 7);
+        assert_eq!(next_word(text, 7), text.len());
+
+        // punctuation is a separate word
+        assert_eq!(prev_word("foo.bar", 7), 4);
+        assert_eq!(prev_word("foo.bar", 4), 3);
+        assert_eq!(next_word("foo.bar", 0), 3);
+        assert_eq!(next_word("foo_bar baz", 0), 8);
+
+        let text = "héllo wörld";
+
+        assert_eq!(next_word(text, 0), 7);
+        assert_eq!(prev_word(text, text.len()), 7);
+    }
+}
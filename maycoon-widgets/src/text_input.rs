@@ -0,0 +1,884 @@
+use maycoon_core::app::context::AppContext;
+use maycoon_core::app::focus::Focus;
+use maycoon_core::app::info::AppInfo;
+use maycoon_core::app::pointer::{PointerId, Propagation};
+use maycoon_core::app::update::Update;
+use maycoon_core::event::Handler;
+use maycoon_core::layout;
+use maycoon_core::layout::{Dimension, Layout, LayoutNode, LayoutStyle, StyleNode};
+use maycoon_core::signal::MaybeSignal;
+use maycoon_core::text::char_offsets;
+use maycoon_core::vgi::kurbo::{Rect, RoundedRect, RoundedRectRadii, Stroke};
+use maycoon_core::vgi::{Brush, FontData, Scene};
+use maycoon_core::widget::{Widget, WidgetLayoutExt};
+use maycoon_core::window::{
+    CursorIcon, ElementState, Ime, Key, ModifiersState, MouseButton, NamedKey,
+};
+use maycoon_theme::id::WidgetId;
+use maycoon_theme::theme::Theme;
+use nalgebra::Vector2;
+use std::ops::Deref;
+
+use crate::focus_ring::draw_focus_ring;
+use crate::text_edit::TextEditor;
+
+/// The space between the border and the text.
+const PADDING: f32 = 8.0;
+
+/// A single-line text input field.
+///
+/// The text is edited via keyboard and mouse, once the input is focused by clicking on it or using `Tab`:
+/// - `Left`/`Right` move the caret, `Home`/`End` move it to the start/end of the text.
+/// - Holding `Ctrl` (`Alt` on macOS) moves by words and deletes words using `Backspace`/`Delete`.
+/// - Holding `Shift` or dragging the mouse selects text.
+/// - `Ctrl+A` selects everything, `Ctrl+C`/`Ctrl+X`/`Ctrl+V` copy, cut and paste the selection
+///   and `Ctrl+Z`/`Ctrl+Y` undo and redo edits (using `Cmd` on macOS).
+/// - `Enter` submits the text.
+///
+/// Text composed by an input method (IME) is shown at the caret and inserted once it is committed.
+///
+/// The cursor changes to a text cursor while hovering the input.
+///
+/// The value should be a signal, so it's mutable.
+///
+/// See the [text_input](https://github.com/maycoon-ui/maycoon/blob/master/examples/text_input/src/main.rs) example for how to use it in practice.
+///
+/// ### Theming
+/// Styling the text input requires following properties:
+/// - `color_background` - The background color of the input.
+/// - `color_border` - The color of the border.
+/// - `color_text` - The color of the text and the caret.
+/// - `color_placeholder` - The color of the placeholder text.
+/// - `color_selection` - The background color of selected text.
+/// - `color_focus` - The color of the focus ring, when the input is focused.
+///
+/// The [WidgetId] is equal to `maycoon-widgets:TextInput`.
+pub struct TextInput {
+    layout_style: MaybeSignal<LayoutStyle>,
+    value: MaybeSignal<String>,
+    placeholder: MaybeSignal<String>,
+    font: MaybeSignal<Option<String>>,
+    font_size: MaybeSignal<f32>,
+    on_change: Handler<String>,
+    on_submit: Handler<String>,
+    editor: TextEditor,
+    focus: Option<Focus>,
+    focused: bool,
+    dragging: bool,
+    preedit: String,
+    scroll: f32,
+    pointer: PointerId,
+}
+
+impl TextInput {
+    /// Create a new text input with the given value.
+    ///
+    /// The value should be a signal, so it's mutable.
+    #[inline(always)]
+    pub fn new(value: impl Into<MaybeSignal<String>>) -> Self {
+        Self {
+            layout_style: LayoutStyle {
+                size: Vector2::new(Dimension::length(200.0), Dimension::auto()),
+                ..Default::default()
+            }
+            .into(),
+            value: value.into(),
+            placeholder: String::new().into(),
+            font: None.into(),
+            font_size: 20.0.into(),
+            on_change: Handler::none(),
+            on_submit: Handler::none(),
+            editor: TextEditor::default(),
+            focus: None,
+            focused: false,
+            dragging: false,
+            preedit: String::new(),
+            scroll: 0.0,
+            pointer: PointerId::new(),
+        }
+    }
+
+    /// Sets the value of the text input and returns self.
+    #[inline(always)]
+    pub fn with_value(mut self, value: impl Into<MaybeSignal<String>>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    /// Sets the placeholder text, which is shown if the input is empty, and returns self.
+    #[inline(always)]
+    pub fn with_placeholder(mut self, placeholder: impl Into<MaybeSignal<String>>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the name of the font to use and returns self. Uses the default font if [None].
+    #[inline(always)]
+    pub fn with_font(mut self, font: impl Into<MaybeSignal<Option<String>>>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the font size and returns self.
+    #[inline(always)]
+    pub fn with_font_size(mut self, size: impl Into<MaybeSignal<f32>>) -> Self {
+        self.font_size = size.into();
+        self
+    }
+
+    /// Sets the handler to call with the new text, when the text changes, and returns self.
+    #[inline(always)]
+    pub fn with_on_change(
+        mut self,
+        on_change: impl FnMut(&String, &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_change = Handler::new(on_change);
+        self
+    }
+
+    /// Sets the handler to call with the text, when `Enter` is pressed, and returns self.
+    #[inline(always)]
+    pub fn with_on_submit(
+        mut self,
+        on_submit: impl FnMut(&String, &AppContext) -> Update + 'static,
+    ) -> Self {
+        self.on_submit = Handler::new(on_submit);
+        self
+    }
+
+    /// Get the font of the text input.
+    #[inline(always)]
+    fn font(&self, info: &AppInfo) -> FontData {
+        if let Some(name) = self.font.get().deref() {
+            info.font_context.get(name).expect("Font not found")
+        } else {
+            info.font_context.default_font().clone()
+        }
+    }
+
+    /// Get the rectangle of the text inside the given layout.
+    #[inline(always)]
+    fn text_rect(layout: &Layout) -> Rect {
+        Rect::new(
+            (layout.location.x + PADDING) as f64,
+            (layout.location.y + PADDING) as f64,
+            (layout.location.x + layout.size.width - PADDING) as f64,
+            (layout.location.y + layout.size.height - PADDING) as f64,
+        )
+    }
+
+    /// Returns the text position closest to the given x-coordinate relative to the text start.
+    #[inline(always)]
+    fn hit(text: &str, offsets: &[f32], x: f32) -> usize {
+        let idx = offsets
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| (*a - x).abs().total_cmp(&(*b - x).abs()))
+            .map(|(i, _)| i)
+            .unwrap_or_default();
+
+        text.char_indices()
+            .nth(idx)
+            .map(|(i, _)| i)
+            .unwrap_or(text.len())
+    }
+
+    /// Write the edited text to the value and call the change handler, if the text changed.
+    ///
+    /// Afterward, the text and the editor are synchronized with the value again,
+    /// since the handler might have changed it or the value might not be mutable.
+    fn commit(&mut self, text: &mut String, old_text: &mut String, context: &AppContext) -> Update {
+        if text == old_text {
+            return Update::empty();
+        }
+
+        let mut update = Update::DRAW;
+
+        if let Some(sig) = self.value.as_signal() {
+            sig.set(text.clone());
+
+            update |= self.on_change.call(text, context);
+        }
+
+        self.sync(text, old_text);
+
+        update
+    }
+
+    /// Reload the text from the value and move the caret and selection into it.
+    #[inline(always)]
+    fn sync(&mut self, text: &mut String, old_text: &mut String) {
+        *text = self.value.get().clone();
+        *old_text = text.clone();
+
+        self.editor.clamp(text);
+    }
+
+    /// Apply the given key to the text.
+    ///
+    /// Returns if the text changed.
+    fn handle_key(
+        &mut self,
+        text: &mut String,
+        key: &Key,
+        key_text: Option<&str>,
+        modifiers: ModifiersState,
+        context: &AppContext,
+    ) -> bool {
+        let (primary, word) = if cfg!(target_os = "macos") {
+            (modifiers.super_key(), modifiers.alt_key())
+        } else {
+            (modifiers.control_key(), modifiers.control_key())
+        };
+
+        let select = modifiers.shift_key();
+
+        match key {
+            Key::Named(NamedKey::ArrowLeft) => {
+                self.editor.move_left(text, word, select);
+                false
+            },
+
+            Key::Named(NamedKey::ArrowRight) => {
+                self.editor.move_right(text, word, select);
+                false
+            },
+
+            Key::Named(NamedKey::Home) => {
+                self.editor.set_cursor(text, 0, select);
+                false
+            },
+
+            Key::Named(NamedKey::End) => {
+                self.editor.set_cursor(text, text.len(), select);
+                false
+            },
+
+            Key::Named(NamedKey::Backspace) => self.editor.delete_backward(text, word),
+
+            Key::Named(NamedKey::Delete) => self.editor.delete_forward(text, word),
+
+            Key::Character(c) if primary => match c.to_lowercase().as_str() {
+                "a" => {
+                    self.editor.select_all(text);
+                    false
+                },
+
+                "c" => {
+                    if self.editor.has_selection() {
+                        context.clipboard().set_text(self.editor.selected(text));
+                    }
+
+                    false
+                },
+
+                "x" => {
+                    if self.editor.has_selection() {
+                        context.clipboard().set_text(self.editor.selected(text));
+                    }
+
+                    self.editor.delete_selection(text)
+                },
+
+                "v" => {
+                    let paste = context
+                        .clipboard()
+                        .get_text()
+                        .unwrap_or_default()
+                        .replace(['\r', '\n'], " ");
+
+                    self.editor.insert(text, &paste)
+                },
+
+                "z" if select => self.editor.redo(text),
+
+                "z" => self.editor.undo(text),
+
+                "y" => self.editor.redo(text),
+
+                _ => false,
+            },
+
+            _ => match key_text {
+                Some(insert) if !insert.chars().any(char::is_control) => {
+                    self.editor.insert(text, insert)
+                },
+
+                _ => false,
+            },
+        }
+    }
+}
+
+impl WidgetLayoutExt for TextInput {
+    #[inline(always)]
+    fn set_layout_style(&mut self, layout_style: impl Into<MaybeSignal<LayoutStyle>>) {
+        self.layout_style = layout_style.into();
+    }
+}
+
+impl Widget for TextInput {
+    fn render(
+        &mut self,
+        scene: &mut dyn Scene,
+        theme: &mut dyn Theme,
+        layout_node: &LayoutNode,
+        info: &AppInfo,
+        context: AppContext,
+    ) {
+        let (background, border, text_color, placeholder_color, selection_color) =
+            if let Some(style) = theme.of(self.widget_id()) {
+                (
+                    style.get_color("color_background").unwrap(),
+                    style.get_color("color_border").unwrap(),
+                    style.get_color("color_text").unwrap(),
+                    style.get_color("color_placeholder").unwrap(),
+                    style.get_color("color_selection").unwrap(),
+                )
+            } else {
+                (
+                    theme.defaults().container().background(),
+                    theme.defaults().interactive().inactive(),
+                    theme.defaults().text().foreground(),
+                    theme.defaults().interactive().disabled(),
+                    theme.defaults().interactive().hover(),
+                )
+            };
+
+        let layout = &layout_node.layout;
+        let font = self.font(info);
+        let font_size = *self.font_size.get();
+        let rect = Self::text_rect(layout);
+        let width = rect.width() as f32;
+
+        let bounds = RoundedRect::from_rect(
+            Rect::new(
+                layout.location.x as f64,
+                layout.location.y as f64,
+                (layout.location.x + layout.size.width) as f64,
+                (layout.location.y + layout.size.height) as f64,
+            ),
+            RoundedRectRadii::from_single_radius(5.0),
+        );
+
+        scene.draw_rounded_rect(&Brush::Solid(background), None, None, &bounds);
+        scene.draw_rounded_rect(
+            &Brush::Solid(border),
+            None,
+            Some(&Stroke::new(1.0)),
+            &bounds,
+        );
+
+        let value = self.value.get();
+
+        if value.is_empty() && self.preedit.is_empty() && !self.focused {
+            self.scroll = 0.0;
+
+            let placeholder = self.placeholder.get();
+            let offsets = char_offsets(&font, &placeholder, font_size);
+            let visible = offsets
+                .partition_point(|x| *x <= width + 0.5)
+                .saturating_sub(1);
+
+            scene.draw_text(
+                &Brush::Solid(placeholder_color),
+                None,
+                Vector2::new(rect.x0 as f32, rect.y0 as f32),
+                &placeholder.chars().take(visible).collect::<String>(),
+                true,
+                &font,
+                font_size,
+                0.0,
+                f32::INFINITY,
+            );
+
+            return;
+        }
+
+        // the value might have been changed since the last update
+        self.editor.clamp(&value);
+
+        // insert the composing text of the input method at the caret
+        let cursor = self.editor.cursor();
+        let text = format!("{}{}{}", &value[..cursor], self.preedit, &value[cursor..]);
+
+        let offsets = char_offsets(&font, &text, font_size);
+        let offset_at = |pos: usize| offsets[text[..pos].chars().count()];
+
+        // scroll horizontally to keep the caret visible
+        let caret_x = offset_at(cursor + self.preedit.len());
+        let total = offsets.last().copied().unwrap_or_default();
+
+        if caret_x - self.scroll > width {
+            self.scroll = caret_x - width;
+        } else if caret_x < self.scroll {
+            self.scroll = caret_x;
+        }
+
+        self.scroll = self.scroll.min((total - width).max(0.0)).max(0.0);
+
+        // draw selection
+        if self.focused && self.editor.has_selection() {
+            let selection = self.editor.selection();
+            let start = (offset_at(selection.start) - self.scroll).max(0.0);
+            let end = (offset_at(selection.end) - self.scroll).min(width);
+
+            scene.draw_rect(
+                &Brush::Solid(selection_color),
+                None,
+                None,
+                &Rect::new(
+                    rect.x0 + start as f64,
+                    rect.y0,
+                    rect.x0 + end as f64,
+                    rect.y1,
+                ),
+            );
+        }
+
+        // only draw the visible characters, since the text is not clipped
+        let first = offsets.partition_point(|x| *x < self.scroll - 0.5);
+        let last = offsets.partition_point(|x| *x <= self.scroll + width + 0.5);
+
+        if last > first + 1 {
+            let visible = text
+                .chars()
+                .skip(first)
+                .take(last - first - 1)
+                .collect::<String>();
+
+            scene.draw_text(
+                &Brush::Solid(text_color),
+                None,
+                Vector2::new(
+                    rect.x0 as f32 + offsets[first] - self.scroll,
+                    rect.y0 as f32,
+                ),
+                &visible,
+                true,
+                &font,
+                font_size,
+                0.0,
+                f32::INFINITY,
+            );
+        }
+
+        if self.focused {
+            let x = rect.x0 + (caret_x - self.scroll) as f64;
+
+            // underline the composing text
+            if !self.preedit.is_empty() {
+                let start = rect.x0 + (offset_at(cursor) - self.scroll).max(0.0) as f64;
+
+                scene.draw_rect(
+                    &Brush::Solid(text_color),
+                    None,
+                    None,
+                    &Rect::new(start, rect.y1 - 1.0, x, rect.y1),
+                );
+            }
+
+            // draw caret
+            scene.draw_rect(
+                &Brush::Solid(text_color),
+                None,
+                None,
+                &Rect::new(x - 0.75, rect.y0, x + 0.75, rect.y1),
+            );
+
+            context.set_ime_cursor_area(Vector2::new(x, rect.y0), Vector2::new(1.0, rect.height()));
+
+            draw_focus_ring(scene, theme, self.widget_id(), layout, 5.0);
+        }
+    }
+
+    #[inline(always)]
+    fn layout_style(&self) -> StyleNode {
+        let mut style = self.layout_style.get().clone();
+
+        if style.size.y == Dimension::auto() {
+            style.size.y = Dimension::length(*self.font_size.get() * 1.4 + PADDING * 2.0);
+        }
+
+        StyleNode {
+            style,
+            children: Vec::new(),
+            measure: None,
+        }
+    }
+
+    fn update(&mut self, layout: &LayoutNode, context: AppContext, info: &AppInfo) -> Update {
+        let mut update = Update::empty();
+        let focus = context.focus().register();
+
+        self.focus = Some(focus);
+
+        let pointer =
+            context
+                .pointer()
+                .register(self.pointer, &layout.layout, Propagation::Continue);
+
+        context.pointer().set_cursor_icon(pointer, CursorIcon::Text);
+
+        let mut text = self.value.get().clone();
+        let mut old_text = text.clone();
+        let old_selection = (self.editor.cursor(), self.editor.selection());
+
+        // the value might have been changed from outside
+        self.editor.clamp(&text);
+
+        let font = self.font(info);
+        let font_size = *self.font_size.get();
+        let rect = Self::text_rect(&layout.layout);
+
+        let hit = |text: &str, scroll: f32, x: f32| {
+            Self::hit(
+                text,
+                &char_offsets(&font, text, font_size),
+                x - rect.x0 as f32 + scroll,
+            )
+        };
+
+        // mouse selection
+        if let Some(cursor) = info.cursor_pos {
+            for (_, btn, el) in &info.buttons {
+                if *btn != MouseButton::Left {
+                    continue;
+                }
+
+                match el {
+                    ElementState::Pressed if layout::intersects(cursor, &layout.layout) => {
+                        context.focus().request(focus);
+
+                        let pos = hit(&text, self.scroll, cursor.x);
+
+                        self.editor
+                            .set_cursor(&text, pos, info.modifiers.shift_key());
+                        self.dragging = true;
+                    },
+
+                    ElementState::Pressed => (),
+
+                    ElementState::Released => self.dragging = false,
+                }
+            }
+
+            if self.dragging {
+                let pos = hit(&text, self.scroll, cursor.x);
+
+                self.editor.set_cursor(&text, pos, true);
+            }
+        }
+
+        // allow the input method while focused
+        let focused = context.focus().is_focused(focus);
+
+        if focused != self.focused {
+            self.focused = focused;
+            self.preedit.clear();
+
+            context.set_ime_allowed(focused);
+
+            update |= Update::DRAW;
+        }
+
+        for ime in context.focus().ime(focus, info) {
+            match ime {
+                Ime::Preedit(preedit, _) => self.preedit = preedit.clone(),
+
+                Ime::Commit(commit) => {
+                    self.preedit.clear();
+
+                    if self.editor.insert(&mut text, commit) {
+                        update |= self.commit(&mut text, &mut old_text, &context);
+                    }
+                },
+
+                Ime::Enabled | Ime::Disabled => self.preedit.clear(),
+            }
+
+            update |= Update::DRAW;
+        }
+
+        for key in context.focus().keys(focus, info) {
+            // keys are handled by the input method while composing
+            if !key.is_pressed() || !self.preedit.is_empty() {
+                continue;
+            }
+
+            if key.logical_key == Key::Named(NamedKey::Enter) {
+                update |= self.on_submit.call(&text, &context);
+
+                // the handler might have changed the value (e.g. to clear the input)
+                self.sync(&mut text, &mut old_text);
+            } else if self.handle_key(
+                &mut text,
+                &key.logical_key,
+                key.text.as_deref(),
+                info.modifiers,
+                &context,
+            ) {
+                update |= self.commit(&mut text, &mut old_text, &context);
+            }
+
+            update |= Update::DRAW;
+        }
+
+        if old_selection != (self.editor.cursor(), self.editor.selection()) {
+            update |= Update::DRAW;
+        }
+
+        update
+    }
+
+    #[inline(always)]
+    fn widget_id(&self) -> WidgetId {
+        WidgetId::new("maycoon-widgets", "TextInput")
+    }
+}
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use crate::text_input::TextInput;
+    use maycoon_core::app::update::Update;
+    use maycoon_core::signal::Signal;
+    use maycoon_core::signal::state::StateSignal;
+    use maycoon_core::testing::Harness;
+    use maycoon_core::vgi::Scene;
+    use maycoon_core::vgi::recording::{DrawCommand, RecordingScene};
+    use maycoon_core::window::{Ime, Key, ModifiersState, NamedKey};
+    use maycoon_theme::theme::celeste::CelesteTheme;
+    use nalgebra::Vector2;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// The modifier used for shortcuts like copy and paste.
+    fn primary() -> ModifiersState {
+        if cfg!(target_os = "macos") {
+            ModifiersState::SUPER
+        } else {
+            ModifiersState::CONTROL
+        }
+    }
+
+    /// Create a harness with a focused text input for the given value.
+    fn focused(value: &StateSignal<String>) -> Harness<TextInput, CelesteTheme> {
+        let mut harness = Harness::new(
+            TextInput::new(value.maybe()),
+            CelesteTheme::default(),
+            Vector2::new(400.0, 100.0),
+        );
+
+        harness.frame();
+        harness.click(Vector2::new(10.0, 10.0));
+        harness.frame();
+
+        harness
+    }
+
+    /// Type the given text by pressing a key for each character.
+    fn type_text<S: Scene>(harness: &mut Harness<TextInput, CelesteTheme, S>, text: &str) {
+        for c in text.chars() {
+            harness.press_key(Key::Character(c.to_string().into()));
+        }
+
+        harness.frame();
+    }
+
+    /// Tests that the value can be cleared by the submit handler.
+    #[test]
+    fn test_text_input_clear_on_submit() {
+        let value = StateSignal::new(String::new());
+        let submitted = Rc::new(RefCell::new(Vec::new()));
+
+        let input = TextInput::new(value.maybe()).with_on_submit({
+            let value = value.clone();
+            let submitted = submitted.clone();
+
+            move |text, _| {
+                submitted.borrow_mut().push(text.clone());
+                value.set(String::new());
+
+                Update::DRAW
+            }
+        });
+
+        let mut harness = Harness::new(input, CelesteTheme::default(), Vector2::new(400.0, 100.0));
+
+        harness.frame();
+        harness.click(Vector2::new(10.0, 10.0));
+        harness.frame();
+
+        for c in ["a", "b", "c"] {
+            harness.press_key(Key::Character(c.into()));
+        }
+
+        harness.frame();
+        assert_eq!(value.get().as_str(), "abc");
+
+        // the input is drawn with the cleared value in the same frame
+        harness.press_key(Key::Named(NamedKey::Enter));
+        harness.frame();
+
+        assert_eq!(*submitted.borrow(), vec![String::from("abc")]);
+        assert_eq!(value.get().as_str(), "");
+
+        // typing and submitting in the same frame
+        harness.press_key(Key::Character("d".into()));
+        harness.press_key(Key::Named(NamedKey::Enter));
+        harness.frame();
+
+        assert_eq!(
+            *submitted.borrow(),
+            vec![String::from("abc"), String::from("d")]
+        );
+        assert_eq!(value.get().as_str(), "");
+
+        harness.press_key(Key::Character("e".into()));
+        harness.frame();
+
+        assert_eq!(value.get().as_str(), "e");
+    }
+
+    /// Tests that the caret is moved into the value, if it gets shorter from outside.
+    #[test]
+    fn test_text_input_external_change() {
+        let value = StateSignal::new(String::from("Hello World"));
+
+        let mut harness = Harness::new(
+            TextInput::new(value.maybe()),
+            CelesteTheme::default(),
+            Vector2::new(400.0, 100.0),
+        );
+
+        harness.frame();
+        harness.click(Vector2::new(10.0, 10.0));
+        harness.frame();
+        harness.press_key(Key::Named(NamedKey::End));
+        harness.frame();
+
+        value.set(String::from("Hi"));
+        harness.force_update();
+        harness.frame();
+
+        harness.press_key(Key::Character("!".into()));
+        harness.frame();
+
+        assert_eq!(value.get().as_str(), "Hi!");
+    }
+
+    /// Tests typing and replacing the selection.
+    #[test]
+    fn test_text_input_selection() {
+        let value = StateSignal::new(String::new());
+        let mut harness = focused(&value);
+
+        type_text(&mut harness, "Hello World");
+        assert_eq!(value.get().as_str(), "Hello World");
+
+        harness.set_modifiers(ModifiersState::SHIFT);
+
+        for _ in 0..5 {
+            harness.press_key(Key::Named(NamedKey::ArrowLeft));
+        }
+
+        harness.frame();
+        harness.set_modifiers(ModifiersState::empty());
+
+        type_text(&mut harness, "There");
+        assert_eq!(value.get().as_str(), "Hello There");
+
+        harness.set_modifiers(primary());
+        harness.press_key(Key::Character("a".into()));
+        harness.frame();
+        harness.set_modifiers(ModifiersState::empty());
+
+        harness.press_key(Key::Named(NamedKey::Backspace));
+        harness.frame();
+        assert_eq!(value.get().as_str(), "");
+
+        harness.set_modifiers(primary());
+        harness.press_key(Key::Character("z".into()));
+        harness.frame();
+        assert_eq!(value.get().as_str(), "Hello There");
+    }
+
+    /// Tests copying, cutting and pasting with the clipboard.
+    #[test]
+    fn test_text_input_clipboard() {
+        let value = StateSignal::new(String::new());
+        let mut harness = focused(&value);
+        let clipboard = harness.context().clipboard();
+
+        type_text(&mut harness, "copy");
+
+        harness.set_modifiers(primary());
+        harness.press_key(Key::Character("a".into()));
+        harness.press_key(Key::Character("c".into()));
+        harness.frame();
+        assert_eq!(clipboard.get_text().as_deref(), Some("copy"));
+
+        harness.set_modifiers(ModifiersState::empty());
+        harness.press_key(Key::Named(NamedKey::End));
+        harness.set_modifiers(primary());
+        harness.press_key(Key::Character("v".into()));
+        harness.frame();
+        assert_eq!(value.get().as_str(), "copycopy");
+
+        // line breaks are replaced, since the input only has one line
+        clipboard.set_text("a\nb");
+
+        harness.press_key(Key::Character("a".into()));
+        harness.press_key(Key::Character("v".into()));
+        harness.frame();
+        assert_eq!(value.get().as_str(), "a b");
+
+        harness.press_key(Key::Character("a".into()));
+        harness.press_key(Key::Character("x".into()));
+        harness.frame();
+        assert_eq!(value.get().as_str(), "");
+        assert_eq!(clipboard.get_text().as_deref(), Some("a b"));
+    }
+
+    /// Tests that the composing text of the input method is drawn, but only inserted on commit.
+    #[test]
+    fn test_text_input_ime_preedit() {
+        let value = StateSignal::new(String::from("a"));
+        let mut harness = Harness::with_scene(
+            TextInput::new(value.maybe()),
+            CelesteTheme::default(),
+            Vector2::new(400.0, 100.0),
+            RecordingScene::default(),
+        );
+
+        harness.frame();
+        harness.click(Vector2::new(10.0, 10.0));
+        harness.press_key(Key::Named(NamedKey::End));
+        harness.frame();
+
+        harness.ime(Ime::Enabled);
+        harness.ime(Ime::Preedit(String::from("ni"), Some((2, 2))));
+        harness.frame();
+
+        assert_eq!(value.get().as_str(), "a");
+        assert!(
+            harness
+                .scene()
+                .commands()
+                .iter()
+                .any(|command| matches!(command, DrawCommand::Text { text, .. } if text == "ani"))
+        );
+
+        // keys are handled by the input method while composing
+        type_text(&mut harness, "x");
+        assert_eq!(value.get().as_str(), "a");
+
+        harness.ime(Ime::Preedit(String::new(), None));
+        harness.ime(Ime::Commit(String::from("你")));
+        harness.frame();
+        assert_eq!(value.get().as_str(), "a你");
+
+        type_text(&mut harness, "x");
+        assert_eq!(value.get().as_str(), "a你x");
+    }
+}